@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./types/AccountTypes.sol";
+import "./types/AccountMethodNums.sol";
 import "./cbor/AccountCbor.sol";
 import "./types/CommonTypes.sol";
 import "./utils/Misc.sol";
@@ -34,7 +35,7 @@ contract AccountAPI {
     function authenticate_message(bytes memory target, AccountTypes.AuthenticateMessageParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(AccountTypes.AuthenticateMessageMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(AccountMethodNums.AuthenticateMessage, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -43,7 +44,7 @@ contract AccountAPI {
     function universal_receiver_hook(bytes memory target, bytes memory params) public {
         bytes memory raw_request = params.serializeBytes();
 
-        bytes memory raw_response = Actor.call(AccountTypes.UniversalReceiverHookMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(AccountMethodNums.Receive, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
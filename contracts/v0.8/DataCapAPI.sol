@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./types/DataCapTypes.sol";
+import "./types/DataCapMethodNums.sol";
 import "./cbor/DataCapCbor.sol";
 
 import "./utils/Actor.sol";
@@ -43,7 +44,7 @@ contract DataCapAPI {
     function name() public returns (string memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(DataCapTypes.NameMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Name, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -53,7 +54,7 @@ contract DataCapAPI {
     function symbol() public returns (string memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(DataCapTypes.SymbolMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Symbol, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -63,7 +64,7 @@ contract DataCapAPI {
     function total_supply() public returns (int256) {
         bytes memory raw_request = BytesCBOR.serializeNull();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.TotalSupplyMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.TotalSupply, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -73,7 +74,7 @@ contract DataCapAPI {
     function balance(bytes memory addr) public returns (int256) {
         bytes memory raw_request = addr.serializeAddress();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.BalanceOfMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Balance, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -83,7 +84,7 @@ contract DataCapAPI {
     function allowance(DataCapTypes.GetAllowanceParams memory params) public returns (int256) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.AllowanceMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Allowance, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -93,7 +94,7 @@ contract DataCapAPI {
     function transfer(DataCapTypes.TransferParams memory params) public returns (DataCapTypes.TransferReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.TransferMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Transfer, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -106,7 +107,7 @@ contract DataCapAPI {
     function transfer_from(DataCapTypes.TransferFromParams memory params) public returns (DataCapTypes.TransferFromReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.TransferFromMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.TransferFrom, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -119,7 +120,7 @@ contract DataCapAPI {
     function increase_allowance(DataCapTypes.IncreaseAllowanceParams memory params) public returns (int256) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.IncreaseAllowanceMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.IncreaseAllowance, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -129,7 +130,7 @@ contract DataCapAPI {
     function decrease_allowance(DataCapTypes.DecreaseAllowanceParams memory params) public returns (int256) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.DecreaseAllowanceMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.DecreaseAllowance, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -139,7 +140,7 @@ contract DataCapAPI {
     function revoke_allowance(DataCapTypes.RevokeAllowanceParams memory params) public returns (int256) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.RevokeAllowanceMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.RevokeAllowance, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -149,7 +150,7 @@ contract DataCapAPI {
     function burn(DataCapTypes.BurnParams memory params) public returns (DataCapTypes.BurnReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.BurnMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.Burn, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -162,7 +163,7 @@ contract DataCapAPI {
     function burn_from(DataCapTypes.BurnFromParams memory params) public returns (DataCapTypes.BurnFromReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(DataCapTypes.BurnFromMethodNum, DataCapTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(DataCapMethodNums.BurnFrom, DataCapTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./types/InitTypes.sol";
+import "./types/InitMethodNums.sol";
 import "./cbor/InitCbor.sol";
 import "./types/CommonTypes.sol";
 import "./utils/Misc.sol";
@@ -36,7 +37,7 @@ contract InitAPI {
     function exec(InitTypes.ExecParams memory params) public returns (InitTypes.ExecReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(InitTypes.ExecMethodNum, InitTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(InitMethodNums.Exec, InitTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -50,7 +51,7 @@ contract InitAPI {
     function exec4(InitTypes.Exec4Params memory params) public returns (InitTypes.Exec4Return memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(InitTypes.Exec4MethodNum, InitTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(InitMethodNums.Exec4, InitTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
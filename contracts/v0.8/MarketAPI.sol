@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./types/MarketTypes.sol";
+import "./types/MarketMethodNums.sol";
 import "./cbor/MarketCbor.sol";
 import "./types/CommonTypes.sol";
 import "./utils/Misc.sol";
@@ -58,7 +59,7 @@ contract MarketAPI {
     function add_balance(bytes memory provider_or_client) public payable {
         bytes memory raw_request = provider_or_client.serializeAddress();
 
-        bytes memory raw_response = Actor.call(MarketTypes.AddBalanceMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.AddBalance, MarketTypes.ActorCode, raw_request);
 
         Actor.readRespData(raw_response);
 
@@ -70,7 +71,7 @@ contract MarketAPI {
     function withdraw_balance(MarketTypes.WithdrawBalanceParams memory params) public returns (MarketTypes.WithdrawBalanceReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.WithdrawBalanceMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.WithdrawBalance, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -84,7 +85,7 @@ contract MarketAPI {
     function get_balance(bytes memory addr) public returns (MarketTypes.GetBalanceReturn memory) {
         bytes memory raw_request = addr.serializeAddress();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetBalanceMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetBalance, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -101,7 +102,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.GetDealDataCommitmentReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealDataCommitmentMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealDataCommitment, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -115,7 +116,7 @@ contract MarketAPI {
     function get_deal_client(MarketTypes.GetDealClientParams memory params) public returns (MarketTypes.GetDealClientReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealClientMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealClient, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -129,7 +130,7 @@ contract MarketAPI {
     function get_deal_provider(MarketTypes.GetDealProviderParams memory params) public returns (MarketTypes.GetDealProviderReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealProviderMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealProvider, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -143,7 +144,7 @@ contract MarketAPI {
     function get_deal_label(MarketTypes.GetDealLabelParams memory params) public returns (MarketTypes.GetDealLabelReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealLabelMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealLabel, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -157,7 +158,7 @@ contract MarketAPI {
     function get_deal_term(MarketTypes.GetDealTermParams memory params) public returns (MarketTypes.GetDealTermReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealTermMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealTerm, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -173,7 +174,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.GetDealEpochPriceReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealEpochPriceMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealTotalPrice, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -189,7 +190,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.GetDealClientCollateralReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealClientCollateralMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealClientCollateral, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -205,7 +206,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.GetDealProviderCollateralReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealProviderCollateralMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealProviderCollateral, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -220,7 +221,7 @@ contract MarketAPI {
     function get_deal_verified(MarketTypes.GetDealVerifiedParams memory params) public returns (MarketTypes.GetDealVerifiedReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealVerifiedMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealVerified, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -238,7 +239,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.GetDealActivationReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.GetDealActivationMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.GetDealActivation, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -254,7 +255,7 @@ contract MarketAPI {
     ) public returns (MarketTypes.PublishStorageDealsReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MarketTypes.PublishStorageDealsMethodNum, MarketTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(MarketMethodNums.PublishStorageDeals, MarketTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
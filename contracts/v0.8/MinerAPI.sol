@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./types/MinerTypes.sol";
+import "./types/MinerMethodNums.sol";
 import "./cbor/MinerCbor.sol";
 import "./utils/Misc.sol";
 import "./utils/Actor.sol";
@@ -49,7 +50,7 @@ contract MinerAPI {
     function get_owner(bytes memory target) public returns (MinerTypes.GetOwnerReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetOwnerMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetOwner, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -66,7 +67,7 @@ contract MinerAPI {
     function change_owner_address(bytes memory target, bytes memory addr) public {
         bytes memory raw_request = addr.serializeAddress();
 
-        bytes memory raw_response = Actor.call(MinerTypes.ChangeOwnerAddressMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ChangeOwnerAddress, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -82,7 +83,7 @@ contract MinerAPI {
     ) public returns (MinerTypes.IsControllingAddressReturn memory) {
         bytes memory raw_request = addr.serializeAddress();
 
-        bytes memory raw_response = Actor.call(MinerTypes.IsControllingAddressMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.IsControllingAddress, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -98,7 +99,7 @@ contract MinerAPI {
     function get_sector_size(bytes memory target) public returns (MinerTypes.GetSectorSizeReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetSectorSizeMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetSectorSize, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -115,7 +116,7 @@ contract MinerAPI {
     function get_available_balance(bytes memory target) public returns (MinerTypes.GetAvailableBalanceReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetAvailableBalanceMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetAvailableBalance, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -130,7 +131,7 @@ contract MinerAPI {
     function get_vesting_funds(bytes memory target) public returns (MinerTypes.GetVestingFundsReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetVestingFundsMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetVestingFunds, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -147,7 +148,7 @@ contract MinerAPI {
     function change_beneficiary(bytes memory target, MinerTypes.ChangeBeneficiaryParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MinerTypes.ChangeBeneficiaryMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ChangeBeneficiary, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -160,7 +161,7 @@ contract MinerAPI {
     function get_beneficiary(bytes memory target) public returns (MinerTypes.GetBeneficiaryReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetBeneficiaryMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetBeneficiary, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -175,7 +176,7 @@ contract MinerAPI {
     function change_worker_address(bytes memory target, MinerTypes.ChangeWorkerAddressParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MinerTypes.ChangeWorkerAddressMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ChangeWorkerAddress, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -187,7 +188,7 @@ contract MinerAPI {
     function change_peer_id(bytes memory target, MinerTypes.ChangePeerIDParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MinerTypes.ChangePeerIDMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ChangePeerID, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -199,7 +200,7 @@ contract MinerAPI {
     function change_multiaddresses(bytes memory target, MinerTypes.ChangeMultiaddrsParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MinerTypes.ChangeMultiaddrsMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ChangeMultiaddrs, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -211,7 +212,7 @@ contract MinerAPI {
     function repay_debt(bytes memory target) public {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.RepayDebtMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.RepayDebt, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -223,7 +224,7 @@ contract MinerAPI {
     function confirm_change_worker_address(bytes memory target) public {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.ConfirmChangeWorkerAddressMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.ConfirmChangeWorkerAddress, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -235,7 +236,7 @@ contract MinerAPI {
     function get_peer_id(bytes memory target) public returns (MinerTypes.GetPeerIDReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetPeerIDMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetPeerID, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -250,7 +251,7 @@ contract MinerAPI {
     function get_multiaddresses(bytes memory target) public returns (MinerTypes.GetMultiaddrsReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(MinerTypes.GetMultiaddrsMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MinerMethodNums.GetMultiaddrs, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./types/MultisigTypes.sol";
+import "./types/MultisigMethodNums.sol";
 import "./cbor/MultisigCbor.sol";
 import "./types/CommonTypes.sol";
 import "./utils/Misc.sol";
@@ -42,7 +43,7 @@ contract MultisigAPI {
     function propose(bytes memory target, MultisigTypes.ProposeParams memory params) public returns (MultisigTypes.ProposeReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.ProposeMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.Propose, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -56,7 +57,7 @@ contract MultisigAPI {
     function approve(bytes memory target, MultisigTypes.TxnIDParams memory params) public returns (MultisigTypes.ApproveReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.ApproveMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.Approve, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -70,7 +71,7 @@ contract MultisigAPI {
     function cancel(bytes memory target, MultisigTypes.TxnIDParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.CancelMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.Cancel, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -79,7 +80,7 @@ contract MultisigAPI {
     function add_signer(bytes memory target, MultisigTypes.AddSignerParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.AddSignerMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.AddSigner, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -88,7 +89,7 @@ contract MultisigAPI {
     function remove_signer(bytes memory target, MultisigTypes.RemoveSignerParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.RemoveSignerMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.RemoveSigner, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -97,7 +98,7 @@ contract MultisigAPI {
     function swap_signer(bytes memory target, MultisigTypes.SwapSignerParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.SwapSignerMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.SwapSigner, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -106,7 +107,7 @@ contract MultisigAPI {
     function swap_signer(bytes memory target, MultisigTypes.ChangeNumApprovalsThresholdParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.ChangeNumApprovalsThresholdMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.ChangeNumApprovalsThreshold, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -115,7 +116,7 @@ contract MultisigAPI {
     function lock_balance(bytes memory target, MultisigTypes.LockBalanceParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.LockBalanceMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.LockBalance, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
@@ -124,7 +125,7 @@ contract MultisigAPI {
     function universal_receiver_hook(bytes memory target, bytes memory params) public {
         bytes memory raw_request = params.serializeBytes();
 
-        bytes memory raw_response = Actor.call(MultisigTypes.UniversalReceiverHookMethodNum, target, raw_request);
+        bytes memory raw_response = Actor.call(MultisigMethodNums.Receive, target, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
     }
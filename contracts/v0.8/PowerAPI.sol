@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./types/PowerTypes.sol";
+import "./types/PowerMethodNums.sol";
 import "./cbor/PowerCbor.sol";
 
 import "./utils/Actor.sol";
@@ -37,7 +38,7 @@ contract PowerAPI {
     function create_miner(PowerTypes.CreateMinerParams memory params) public returns (PowerTypes.CreateMinerReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(PowerTypes.CreateMinerMethodNum, PowerTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(PowerMethodNums.CreateMiner, PowerTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -50,7 +51,7 @@ contract PowerAPI {
     function miner_count() public returns (PowerTypes.MinerCountReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(PowerTypes.MinerCountMethodNum, PowerTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(PowerMethodNums.MinerCount, PowerTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -63,7 +64,7 @@ contract PowerAPI {
     function miner_consensus_count() public returns (PowerTypes.MinerConsensusCountReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(PowerTypes.MinerConsensusCountMethodNum, PowerTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(PowerMethodNums.MinerConsensusCount, PowerTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -76,7 +77,7 @@ contract PowerAPI {
     function network_raw_power() public returns (PowerTypes.NetworkRawPowerReturn memory) {
         bytes memory raw_request = new bytes(0);
 
-        bytes memory raw_response = Actor.call(PowerTypes.NetworkRawPowerMethodNum, PowerTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(PowerMethodNums.NetworkRawPower, PowerTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -89,7 +90,7 @@ contract PowerAPI {
     function miner_raw_power(PowerTypes.MinerRawPowerParams memory params) public returns (PowerTypes.MinerRawPowerReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(PowerTypes.MinerRawPowerMethodNum, PowerTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(PowerMethodNums.MinerRawPower, PowerTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -19,6 +19,7 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./types/VerifRegTypes.sol";
+import "./types/VerifRegMethodNums.sol";
 import "./cbor/VerifRegCbor.sol";
 
 import "./utils/Actor.sol";
@@ -41,7 +42,7 @@ contract VerifRegAPI {
     function get_claims(VerifRegTypes.GetClaimsParams memory params) public returns (VerifRegTypes.GetClaimsReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.GetClaimsMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.GetClaims, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -54,7 +55,7 @@ contract VerifRegAPI {
     function add_verified_client(VerifRegTypes.AddVerifierClientParams memory params) public {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.AddVerifierClientMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.AddVerifiedClient, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -66,7 +67,7 @@ contract VerifRegAPI {
     ) public returns (VerifRegTypes.RemoveExpiredAllocationsReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.RemoveExpiredAllocationsMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.RemoveExpiredAllocations, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -79,7 +80,7 @@ contract VerifRegAPI {
     function extend_claim_terms(VerifRegTypes.ExtendClaimTermsParams memory params) public returns (CommonTypes.BatchReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.ExtendClaimTermsMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.ExtendClaimTerms, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -94,7 +95,7 @@ contract VerifRegAPI {
     ) public returns (VerifRegTypes.RemoveExpiredClaimsReturn memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.RemoveExpiredClaimsMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.RemoveExpiredClaims, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
@@ -109,7 +110,7 @@ contract VerifRegAPI {
     ) public returns (VerifRegTypes.AllocationsResponse memory) {
         bytes memory raw_request = params.serialize();
 
-        bytes memory raw_response = Actor.call(VerifRegTypes.UniversalReceiverMethodNum, VerifRegTypes.ActorCode, raw_request);
+        bytes memory raw_response = Actor.call(VerifRegMethodNums.Receive, VerifRegTypes.ActorCode, raw_request);
 
         bytes memory result = Actor.readRespData(raw_response);
 
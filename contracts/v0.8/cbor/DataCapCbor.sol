@@ -73,7 +73,7 @@ library BytesCBOR {
         uint len;
 
         (tmp, byteIdx) = ret.readBytes(byteIdx);
-        return Misc.toInt256(tmp, 0);
+        return Misc.toBigInt(tmp);
     }
 }
 
@@ -131,7 +131,7 @@ library TransferCBOR {
 
         buf.startFixedArray(3);
         buf.writeBytes(params.to);
-        buf.writeBytes(Misc.toBytes(params.amount));
+        buf.writeBytes(Misc.toBigIntBytes(params.amount));
         buf.writeBytes(params.operator_data);
 
         return buf.data();
@@ -146,10 +146,10 @@ library TransferCBOR {
         assert(len == 3);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.from_balance = Misc.toInt256(tmp, 0);
+        ret.from_balance = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.to_balance = Misc.toInt256(tmp, 0);
+        ret.to_balance = Misc.toBigInt(tmp);
 
         (ret.recipient_data, byteIdx) = rawResp.readBytes(byteIdx);
     }
@@ -168,7 +168,7 @@ library TransferFromCBOR {
         buf.startFixedArray(4);
         buf.writeBytes(params.from);
         buf.writeBytes(params.to);
-        buf.writeBytes(Misc.toBytes(params.amount));
+        buf.writeBytes(Misc.toBigIntBytes(params.amount));
         buf.writeBytes(params.operator_data);
 
         return buf.data();
@@ -183,13 +183,13 @@ library TransferFromCBOR {
         assert(len == 3);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.from_balance = Misc.toInt256(tmp, 0);
+        ret.from_balance = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.to_balance = Misc.toInt256(tmp, 0);
+        ret.to_balance = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.allowance = Misc.toInt256(tmp, 0);
+        ret.allowance = Misc.toBigInt(tmp);
 
         (ret.recipient_data, byteIdx) = rawResp.readBytes(byteIdx);
     }
@@ -207,7 +207,7 @@ library IncreaseAllowanceCBOR {
 
         buf.startFixedArray(2);
         buf.writeBytes(params.operator);
-        buf.writeBytes(Misc.toBytes(params.increase));
+        buf.writeBytes(Misc.toBigIntBytes(params.increase));
 
         return buf.data();
     }
@@ -225,7 +225,7 @@ library DecreaseAllowanceCBOR {
 
         buf.startFixedArray(2);
         buf.writeBytes(params.operator);
-        buf.writeBytes(Misc.toBytes(params.decrease));
+        buf.writeBytes(Misc.toBigIntBytes(params.decrease));
 
         return buf.data();
     }
@@ -259,7 +259,7 @@ library BurnCBOR {
         CBOR.CBORBuffer memory buf = CBOR.create(64);
 
         buf.startFixedArray(1);
-        buf.writeBytes(Misc.toBytes(params.amount));
+        buf.writeBytes(Misc.toBigIntBytes(params.amount));
 
         return buf.data();
     }
@@ -273,7 +273,7 @@ library BurnCBOR {
         assert(len == 1);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.balance = Misc.toInt256(tmp, 0);
+        ret.balance = Misc.toBigInt(tmp);
     }
 }
 
@@ -289,7 +289,7 @@ library BurnFromCBOR {
 
         buf.startFixedArray(2);
         buf.writeBytes(params.owner);
-        buf.writeBytes(Misc.toBytes(params.amount));
+        buf.writeBytes(Misc.toBigIntBytes(params.amount));
 
         return buf.data();
     }
@@ -303,9 +303,9 @@ library BurnFromCBOR {
         assert(len == 2);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.balance = Misc.toInt256(tmp, 0);
+        ret.balance = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.allowance = Misc.toInt256(tmp, 0);
+        ret.allowance = Misc.toBigInt(tmp);
     }
 }
@@ -36,7 +36,7 @@ library WithdrawBalanceCBOR {
 
         buf.startFixedArray(2);
         buf.writeBytes(params.provider_or_client);
-        buf.writeBytes(Misc.toBytes(params.tokenAmount));
+        buf.writeBytes(Misc.toBigIntBytes(params.tokenAmount));
 
         return buf.data();
     }
@@ -47,7 +47,7 @@ library WithdrawBalanceCBOR {
         uint len;
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.amount_withdrawn = Misc.toUint256(tmp, 0);
+        ret.amount_withdrawn = Misc.toBigUint(tmp);
     }
 }
 
@@ -87,10 +87,10 @@ library GetBalanceCBOR {
         assert(len == 2);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.balance = Misc.toInt256(tmp, 0);
+        ret.balance = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.locked = Misc.toInt256(tmp, 0);
+        ret.locked = Misc.toBigInt(tmp);
     }
 }
 
@@ -254,7 +254,7 @@ library GetDealEpochPriceCBOR {
 
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.price_per_epoch = Misc.toUint256(tmp, 0);
+        ret.price_per_epoch = Misc.toBigUint(tmp);
     }
 }
 
@@ -280,7 +280,7 @@ library GetDealClientCollateralCBOR {
         assert(len == 2);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.collateral = Misc.toUint256(tmp, 0);
+        ret.collateral = Misc.toBigUint(tmp);
     }
 }
 
@@ -307,7 +307,7 @@ library GetDealProviderCollateralCBOR {
 
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.collateral = Misc.toUint256(tmp, 0);
+        ret.collateral = Misc.toBigUint(tmp);
     }
 }
 
@@ -390,9 +390,9 @@ library PublishStorageDealsCBOR {
             buf.writeString(params.deals[i].proposal.label);
             buf.writeInt64(params.deals[i].proposal.start_epoch);
             buf.writeInt64(params.deals[i].proposal.end_epoch);
-            buf.writeBytes(Misc.toBytes(uint256(params.deals[i].proposal.storage_price_per_epoch)));
-            buf.writeBytes(Misc.toBytes(uint256(params.deals[i].proposal.provider_collateral)));
-            buf.writeBytes(Misc.toBytes(uint256(params.deals[i].proposal.client_collateral)));
+            buf.writeBytes(Misc.toBigIntBytes(params.deals[i].proposal.storage_price_per_epoch));
+            buf.writeBytes(Misc.toBigIntBytes(params.deals[i].proposal.provider_collateral));
+            buf.writeBytes(Misc.toBigIntBytes(params.deals[i].proposal.client_collateral));
 
             buf.writeBytes(params.deals[i].client_signature);
         }
@@ -37,7 +37,7 @@ library ChangeBeneficiaryCBOR {
 
         buf.startFixedArray(3);
         buf.writeBytes(params.new_beneficiary);
-        buf.writeBytes(Misc.toBytes(uint256(params.new_quota)));
+        buf.writeBytes(Misc.toBigIntBytes(params.new_quota));
         buf.writeUInt64(params.new_expiration);
 
         return buf.data();
@@ -91,7 +91,7 @@ library GetAvailableBalanceCBOR {
 
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.available_balance = int256(Misc.toUint256(tmp, 0));
+        ret.available_balance = Misc.toBigInt(tmp);
     }
 }
 
@@ -140,10 +140,10 @@ library GetBeneficiaryCBOR {
         assert(len == 3);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.active.term.quota = int256(Misc.toUint256(tmp, 0));
+        ret.active.term.quota = Misc.toBigInt(tmp);
 
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.active.term.used_quota = int256(Misc.toUint256(tmp, 0));
+        ret.active.term.used_quota = Misc.toBigInt(tmp);
 
         (ret.active.term.expiration, byteIdx) = rawResp.readUInt64(byteIdx);
 
@@ -182,7 +182,7 @@ library GetVestingFundsCBOR {
             (epoch, byteIdx) = rawResp.readInt64(byteIdx);
             (tmp, byteIdx) = rawResp.readBytes(byteIdx);
 
-            amount = int256(Misc.toUint256(tmp, 0));
+            amount = Misc.toBigInt(tmp);
             ret.vesting_funds[i] = CommonTypes.VestingFunds(epoch, amount);
         }
     }
@@ -50,7 +50,7 @@ library ProposeCBOR {
 
         buf.startFixedArray(4);
         buf.writeBytes(params.to);
-        buf.writeBytes(Misc.toBytes(params.value));
+        buf.writeBytes(Misc.toBigIntBytes(params.value));
         buf.writeUInt64(params.method);
         buf.writeBytes(params.params);
 
@@ -192,7 +192,7 @@ library LockBalanceCBOR {
         buf.startFixedArray(3);
         buf.writeInt64(params.start_epoch);
         buf.writeInt64(params.unlock_duration);
-        buf.writeBytes(Misc.toBytes(params.amount));
+        buf.writeBytes(Misc.toBigIntBytes(params.amount));
 
         return buf.data();
     }
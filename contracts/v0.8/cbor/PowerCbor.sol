@@ -102,7 +102,7 @@ library NetworkRawPowerCBOR {
 
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.raw_byte_power = Misc.toInt256(tmp, 0);
+        ret.raw_byte_power = Misc.toBigInt(tmp);
     }
 }
 
@@ -130,7 +130,7 @@ library MinerRawPowerCBOR {
 
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.raw_byte_power = int256(Misc.toUint256(tmp, 0));
+        ret.raw_byte_power = Misc.toBigInt(tmp);
 
         (ret.meets_consensus_minimum, byteIdx) = rawResp.readBool(byteIdx);
     }
@@ -159,7 +159,7 @@ library RemoveExpiredAllocationsCBOR {
 
         bytes memory tmp;
         (tmp, byteIdx) = rawResp.readBytes(byteIdx);
-        ret.datacap_recovered = int256(Misc.toUint256(tmp, 0));
+        ret.datacap_recovered = Misc.toBigInt(tmp);
     }
 }
 
@@ -0,0 +1,32 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import "../utils/Actor.sol";
+
+/// @title Exposes Actor.call to integration tests, with any flags.
+/// @author Zondax AG
+contract ActorCallTest {
+    /// @notice Calls any actor method with raw CBOR params and the given call flags, and returns the raw return data.
+    function call_actor(uint method_num, bytes memory actor_code, bytes memory raw_request, uint64 flags) public payable returns (bytes memory) {
+        bytes memory raw_response = Actor.callWithFlags(method_num, actor_code, raw_request, flags);
+
+        return Actor.readRespData(raw_response);
+    }
+}
@@ -0,0 +1,91 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import "../utils/CborDecode.sol";
+
+/// @title Exposes the CborDecode.sol readers so integration tests can fuzz them.
+/// @author Zondax AG
+contract CborDecodeTest {
+    using CBORDecoder for bytes;
+
+    function is_null_next(bytes memory raw, uint idx) public pure returns (bool) {
+        return raw.isNullNext(idx);
+    }
+
+    function read_bool(bytes memory raw, uint idx) public pure returns (bool, uint) {
+        return raw.readBool(idx);
+    }
+
+    function read_fixed_array(bytes memory raw, uint idx) public pure returns (uint, uint) {
+        return raw.readFixedArray(idx);
+    }
+
+    function read_string(bytes memory raw, uint idx) public pure returns (string memory, uint) {
+        return raw.readString(idx);
+    }
+
+    function read_bytes(bytes memory raw, uint idx) public pure returns (bytes memory, uint) {
+        return raw.readBytes(idx);
+    }
+
+    function read_uint256(bytes memory raw, uint idx) public pure returns (uint256, uint) {
+        return raw.readUInt256(idx);
+    }
+
+    function read_int256(bytes memory raw, uint idx) public pure returns (int256, uint) {
+        return raw.readInt256(idx);
+    }
+
+    function read_uint64(bytes memory raw, uint idx) public pure returns (uint64, uint) {
+        return raw.readUInt64(idx);
+    }
+
+    function read_uint32(bytes memory raw, uint idx) public pure returns (uint32, uint) {
+        return raw.readUInt32(idx);
+    }
+
+    function read_uint16(bytes memory raw, uint idx) public pure returns (uint16, uint) {
+        return raw.readUInt16(idx);
+    }
+
+    function read_uint8(bytes memory raw, uint idx) public pure returns (uint8, uint) {
+        return raw.readUInt8(idx);
+    }
+
+    function read_int64(bytes memory raw, uint idx) public pure returns (int64, uint) {
+        return raw.readInt64(idx);
+    }
+
+    function read_int32(bytes memory raw, uint idx) public pure returns (int32, uint) {
+        return raw.readInt32(idx);
+    }
+
+    function read_int16(bytes memory raw, uint idx) public pure returns (int16, uint) {
+        return raw.readInt16(idx);
+    }
+
+    function read_int8(bytes memory raw, uint idx) public pure returns (int8, uint) {
+        return raw.readInt8(idx);
+    }
+
+    function parse_cbor_header(bytes memory raw, uint idx) public pure returns (uint8, uint64, uint) {
+        return raw.parseCborHeader(idx);
+    }
+}
@@ -0,0 +1,108 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {DataCapTypes} from "../types/DataCapTypes.sol";
+import "../cbor/DataCapCbor.sol";
+
+/// @title Exposes the DataCapCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract DataCapCodecTest {
+    using BytesCBOR for bytes;
+    using GetAllowanceCBOR for DataCapTypes.GetAllowanceParams;
+    using TransferCBOR for DataCapTypes.TransferParams;
+    using TransferCBOR for DataCapTypes.TransferReturn;
+    using TransferFromCBOR for DataCapTypes.TransferFromParams;
+    using TransferFromCBOR for DataCapTypes.TransferFromReturn;
+    using IncreaseAllowanceCBOR for DataCapTypes.IncreaseAllowanceParams;
+    using DecreaseAllowanceCBOR for DataCapTypes.DecreaseAllowanceParams;
+    using RevokeAllowanceCBOR for DataCapTypes.RevokeAllowanceParams;
+    using BurnCBOR for DataCapTypes.BurnParams;
+    using BurnCBOR for DataCapTypes.BurnReturn;
+    using BurnFromCBOR for DataCapTypes.BurnFromParams;
+    using BurnFromCBOR for DataCapTypes.BurnFromReturn;
+
+    function serialize_address(bytes memory addr) public pure returns (bytes memory) {
+        return addr.serializeAddress();
+    }
+
+    function serialize_null() public pure returns (bytes memory) {
+        return BytesCBOR.serializeNull();
+    }
+
+    function deserialize_address(bytes memory raw) public pure returns (bytes memory) {
+        return raw.deserializeAddress();
+    }
+
+    function deserialize_string(bytes memory raw) public pure returns (string memory) {
+        return raw.deserializeString();
+    }
+
+    function deserialize_int256(bytes memory raw) public pure returns (int256) {
+        return raw.deserializeInt256();
+    }
+
+    function serialize_get_allowance(DataCapTypes.GetAllowanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_transfer(DataCapTypes.TransferParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_transfer(bytes memory raw) public pure returns (DataCapTypes.TransferReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_transfer_from(DataCapTypes.TransferFromParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_transfer_from(bytes memory raw) public pure returns (DataCapTypes.TransferFromReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_increase_allowance(DataCapTypes.IncreaseAllowanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_decrease_allowance(DataCapTypes.DecreaseAllowanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_revoke_allowance(DataCapTypes.RevokeAllowanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_burn(DataCapTypes.BurnParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_burn(bytes memory raw) public pure returns (DataCapTypes.BurnReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_burn_from(DataCapTypes.BurnFromParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_burn_from(bytes memory raw) public pure returns (DataCapTypes.BurnFromReturn memory ret) {
+        ret.deserialize(raw);
+    }
+}
@@ -0,0 +1,47 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {InitTypes} from "../types/InitTypes.sol";
+import "../cbor/InitCbor.sol";
+
+/// @title Exposes the InitCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract InitCodecTest {
+    using ExecCBOR for InitTypes.ExecParams;
+    using ExecCBOR for InitTypes.ExecReturn;
+    using Exec4CBOR for InitTypes.Exec4Params;
+    using Exec4CBOR for InitTypes.Exec4Return;
+
+    function serialize_exec(InitTypes.ExecParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_exec(bytes memory raw) public pure returns (InitTypes.ExecReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_exec4(InitTypes.Exec4Params memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_exec4(bytes memory raw) public pure returns (InitTypes.Exec4Return memory ret) {
+        ret.deserialize(raw);
+    }
+}
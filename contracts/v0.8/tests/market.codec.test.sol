@@ -0,0 +1,161 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {MarketTypes} from "../types/MarketTypes.sol";
+import "../cbor/MarketCbor.sol";
+
+/// @title Exposes the MarketCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract MarketCodecTest {
+    using AddressCBOR for bytes;
+    using WithdrawBalanceCBOR for MarketTypes.WithdrawBalanceParams;
+    using WithdrawBalanceCBOR for MarketTypes.WithdrawBalanceReturn;
+    using GetBalanceCBOR for MarketTypes.GetBalanceReturn;
+    using GetDealDataCommitmentCBOR for MarketTypes.GetDealDataCommitmentParams;
+    using GetDealDataCommitmentCBOR for MarketTypes.GetDealDataCommitmentReturn;
+    using GetDealClientCBOR for MarketTypes.GetDealClientParams;
+    using GetDealClientCBOR for MarketTypes.GetDealClientReturn;
+    using GetDealProviderCBOR for MarketTypes.GetDealProviderParams;
+    using GetDealProviderCBOR for MarketTypes.GetDealProviderReturn;
+    using GetDealLabelCBOR for MarketTypes.GetDealLabelParams;
+    using GetDealLabelCBOR for MarketTypes.GetDealLabelReturn;
+    using GetDealTermCBOR for MarketTypes.GetDealTermParams;
+    using GetDealTermCBOR for MarketTypes.GetDealTermReturn;
+    using GetDealEpochPriceCBOR for MarketTypes.GetDealEpochPriceParams;
+    using GetDealEpochPriceCBOR for MarketTypes.GetDealEpochPriceReturn;
+    using GetDealClientCollateralCBOR for MarketTypes.GetDealClientCollateralParams;
+    using GetDealClientCollateralCBOR for MarketTypes.GetDealClientCollateralReturn;
+    using GetDealProviderCollateralCBOR for MarketTypes.GetDealProviderCollateralParams;
+    using GetDealProviderCollateralCBOR for MarketTypes.GetDealProviderCollateralReturn;
+    using GetDealVerifiedCBOR for MarketTypes.GetDealVerifiedParams;
+    using GetDealVerifiedCBOR for MarketTypes.GetDealVerifiedReturn;
+    using GetDealActivationCBOR for MarketTypes.GetDealActivationParams;
+    using GetDealActivationCBOR for MarketTypes.GetDealActivationReturn;
+    using PublishStorageDealsCBOR for MarketTypes.PublishStorageDealsParams;
+    using PublishStorageDealsCBOR for MarketTypes.PublishStorageDealsReturn;
+
+    function serialize_address(bytes memory addr) public pure returns (bytes memory) {
+        return addr.serializeAddress();
+    }
+
+    function deserialize_address(bytes memory raw) public pure returns (bytes memory) {
+        return raw.deserializeAddress();
+    }
+
+    function serialize_withdraw_balance(MarketTypes.WithdrawBalanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_withdraw_balance(bytes memory raw) public pure returns (MarketTypes.WithdrawBalanceReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_balance(bytes memory raw) public pure returns (MarketTypes.GetBalanceReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_data_commitment(MarketTypes.GetDealDataCommitmentParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_data_commitment(bytes memory raw) public pure returns (MarketTypes.GetDealDataCommitmentReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_client(MarketTypes.GetDealClientParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_client(bytes memory raw) public pure returns (MarketTypes.GetDealClientReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_provider(MarketTypes.GetDealProviderParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_provider(bytes memory raw) public pure returns (MarketTypes.GetDealProviderReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_label(MarketTypes.GetDealLabelParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_label(bytes memory raw) public pure returns (MarketTypes.GetDealLabelReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_term(MarketTypes.GetDealTermParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_term(bytes memory raw) public pure returns (MarketTypes.GetDealTermReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_epoch_price(MarketTypes.GetDealEpochPriceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_epoch_price(bytes memory raw) public pure returns (MarketTypes.GetDealEpochPriceReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_client_collateral(MarketTypes.GetDealClientCollateralParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_client_collateral(bytes memory raw) public pure returns (MarketTypes.GetDealClientCollateralReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_provider_collateral(MarketTypes.GetDealProviderCollateralParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_provider_collateral(bytes memory raw) public pure returns (MarketTypes.GetDealProviderCollateralReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_verified(MarketTypes.GetDealVerifiedParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_verified(bytes memory raw) public pure returns (MarketTypes.GetDealVerifiedReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_get_deal_activation(MarketTypes.GetDealActivationParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_deal_activation(bytes memory raw) public pure returns (MarketTypes.GetDealActivationReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_publish_storage_deals(MarketTypes.PublishStorageDealsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_publish_storage_deals(bytes memory raw) public pure returns (MarketTypes.PublishStorageDealsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+}
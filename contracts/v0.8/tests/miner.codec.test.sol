@@ -0,0 +1,96 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {MinerTypes} from "../types/MinerTypes.sol";
+import "../cbor/MinerCbor.sol";
+
+/// @title Exposes the MinerCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract MinerCodecTest {
+    using AddressCBOR for bytes;
+    using ChangeBeneficiaryCBOR for MinerTypes.ChangeBeneficiaryParams;
+    using GetOwnerCBOR for MinerTypes.GetOwnerReturn;
+    using IsControllingAddressCBOR for MinerTypes.IsControllingAddressReturn;
+    using GetSectorSizeCBOR for MinerTypes.GetSectorSizeReturn;
+    using GetAvailableBalanceCBOR for MinerTypes.GetAvailableBalanceReturn;
+    using GetBeneficiaryCBOR for MinerTypes.GetBeneficiaryReturn;
+    using GetVestingFundsCBOR for MinerTypes.GetVestingFundsReturn;
+    using ChangeWorkerAddressCBOR for MinerTypes.ChangeWorkerAddressParams;
+    using ChangePeerIDCBOR for MinerTypes.ChangePeerIDParams;
+    using ChangeMultiaddrsCBOR for MinerTypes.ChangeMultiaddrsParams;
+    using GetPeerIDCBOR for MinerTypes.GetPeerIDReturn;
+    using GetMultiaddrsCBOR for MinerTypes.GetMultiaddrsReturn;
+
+    function serialize_address(bytes memory addr) public pure returns (bytes memory) {
+        return addr.serializeAddress();
+    }
+
+    function deserialize_address(bytes memory raw) public pure returns (bytes memory) {
+        return raw.deserializeAddress();
+    }
+
+    function serialize_change_beneficiary(MinerTypes.ChangeBeneficiaryParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_owner(bytes memory raw) public pure returns (MinerTypes.GetOwnerReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_is_controlling_address(bytes memory raw) public pure returns (MinerTypes.IsControllingAddressReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_sector_size(bytes memory raw) public pure returns (MinerTypes.GetSectorSizeReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_available_balance(bytes memory raw) public pure returns (MinerTypes.GetAvailableBalanceReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_beneficiary(bytes memory raw) public pure returns (MinerTypes.GetBeneficiaryReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_vesting_funds(bytes memory raw) public pure returns (MinerTypes.GetVestingFundsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_change_worker_address(MinerTypes.ChangeWorkerAddressParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_change_peer_id(MinerTypes.ChangePeerIDParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_change_multiaddrs(MinerTypes.ChangeMultiaddrsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_peer_id(bytes memory raw) public pure returns (MinerTypes.GetPeerIDReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_get_multiaddrs(bytes memory raw) public pure returns (MinerTypes.GetMultiaddrsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+}
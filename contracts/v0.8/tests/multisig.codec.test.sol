@@ -0,0 +1,77 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {MultisigTypes} from "../types/MultisigTypes.sol";
+import "../cbor/MultisigCbor.sol";
+
+/// @title Exposes the MultisigCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract MultisigCodecTest {
+    using BytesCBOR for bytes;
+    using ProposeCBOR for MultisigTypes.ProposeParams;
+    using ProposeCBOR for MultisigTypes.ProposeReturn;
+    using TxnIDCBOR for MultisigTypes.TxnIDParams;
+    using ApproveCBOR for MultisigTypes.ApproveReturn;
+    using AddSignerCBOR for MultisigTypes.AddSignerParams;
+    using RemoveSignerCBOR for MultisigTypes.RemoveSignerParams;
+    using SwapSignerCBOR for MultisigTypes.SwapSignerParams;
+    using ChangeNumApprovalsThresholdCBOR for MultisigTypes.ChangeNumApprovalsThresholdParams;
+    using LockBalanceCBOR for MultisigTypes.LockBalanceParams;
+
+    function serialize_bytes(bytes memory data) public pure returns (bytes memory) {
+        return data.serializeBytes();
+    }
+
+    function serialize_propose(MultisigTypes.ProposeParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_propose(bytes memory raw) public pure returns (MultisigTypes.ProposeReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_txn_id(MultisigTypes.TxnIDParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_approve(bytes memory raw) public pure returns (MultisigTypes.ApproveReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_add_signer(MultisigTypes.AddSignerParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_remove_signer(MultisigTypes.RemoveSignerParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_swap_signer(MultisigTypes.SwapSignerParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_change_num_approvals_threshold(MultisigTypes.ChangeNumApprovalsThresholdParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_lock_balance(MultisigTypes.LockBalanceParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+}
@@ -0,0 +1,62 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {PowerTypes} from "../types/PowerTypes.sol";
+import "../cbor/PowerCbor.sol";
+
+/// @title Exposes the PowerCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract PowerCodecTest {
+    using CreateMinerCBOR for PowerTypes.CreateMinerParams;
+    using CreateMinerCBOR for PowerTypes.CreateMinerReturn;
+    using MinerCountCBOR for PowerTypes.MinerCountReturn;
+    using MinerConsensusCountCBOR for PowerTypes.MinerConsensusCountReturn;
+    using NetworkRawPowerCBOR for PowerTypes.NetworkRawPowerReturn;
+    using MinerRawPowerCBOR for PowerTypes.MinerRawPowerParams;
+    using MinerRawPowerCBOR for PowerTypes.MinerRawPowerReturn;
+
+    function serialize_create_miner(PowerTypes.CreateMinerParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_create_miner(bytes memory raw) public pure returns (PowerTypes.CreateMinerReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_miner_count(bytes memory raw) public pure returns (PowerTypes.MinerCountReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_miner_consensus_count(bytes memory raw) public pure returns (PowerTypes.MinerConsensusCountReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function deserialize_network_raw_power(bytes memory raw) public pure returns (PowerTypes.NetworkRawPowerReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_miner_raw_power(PowerTypes.MinerRawPowerParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_miner_raw_power(bytes memory raw) public pure returns (PowerTypes.MinerRawPowerReturn memory ret) {
+        ret.deserialize(raw);
+    }
+}
@@ -0,0 +1,89 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+import {CommonTypes} from "../types/CommonTypes.sol";
+import "../utils/CborDecode.sol";
+
+/// @title Records every universal receiver hook it gets, so integration tests can check what was delivered.
+/// @author Zondax AG
+contract UniversalReceiverTest {
+    using CBORDecoder for bytes;
+
+    // frc42_dispatch::method_hash!("FRC46")
+    uint32 constant FRC46TokenType = 2233613279;
+
+    bool public reject;
+
+    uint public hook_calls;
+    uint64 public last_method;
+    uint32 public last_type;
+    bytes public last_payload;
+
+    // FRC46TokenReceived fields, only filled when last_type is FRC46TokenType
+    uint64 public last_from;
+    uint64 public last_to;
+    uint64 public last_operator;
+    bytes public last_amount;
+    bytes public last_operator_data;
+    bytes public last_token_data;
+
+    function set_reject(bool _reject) public {
+        reject = _reject;
+    }
+
+    /// @notice Entry point the EVM actor uses for any native method call other than InvokeContract.
+    function handle_filecoin_method(uint64 method, uint64, bytes memory params) public returns (uint32, uint64, bytes memory) {
+        require(method == CommonTypes.UniversalReceiverHook, "unexpected method");
+        require(!reject, "receiver hook rejected");
+
+        uint byteIdx = 0;
+        uint len;
+
+        (len, byteIdx) = params.readFixedArray(byteIdx);
+        assert(len == 2);
+
+        (last_type, byteIdx) = params.readUInt32(byteIdx);
+        (last_payload, byteIdx) = params.readBytes(byteIdx);
+
+        if (last_type == FRC46TokenType) {
+            readTokenReceived(last_payload);
+        }
+
+        last_method = method;
+        hook_calls++;
+
+        return (0, 0, new bytes(0));
+    }
+
+    function readTokenReceived(bytes memory payload) internal {
+        uint byteIdx = 0;
+        uint len;
+
+        (len, byteIdx) = payload.readFixedArray(byteIdx);
+        assert(len == 6);
+
+        (last_from, byteIdx) = payload.readUInt64(byteIdx);
+        (last_to, byteIdx) = payload.readUInt64(byteIdx);
+        (last_operator, byteIdx) = payload.readUInt64(byteIdx);
+        (last_amount, byteIdx) = payload.readBytes(byteIdx);
+        (last_operator_data, byteIdx) = payload.readBytes(byteIdx);
+        (last_token_data, byteIdx) = payload.readBytes(byteIdx);
+    }
+}
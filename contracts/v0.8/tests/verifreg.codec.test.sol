@@ -0,0 +1,83 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// DRAFT!! THIS CODE HAS NOT BEEN AUDITED - USE ONLY FOR PROTOTYPING
+//
+pragma solidity >=0.4.25 <=0.8.15;
+
+import {CommonTypes} from "../types/CommonTypes.sol";
+import {VerifRegTypes} from "../types/VerifRegTypes.sol";
+import "../cbor/VerifRegCbor.sol";
+
+/// @title Exposes the VerifRegCbor.sol codecs so integration tests can check them against the actor types.
+/// @author Zondax AG
+contract VerifRegCodecTest {
+    using GetClaimsCBOR for VerifRegTypes.GetClaimsParams;
+    using GetClaimsCBOR for VerifRegTypes.GetClaimsReturn;
+    using AddVerifierClientCBOR for VerifRegTypes.AddVerifierClientParams;
+    using RemoveExpiredAllocationsCBOR for VerifRegTypes.RemoveExpiredAllocationsParams;
+    using RemoveExpiredAllocationsCBOR for VerifRegTypes.RemoveExpiredAllocationsReturn;
+    using ExtendClaimTermsCBOR for VerifRegTypes.ExtendClaimTermsParams;
+    using ExtendClaimTermsCBOR for CommonTypes.BatchReturn;
+    using RemoveExpiredClaimsCBOR for VerifRegTypes.RemoveExpiredClaimsParams;
+    using RemoveExpiredClaimsCBOR for VerifRegTypes.RemoveExpiredClaimsReturn;
+    using UniversalReceiverHookCBOR for VerifRegTypes.UniversalReceiverParams;
+    using UniversalReceiverHookCBOR for VerifRegTypes.AllocationsResponse;
+
+    function serialize_get_claims(VerifRegTypes.GetClaimsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_get_claims(bytes memory raw) public pure returns (VerifRegTypes.GetClaimsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_add_verified_client(VerifRegTypes.AddVerifierClientParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function serialize_remove_expired_allocations(VerifRegTypes.RemoveExpiredAllocationsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_remove_expired_allocations(bytes memory raw) public pure returns (VerifRegTypes.RemoveExpiredAllocationsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_extend_claim_terms(VerifRegTypes.ExtendClaimTermsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_extend_claim_terms(bytes memory raw) public pure returns (CommonTypes.BatchReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_remove_expired_claims(VerifRegTypes.RemoveExpiredClaimsParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_remove_expired_claims(bytes memory raw) public pure returns (VerifRegTypes.RemoveExpiredClaimsReturn memory ret) {
+        ret.deserialize(raw);
+    }
+
+    function serialize_universal_receiver_hook(VerifRegTypes.UniversalReceiverParams memory params) public pure returns (bytes memory) {
+        return params.serialize();
+    }
+
+    function deserialize_universal_receiver_hook(bytes memory raw) public pure returns (VerifRegTypes.AllocationsResponse memory ret) {
+        ret.deserialize(raw);
+    }
+}
@@ -19,12 +19,14 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./CommonTypes.sol";
+import "./AccountMethodNums.sol";
 
 /// @title Filecoin account actor types for Solidity.
 /// @author Zondax AG
 library AccountTypes {
-    uint constant AuthenticateMessageMethodNum = 2643134072;
-    uint constant UniversalReceiverHookMethodNum = 3726118371;
+    /// @dev Deprecated, use AccountMethodNums instead.
+    uint constant AuthenticateMessageMethodNum = AccountMethodNums.AuthenticateMessage;
+    uint constant UniversalReceiverHookMethodNum = AccountMethodNums.Receive;
 
     struct AuthenticateMessageParams {
         bytes signature;
@@ -21,6 +21,9 @@ pragma solidity >=0.4.25 <=0.8.17;
 /// @title Filecoin actors' common types for Solidity.
 /// @author Zondax AG
 library CommonTypes {
+    /// @notice Method number of the FRC-46 universal receiver hook, the same for every actor receiving tokens.
+    uint64 constant UniversalReceiverHook = 3726118371;
+
     enum RegisteredSealProof {
         StackedDRG2KiBV1,
         StackedDRG512MiBV1,
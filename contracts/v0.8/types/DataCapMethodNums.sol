@@ -0,0 +1,39 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin datacap actor method numbers for Solidity.
+/// @author Zondax AG
+library DataCapMethodNums {
+    uint64 constant Allowance = 4205072950;
+    uint64 constant Balance = 3261979605;
+    uint64 constant Burn = 1434719642;
+    uint64 constant BurnFrom = 2979674018;
+    uint64 constant DecreaseAllowance = 1529376545;
+    uint64 constant Destroy = 2624896501;
+    uint64 constant Granularity = 3936767397;
+    uint64 constant IncreaseAllowance = 1777121560;
+    uint64 constant Mint = 116935346;
+    uint64 constant Name = 48890204;
+    uint64 constant RevokeAllowance = 2765635761;
+    uint64 constant Symbol = 2061153854;
+    uint64 constant TotalSupply = 114981429;
+    uint64 constant Transfer = 80475954;
+    uint64 constant TransferFrom = 3621052141;
+}
@@ -19,23 +19,25 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./CommonTypes.sol";
+import "./DataCapMethodNums.sol";
 
 /// @title Filecoin datacap actor types for Solidity.
 /// @author Zondax AG
 library DataCapTypes {
     bytes constant ActorCode = hex"0007";
-    uint constant NameMethodNum = 48890204;
-    uint constant SymbolMethodNum = 2061153854;
-    uint constant TotalSupplyMethodNum = 114981429;
-    uint constant BalanceOfMethodNum = 3261979605;
-    uint constant TransferMethodNum = 80475954;
-    uint constant TransferFromMethodNum = 3621052141;
-    uint constant IncreaseAllowanceMethodNum = 1777121560;
-    uint constant DecreaseAllowanceMethodNum = 1529376545;
-    uint constant RevokeAllowanceMethodNum = 2765635761;
-    uint constant BurnMethodNum = 1434719642;
-    uint constant BurnFromMethodNum = 2979674018;
-    uint constant AllowanceMethodNum = 4205072950;
+    /// @dev Deprecated, use DataCapMethodNums instead.
+    uint constant NameMethodNum = DataCapMethodNums.Name;
+    uint constant SymbolMethodNum = DataCapMethodNums.Symbol;
+    uint constant TotalSupplyMethodNum = DataCapMethodNums.TotalSupply;
+    uint constant BalanceOfMethodNum = DataCapMethodNums.Balance;
+    uint constant TransferMethodNum = DataCapMethodNums.Transfer;
+    uint constant TransferFromMethodNum = DataCapMethodNums.TransferFrom;
+    uint constant IncreaseAllowanceMethodNum = DataCapMethodNums.IncreaseAllowance;
+    uint constant DecreaseAllowanceMethodNum = DataCapMethodNums.DecreaseAllowance;
+    uint constant RevokeAllowanceMethodNum = DataCapMethodNums.RevokeAllowance;
+    uint constant BurnMethodNum = DataCapMethodNums.Burn;
+    uint constant BurnFromMethodNum = DataCapMethodNums.BurnFrom;
+    uint constant AllowanceMethodNum = DataCapMethodNums.Allowance;
 
     struct GetAllowanceParams {
         bytes owner;
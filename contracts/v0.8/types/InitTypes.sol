@@ -19,13 +19,15 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./CommonTypes.sol";
+import "./InitMethodNums.sol";
 
 /// @title Filecoin init actor types for Solidity.
 /// @author Zondax AG
 library InitTypes {
     bytes constant ActorCode = hex"0001";
-    uint constant ExecMethodNum = 81225168;
-    uint constant Exec4MethodNum = 3;
+    /// @dev Deprecated, use InitMethodNums instead.
+    uint constant ExecMethodNum = InitMethodNums.Exec;
+    uint constant Exec4MethodNum = InitMethodNums.Exec4;
 
     struct ExecParams {
         bytes code_cid;
@@ -0,0 +1,38 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin market actor method numbers for Solidity.
+/// @author Zondax AG
+library MarketMethodNums {
+    uint64 constant AddBalance = 822473126;
+    uint64 constant GetBalance = 726108461;
+    uint64 constant GetDealActivation = 2567238399;
+    uint64 constant GetDealClient = 128053329;
+    uint64 constant GetDealClientCollateral = 200567895;
+    uint64 constant GetDealDataCommitment = 1157985802;
+    uint64 constant GetDealLabel = 46363526;
+    uint64 constant GetDealProvider = 935081690;
+    uint64 constant GetDealProviderCollateral = 2986712137;
+    uint64 constant GetDealTerm = 163777312;
+    uint64 constant GetDealTotalPrice = 4287162428;
+    uint64 constant GetDealVerified = 2627389465;
+    uint64 constant PublishStorageDeals = 2236929350;
+    uint64 constant WithdrawBalance = 2280458852;
+}
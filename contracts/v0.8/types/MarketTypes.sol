@@ -19,25 +19,27 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./CommonTypes.sol";
+import "./MarketMethodNums.sol";
 
 /// @title Filecoin market actor types for Solidity.
 /// @author Zondax AG
 library MarketTypes {
     bytes constant ActorCode = hex"0005";
-    uint constant AddBalanceMethodNum = 822473126;
-    uint constant WithdrawBalanceMethodNum = 2280458852;
-    uint constant GetBalanceMethodNum = 726108461;
-    uint constant GetDealDataCommitmentMethodNum = 1157985802;
-    uint constant GetDealClientMethodNum = 128053329;
-    uint constant GetDealProviderMethodNum = 935081690;
-    uint constant GetDealLabelMethodNum = 46363526;
-    uint constant GetDealTermMethodNum = 163777312;
-    uint constant GetDealEpochPriceMethodNum = 4287162428;
-    uint constant GetDealClientCollateralMethodNum = 200567895;
-    uint constant GetDealProviderCollateralMethodNum = 2986712137;
-    uint constant GetDealVerifiedMethodNum = 2627389465;
-    uint constant GetDealActivationMethodNum = 2567238399;
-    uint constant PublishStorageDealsMethodNum = 2236929350;
+    /// @dev Deprecated, use MarketMethodNums instead.
+    uint constant AddBalanceMethodNum = MarketMethodNums.AddBalance;
+    uint constant WithdrawBalanceMethodNum = MarketMethodNums.WithdrawBalance;
+    uint constant GetBalanceMethodNum = MarketMethodNums.GetBalance;
+    uint constant GetDealDataCommitmentMethodNum = MarketMethodNums.GetDealDataCommitment;
+    uint constant GetDealClientMethodNum = MarketMethodNums.GetDealClient;
+    uint constant GetDealProviderMethodNum = MarketMethodNums.GetDealProvider;
+    uint constant GetDealLabelMethodNum = MarketMethodNums.GetDealLabel;
+    uint constant GetDealTermMethodNum = MarketMethodNums.GetDealTerm;
+    uint constant GetDealEpochPriceMethodNum = MarketMethodNums.GetDealTotalPrice;
+    uint constant GetDealClientCollateralMethodNum = MarketMethodNums.GetDealClientCollateral;
+    uint constant GetDealProviderCollateralMethodNum = MarketMethodNums.GetDealProviderCollateral;
+    uint constant GetDealVerifiedMethodNum = MarketMethodNums.GetDealVerified;
+    uint constant GetDealActivationMethodNum = MarketMethodNums.GetDealActivation;
+    uint constant PublishStorageDealsMethodNum = MarketMethodNums.PublishStorageDeals;
 
     struct WithdrawBalanceParams {
         bytes provider_or_client;
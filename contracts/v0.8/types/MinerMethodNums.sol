@@ -0,0 +1,40 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin miner actor method numbers for Solidity.
+/// @author Zondax AG
+library MinerMethodNums {
+    uint64 constant ChangeBeneficiary = 1570634796;
+    uint64 constant ChangeMultiaddrs = 1063480576;
+    uint64 constant ChangeOwnerAddress = 1010589339;
+    uint64 constant ChangePeerID = 1236548004;
+    uint64 constant ChangeWorkerAddress = 3302309124;
+    uint64 constant ConfirmChangeWorkerAddress = 2354970453;
+    uint64 constant GetAvailableBalance = 4026106874;
+    uint64 constant GetBeneficiary = 4158972569;
+    uint64 constant GetMultiaddrs = 1332909407;
+    uint64 constant GetOwner = 3275365574;
+    uint64 constant GetPeerID = 2812875329;
+    uint64 constant GetSectorSize = 3858292296;
+    uint64 constant GetVestingFunds = 1726876304;
+    uint64 constant IsControllingAddress = 348244887;
+    uint64 constant RepayDebt = 3665352697;
+    uint64 constant WithdrawBalance = 2280458852;
+}
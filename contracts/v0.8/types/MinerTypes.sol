@@ -19,25 +19,27 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./CommonTypes.sol";
+import "./MinerMethodNums.sol";
 
 /// @title Filecoin miner actor types for Solidity.
 /// @author Zondax AG
 library MinerTypes {
-    uint constant GetOwnerMethodNum = 3275365574;
-    uint constant ChangeOwnerAddressMethodNum = 1010589339;
-    uint constant IsControllingAddressMethodNum = 348244887;
-    uint constant GetSectorSizeMethodNum = 3858292296;
-    uint constant GetAvailableBalanceMethodNum = 4026106874;
-    uint constant GetVestingFundsMethodNum = 1726876304;
-    uint constant ChangeBeneficiaryMethodNum = 1570634796;
-    uint constant GetBeneficiaryMethodNum = 4158972569;
-    uint constant ChangeWorkerAddressMethodNum = 3302309124;
-    uint constant ChangePeerIDMethodNum = 1236548004;
-    uint constant ChangeMultiaddrsMethodNum = 1063480576;
-    uint constant RepayDebtMethodNum = 3665352697;
-    uint constant ConfirmChangeWorkerAddressMethodNum = 2354970453;
-    uint constant GetPeerIDMethodNum = 2812875329;
-    uint constant GetMultiaddrsMethodNum = 1332909407;
+    /// @dev Deprecated, use MinerMethodNums instead.
+    uint constant GetOwnerMethodNum = MinerMethodNums.GetOwner;
+    uint constant ChangeOwnerAddressMethodNum = MinerMethodNums.ChangeOwnerAddress;
+    uint constant IsControllingAddressMethodNum = MinerMethodNums.IsControllingAddress;
+    uint constant GetSectorSizeMethodNum = MinerMethodNums.GetSectorSize;
+    uint constant GetAvailableBalanceMethodNum = MinerMethodNums.GetAvailableBalance;
+    uint constant GetVestingFundsMethodNum = MinerMethodNums.GetVestingFunds;
+    uint constant ChangeBeneficiaryMethodNum = MinerMethodNums.ChangeBeneficiary;
+    uint constant GetBeneficiaryMethodNum = MinerMethodNums.GetBeneficiary;
+    uint constant ChangeWorkerAddressMethodNum = MinerMethodNums.ChangeWorkerAddress;
+    uint constant ChangePeerIDMethodNum = MinerMethodNums.ChangePeerID;
+    uint constant ChangeMultiaddrsMethodNum = MinerMethodNums.ChangeMultiaddrs;
+    uint constant RepayDebtMethodNum = MinerMethodNums.RepayDebt;
+    uint constant ConfirmChangeWorkerAddressMethodNum = MinerMethodNums.ConfirmChangeWorkerAddress;
+    uint constant GetPeerIDMethodNum = MinerMethodNums.GetPeerID;
+    uint constant GetMultiaddrsMethodNum = MinerMethodNums.GetMultiaddrs;
 
     struct GetOwnerReturn {
         bytes owner;
@@ -0,0 +1,33 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin multisig actor method numbers for Solidity.
+/// @author Zondax AG
+library MultisigMethodNums {
+    uint64 constant AddSigner = 3028530033;
+    uint64 constant Approve = 1289044053;
+    uint64 constant Cancel = 3365893656;
+    uint64 constant ChangeNumApprovalsThreshold = 3375931653;
+    uint64 constant LockBalance = 1999470977;
+    uint64 constant Propose = 1696838335;
+    uint64 constant Receive = 3726118371;
+    uint64 constant RemoveSigner = 21182899;
+    uint64 constant SwapSigner = 3968117037;
+}
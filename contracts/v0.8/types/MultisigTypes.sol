@@ -19,19 +19,21 @@
 pragma solidity >=0.4.25 <=0.8.15;
 
 import "./CommonTypes.sol";
+import "./MultisigMethodNums.sol";
 
 /// @title Filecoin multisig actor types for Solidity.
 /// @author Zondax AG
 library MultisigTypes {
-    uint constant ProposeMethodNum = 1696838335;
-    uint constant ApproveMethodNum = 1289044053;
-    uint constant CancelMethodNum = 3365893656;
-    uint constant AddSignerMethodNum = 3028530033;
-    uint constant RemoveSignerMethodNum = 21182899;
-    uint constant SwapSignerMethodNum = 3968117037;
-    uint constant ChangeNumApprovalsThresholdMethodNum = 3375931653;
-    uint constant LockBalanceMethodNum = 1999470977;
-    uint constant UniversalReceiverHookMethodNum = 3726118371;
+    /// @dev Deprecated, use MultisigMethodNums instead.
+    uint constant ProposeMethodNum = MultisigMethodNums.Propose;
+    uint constant ApproveMethodNum = MultisigMethodNums.Approve;
+    uint constant CancelMethodNum = MultisigMethodNums.Cancel;
+    uint constant AddSignerMethodNum = MultisigMethodNums.AddSigner;
+    uint constant RemoveSignerMethodNum = MultisigMethodNums.RemoveSigner;
+    uint constant SwapSignerMethodNum = MultisigMethodNums.SwapSigner;
+    uint constant ChangeNumApprovalsThresholdMethodNum = MultisigMethodNums.ChangeNumApprovalsThreshold;
+    uint constant LockBalanceMethodNum = MultisigMethodNums.LockBalance;
+    uint constant UniversalReceiverHookMethodNum = MultisigMethodNums.Receive;
 
     struct ProposeParams {
         bytes to;
@@ -0,0 +1,29 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin power actor method numbers for Solidity.
+/// @author Zondax AG
+library PowerMethodNums {
+    uint64 constant CreateMiner = 1173380165;
+    uint64 constant MinerConsensusCount = 196739875;
+    uint64 constant MinerCount = 1987646258;
+    uint64 constant MinerRawPower = 3753401894;
+    uint64 constant NetworkRawPower = 931722534;
+}
@@ -19,16 +19,18 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./CommonTypes.sol";
+import "./PowerMethodNums.sol";
 
 /// @title Filecoin power actor types for Solidity.
 /// @author Zondax AG
 library PowerTypes {
     bytes constant ActorCode = hex"0004";
-    uint constant CreateMinerMethodNum = 1173380165;
-    uint constant MinerCountMethodNum = 1987646258;
-    uint constant MinerConsensusCountMethodNum = 196739875;
-    uint constant NetworkRawPowerMethodNum = 931722534;
-    uint constant MinerRawPowerMethodNum = 3753401894;
+    /// @dev Deprecated, use PowerMethodNums instead.
+    uint constant CreateMinerMethodNum = PowerMethodNums.CreateMiner;
+    uint constant MinerCountMethodNum = PowerMethodNums.MinerCount;
+    uint constant MinerConsensusCountMethodNum = PowerMethodNums.MinerConsensusCount;
+    uint constant NetworkRawPowerMethodNum = PowerMethodNums.NetworkRawPower;
+    uint constant MinerRawPowerMethodNum = PowerMethodNums.MinerRawPower;
 
     struct CreateMinerParams {
         bytes owner;
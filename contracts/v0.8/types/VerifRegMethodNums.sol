@@ -0,0 +1,30 @@
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the "License");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an "AS IS" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY script/src/generate.rs - DO NOT EDIT, RUN `make generate_method_nums`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+
+/// @title Filecoin verifreg actor method numbers for Solidity.
+/// @author Zondax AG
+library VerifRegMethodNums {
+    uint64 constant AddVerifiedClient = 3916220144;
+    uint64 constant ExtendClaimTerms = 1752273514;
+    uint64 constant GetClaims = 2199871187;
+    uint64 constant Receive = 3726118371;
+    uint64 constant RemoveExpiredAllocations = 2421068268;
+    uint64 constant RemoveExpiredClaims = 2873373899;
+}
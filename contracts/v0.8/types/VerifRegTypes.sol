@@ -19,17 +19,19 @@
 pragma solidity >=0.4.25 <=0.8.17;
 
 import "./CommonTypes.sol";
+import "./VerifRegMethodNums.sol";
 
 /// @title Filecoin Verified Registry actor types for Solidity.
 /// @author Zondax AG
 library VerifRegTypes {
     bytes constant ActorCode = hex"0006";
-    uint constant GetClaimsMethodNum = 2199871187;
-    uint constant AddVerifierClientMethodNum = 3916220144;
-    uint constant RemoveExpiredAllocationsMethodNum = 2873373899;
-    uint constant ExtendClaimTermsMethodNum = 1752273514;
-    uint constant RemoveExpiredClaimsMethodNum = 2873373899;
-    uint constant UniversalReceiverMethodNum = 3726118371;
+    /// @dev Deprecated, use VerifRegMethodNums instead.
+    uint constant GetClaimsMethodNum = VerifRegMethodNums.GetClaims;
+    uint constant AddVerifierClientMethodNum = VerifRegMethodNums.AddVerifiedClient;
+    uint constant RemoveExpiredAllocationsMethodNum = VerifRegMethodNums.RemoveExpiredAllocations;
+    uint constant ExtendClaimTermsMethodNum = VerifRegMethodNums.ExtendClaimTerms;
+    uint constant RemoveExpiredClaimsMethodNum = VerifRegMethodNums.RemoveExpiredClaims;
+    uint constant UniversalReceiverMethodNum = VerifRegMethodNums.Receive;
 
     struct GetClaimsParams {
         uint64 provider;
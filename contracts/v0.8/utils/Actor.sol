@@ -29,6 +29,10 @@ library Actor {
     uint64 constant DEFAULT_FLAG = 0x00000000;
 
     function call(uint method_num, bytes memory actor_code, bytes memory raw_request) internal returns (bytes memory) {
+        return callWithFlags(method_num, actor_code, raw_request, DEFAULT_FLAG);
+    }
+
+    function callWithFlags(uint method_num, bytes memory actor_code, bytes memory raw_request, uint64 flags) internal returns (bytes memory) {
         bytes memory raw_response = new bytes(MAX_RAW_RESPONSE_SIZE);
 
         uint len;
@@ -42,8 +46,8 @@ library Actor {
             mstore(input, method_num)
             // value to send
             mstore(add(input, 0x20), amount)
-            // readonly flag is mandatory for now
-            mstore(add(input, 0x40), DEFAULT_FLAG)
+            // call flags, DEFAULT_FLAG or READ_ONLY_FLAG
+            mstore(add(input, 0x40), flags)
             // cbor codec is mandatory for now
             mstore(add(input, 0x60), CODEC)
             // address size
@@ -64,11 +68,11 @@ library Actor {
             }
             // actual address
             mstore(add(input, add(0xc0, offset)), mload(add(actor_code, 0x20)))
-            // no params
 
-            // FIXME set inputSize according to the input length
+            // the six words above, the params and the address
+            let input_size := add(0xc0, add(len, mload(actor_code)))
             // delegatecall(gasLimit, to, inputOffset, inputSize, outputOffset, outputSize)
-            if iszero(delegatecall(GAS_LIMIT, CALL_ACTOR_PRECOMPILE_ADDR, input, 0x100, raw_response, MAX_RAW_RESPONSE_SIZE)) {
+            if iszero(delegatecall(GAS_LIMIT, CALL_ACTOR_PRECOMPILE_ADDR, input, input_size, raw_response, MAX_RAW_RESPONSE_SIZE)) {
                 revert(0, 0)
             }
         }
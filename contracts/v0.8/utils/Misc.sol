@@ -41,6 +41,70 @@ library Misc {
         }
     }
 
+    /// @notice Filecoin BigInt bytes of x: empty for zero, otherwise a sign byte (0 positive, 1 negative) followed by the big-endian magnitude.
+    function toBigIntBytes(uint256 x) internal pure returns (bytes memory) {
+        return bigIntBytes(false, x);
+    }
+
+    /// @notice Filecoin BigInt bytes of x: empty for zero, otherwise a sign byte (0 positive, 1 negative) followed by the big-endian magnitude.
+    function toBigIntBytes(int256 x) internal pure returns (bytes memory) {
+        // -x overflows for the smallest int256, its magnitude only fits in a uint256
+        return x < 0 ? bigIntBytes(true, uint256(-(x + 1)) + 1) : bigIntBytes(false, uint256(x));
+    }
+
+    /// @notice Value of Filecoin BigInt bytes, reverts when it does not fit in an int256.
+    function toBigInt(bytes memory b) internal pure returns (int256) {
+        (bool negative, uint256 magnitude) = bigIntMagnitude(b);
+
+        if (negative) {
+            require(magnitude <= uint256(type(int256).max) + 1, "BigInt out of int256 range");
+            return -int256(magnitude - 1) - 1;
+        }
+
+        require(magnitude <= uint256(type(int256).max), "BigInt out of int256 range");
+        return int256(magnitude);
+    }
+
+    /// @notice Value of Filecoin BigInt bytes, reverts when it is negative.
+    function toBigUint(bytes memory b) internal pure returns (uint256) {
+        (bool negative, uint256 magnitude) = bigIntMagnitude(b);
+        require(!negative, "BigInt out of uint256 range");
+
+        return magnitude;
+    }
+
+    function bigIntBytes(bool negative, uint256 magnitude) private pure returns (bytes memory b) {
+        if (magnitude == 0) {
+            return new bytes(0);
+        }
+
+        uint len = 0;
+        for (uint256 rest = magnitude; rest > 0; rest >>= 8) {
+            len++;
+        }
+
+        b = new bytes(len + 1);
+        b[0] = negative ? bytes1(0x01) : bytes1(0x00);
+        for (uint i = len; i > 0; i--) {
+            b[i] = bytes1(uint8(magnitude));
+            magnitude >>= 8;
+        }
+    }
+
+    function bigIntMagnitude(bytes memory b) private pure returns (bool negative, uint256 magnitude) {
+        if (b.length == 0) {
+            return (false, 0);
+        }
+
+        require(b[0] == 0x00 || b[0] == 0x01, "invalid BigInt sign byte");
+        require(b.length <= 33, "BigInt out of uint256 range");
+
+        negative = b[0] == 0x01;
+        for (uint i = 1; i < b.length; i++) {
+            magnitude = (magnitude << 8) | uint8(b[i]);
+        }
+    }
+
     function copy(uint src, uint dest, uint len) internal pure {
         // Copy word-length chunks while possible
         for (; len >= 32; len -= 32) {
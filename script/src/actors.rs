@@ -0,0 +1,152 @@
+//! Builtin actor methods the Solidity APIs call.
+//!
+//! Exported methods are dispatched on the FRC-42 hash of their name. The few methods
+//! that are only reachable through their fixed number (e.g. `Exec4`, which only the EAM
+//! may call) are listed with it.
+
+pub type MethodNum = u64;
+
+pub struct Method {
+    pub name: &'static str,
+    pub number: MethodNum,
+}
+
+pub struct Actor {
+    /// As used in the Solidity sources, e.g. `VerifReg` for `VerifRegTypes`.
+    pub name: &'static str,
+    /// Name of the Rust module holding its constants.
+    pub module: &'static str,
+    pub methods: Vec<Method>,
+}
+
+/// Methods exported under `name`, hashed at compile time.
+macro_rules! exported {
+    ($($name:literal),* $(,)?) => {
+        vec![$(Method { name: $name, number: frc42_dispatch::method_hash!($name) }),*]
+    };
+}
+
+/// Every actor with its methods, in the order the generated files list them. Methods are
+/// sorted by name when generating, so the order below doesn't matter.
+pub fn actors() -> Vec<Actor> {
+    vec![
+        Actor {
+            name: "Market",
+            module: "market",
+            methods: exported![
+                "AddBalance",
+                "WithdrawBalance",
+                "PublishStorageDeals",
+                "GetBalance",
+                "GetDealDataCommitment",
+                "GetDealClient",
+                "GetDealProvider",
+                "GetDealLabel",
+                "GetDealTerm",
+                "GetDealTotalPrice",
+                "GetDealClientCollateral",
+                "GetDealProviderCollateral",
+                "GetDealVerified",
+                "GetDealActivation",
+            ],
+        },
+        Actor {
+            name: "Miner",
+            module: "miner",
+            methods: exported![
+                "ChangeWorkerAddress",
+                "ChangePeerID",
+                "WithdrawBalance",
+                "ChangeMultiaddrs",
+                "ConfirmChangeWorkerAddress",
+                "RepayDebt",
+                "ChangeOwnerAddress",
+                "ChangeBeneficiary",
+                "GetBeneficiary",
+                "GetOwner",
+                "IsControllingAddress",
+                "GetSectorSize",
+                "GetAvailableBalance",
+                "GetVestingFunds",
+                "GetPeerID",
+                "GetMultiaddrs",
+            ],
+        },
+        Actor {
+            name: "Power",
+            module: "power",
+            methods: exported![
+                "CreateMiner",
+                "NetworkRawPower",
+                "MinerRawPower",
+                "MinerCount",
+                "MinerConsensusCount",
+            ],
+        },
+        Actor {
+            name: "VerifReg",
+            module: "verifreg",
+            methods: exported![
+                "AddVerifiedClient",
+                "RemoveExpiredAllocations",
+                "GetClaims",
+                "ExtendClaimTerms",
+                "RemoveExpiredClaims",
+                "Receive",
+            ],
+        },
+        Actor {
+            name: "DataCap",
+            module: "datacap",
+            methods: exported![
+                "Mint",
+                "Destroy",
+                "Name",
+                "Symbol",
+                "Granularity",
+                "TotalSupply",
+                "Balance",
+                "Transfer",
+                "TransferFrom",
+                "IncreaseAllowance",
+                "DecreaseAllowance",
+                "RevokeAllowance",
+                "Burn",
+                "BurnFrom",
+                "Allowance",
+            ],
+        },
+        Actor {
+            name: "Init",
+            module: "init",
+            methods: {
+                let mut methods = exported!["Exec"];
+                methods.push(Method {
+                    name: "Exec4",
+                    number: 3,
+                });
+                methods
+            },
+        },
+        Actor {
+            name: "Account",
+            module: "account",
+            methods: exported!["AuthenticateMessage", "Receive"],
+        },
+        Actor {
+            name: "Multisig",
+            module: "multisig",
+            methods: exported![
+                "Propose",
+                "Approve",
+                "Cancel",
+                "AddSigner",
+                "RemoveSigner",
+                "SwapSigner",
+                "ChangeNumApprovalsThreshold",
+                "LockBalance",
+                "Receive",
+            ],
+        },
+    ]
+}
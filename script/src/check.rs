@@ -0,0 +1,217 @@
+//! Checks the method number constants in the Solidity sources against [`actors`].
+//!
+//! Constants are found in the generated `<Actor>MethodNums` libraries, named after the
+//! method, and anywhere else named `<Method>MethodNum`, so that a hand-typed one put back
+//! in `<Actor>Types` is caught. Each one has to name a method of the actor and hold its
+//! number, and no two methods of an actor may share a number.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::actors::{actors, Actor, MethodNum};
+
+/// Relative to the script directory, where `cargo r` is run from.
+pub const CONTRACTS_DIR: &str = "../contracts";
+
+const ACTORS_PATH: &str = "src/actors.rs";
+
+pub struct Problem {
+    /// `path:line` of the constant, or just the path.
+    pub location: String,
+    pub message: String,
+}
+
+/// A method number constant found in a Solidity source.
+struct Constant {
+    path: PathBuf,
+    line: usize,
+    library: String,
+    /// As declared, e.g. `AddBalanceMethodNum`.
+    name: String,
+    /// The method it stands for, e.g. `AddBalance`.
+    method: String,
+    number: MethodNum,
+}
+
+impl Constant {
+    fn location(&self) -> String {
+        format!("{}:{}", self.path.display(), self.line)
+    }
+}
+
+fn sources(dir: &Path, out: &mut Vec<PathBuf>) {
+    let mut entries: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("Unable to read contracts directory")
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            sources(&path, out);
+        } else if path.extension().map_or(false, |ext| ext == "sol") {
+            out.push(path);
+        }
+    }
+}
+
+/// `uint constant <name> = <number>;`, for any `uint` size.
+fn constant(line: &str) -> Option<(&str, MethodNum)> {
+    match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [ty, "constant", name, "=", value] if ty.starts_with("uint") => {
+            Some((name, value.strip_suffix(';')?.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+fn constants(path: &Path) -> Vec<Constant> {
+    let content = fs::read_to_string(path).expect("Unable to read Solidity source");
+    let mut library = String::new();
+    let mut out = vec![];
+
+    for (i, line) in content.lines().enumerate() {
+        let line = line.trim();
+
+        if let Some(rest) = line.strip_prefix("library ") {
+            library = rest
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string();
+            continue;
+        }
+
+        let (name, number) = match constant(line) {
+            Some(constant) => constant,
+            None => continue,
+        };
+        let method = match name.strip_suffix("MethodNum") {
+            Some(method) => method,
+            None if library.ends_with("MethodNums") => name,
+            None => continue,
+        };
+
+        out.push(Constant {
+            path: path.to_path_buf(),
+            line: i + 1,
+            library: library.clone(),
+            name: name.to_string(),
+            method: method.to_string(),
+            number,
+        });
+    }
+
+    out
+}
+
+fn actor_of<'a>(actors: &'a [Actor], library: &str) -> Option<&'a Actor> {
+    let name = library
+        .strip_suffix("MethodNums")
+        .or_else(|| library.strip_suffix("Types"))?;
+    actors.iter().find(|actor| actor.name == name)
+}
+
+/// Methods of the same actor that share a number.
+fn table_collisions(actors: &[Actor]) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    for actor in actors {
+        for (i, method) in actor.methods.iter().enumerate() {
+            for other in &actor.methods[i + 1..] {
+                if method.number == other.number {
+                    problems.push(Problem {
+                        location: ACTORS_PATH.to_string(),
+                        message: format!(
+                            "{} methods {} and {} share method number {}",
+                            actor.name, method.name, other.name, method.number
+                        ),
+                    });
+                }
+            }
+        }
+    }
+
+    problems
+}
+
+fn check_constant(actors: &[Actor], constant: &Constant) -> Option<String> {
+    let actor = match actor_of(actors, &constant.library) {
+        Some(actor) => actor,
+        None => {
+            return Some(format!(
+                "{}.{} is not in an actor library",
+                constant.library, constant.name
+            ))
+        }
+    };
+
+    let hint = match actor
+        .methods
+        .iter()
+        .find(|method| method.number == constant.number)
+    {
+        Some(other) => format!(", {} is the number of {}", constant.number, other.name),
+        None => String::new(),
+    };
+
+    match actor
+        .methods
+        .iter()
+        .find(|method| method.name == constant.method)
+    {
+        Some(method) if method.number == constant.number => None,
+        Some(method) => Some(format!(
+            "{}.{} is {}, method_hash!(\"{}\") is {}{}",
+            constant.library, constant.name, constant.number, method.name, method.number, hint
+        )),
+        None => Some(format!(
+            "{}.{} is not a known {} method{}",
+            constant.library, constant.name, actor.name, hint
+        )),
+    }
+}
+
+/// Constants in the same library holding the same number.
+fn collisions(constants: &[Constant]) -> Vec<Problem> {
+    let mut problems = vec![];
+
+    for (i, constant) in constants.iter().enumerate() {
+        for other in &constants[i + 1..] {
+            if constant.library == other.library && constant.number == other.number {
+                problems.push(Problem {
+                    location: other.location(),
+                    message: format!(
+                        "{}.{} has the same number as {} (line {}), {}",
+                        other.library, other.name, constant.name, constant.line, other.number
+                    ),
+                });
+            }
+        }
+    }
+
+    problems
+}
+
+/// Checks every source under `dir`. Returns the problems and how many constants were
+/// checked.
+pub fn check(dir: &Path) -> (Vec<Problem>, usize) {
+    let actors = actors();
+    let mut problems = table_collisions(&actors);
+
+    let mut paths = vec![];
+    sources(dir, &mut paths);
+    let constants: Vec<Constant> = paths.iter().flat_map(|path| constants(path)).collect();
+
+    for constant in &constants {
+        if let Some(message) = check_constant(&actors, constant) {
+            problems.push(Problem {
+                location: constant.location(),
+                message,
+            });
+        }
+    }
+    problems.extend(collisions(&constants));
+
+    (problems, constants.len())
+}
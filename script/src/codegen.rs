@@ -0,0 +1,768 @@
+//! Writes Solidity types and CBOR codecs from the actor types described in `testing/schema`.
+//!
+//! Every library of the schema gets a `types/<Library>Types.sol` with its structs, and a
+//! `cbor/<Library>Cbor.sol` with a `<Struct>CBOR` library per struct: `serialize` and
+//! `deserialize` for whole payloads, `write` and `read` for values nested in other ones.
+//! The files are laid out like `contracts/v0.8` and use its `utils/CborDecode.sol` and
+//! `utils/Misc.sol`, so they can be compared with, or dropped in place of, the
+//! hand-written ones. `make build_generated_codecs` copies `utils` next to them and
+//! compiles every file.
+//!
+//! Enums are written with their variants in code order, and encoded through the `code` and
+//! `fromCode` functions of their `<Enum>CBOR` library rather than their ordinal. Optional
+//! addresses and bytes are written as `null` when empty; an absent optional of any other
+//! type is read as the zero value.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use schema::{Encoding, Enum, Schema, Struct, Type, COMMON};
+
+use crate::generate::solidity_header;
+
+/// Relative to the script directory, where `cargo r` is run from.
+pub const CODEGEN_DIR: &str = "generated";
+
+const SOURCE: &str = "script/src/codegen.rs";
+
+/// Field names Solidity reserves, written with a trailing `_` like `type_` on the Rust side.
+const RESERVED: &[&str] = &[
+    "address", "bytes", "string", "bool", "int", "uint", "mapping",
+];
+const MAKE_TARGET: &str = "generate_codecs";
+
+pub fn types_library(library: &str) -> String {
+    format!("{}Types", library)
+}
+
+fn codec_library(name: &str) -> String {
+    format!("{}CBOR", name)
+}
+
+fn field_name(name: &str) -> String {
+    if RESERVED.contains(&name) {
+        format!("{}_", name)
+    } else {
+        name.to_string()
+    }
+}
+
+fn library_of(schema: &Schema, ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Struct(name) => schema.find_struct(name).map(|s| s.library),
+        Type::Enum(name) => schema.find_enum(name).map(|e| e.library),
+        _ => None,
+    }
+}
+
+/// Struct and enum names are qualified unless they live in `library`.
+fn solidity_type(schema: &Schema, ty: &Type, library: &str) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::Uint(bits) => format!("uint{}", bits),
+        Type::Int(bits) => format!("int{}", bits),
+        Type::BigInt => "int256".to_string(),
+        Type::Bytes | Type::Address | Type::Cid => "bytes".to_string(),
+        Type::String | Type::Label => "string".to_string(),
+        Type::Array(item) => format!("{}[]", solidity_type(schema, item, library)),
+        Type::Optional(value) => solidity_type(schema, value, library),
+        Type::Struct(name) | Type::Enum(name) => match library_of(schema, ty) {
+            Some(owner) if owner != library => format!("{}.{}", types_library(owner), name),
+            _ => name.to_string(),
+        },
+    }
+}
+
+/// Libraries whose types `ty` refers to.
+fn references(schema: &Schema, ty: &Type, libraries: &mut BTreeSet<&'static str>) {
+    match ty {
+        Type::Array(item) | Type::Optional(item) => references(schema, item, libraries),
+        Type::Struct(name) => {
+            if let Some(s) = schema.find_struct(name) {
+                libraries.insert(s.library);
+                for field in &s.fields {
+                    references(schema, &field.ty, libraries);
+                }
+            }
+        }
+        Type::Enum(_) => {
+            libraries.extend(library_of(schema, ty));
+        }
+        _ => {}
+    }
+}
+
+fn structs_of<'a>(schema: &'a Schema, library: &str) -> Vec<&'a Struct> {
+    schema
+        .structs
+        .iter()
+        .filter(|s| s.library == library)
+        .collect()
+}
+
+fn enums_of<'a>(schema: &'a Schema, library: &str) -> Vec<&'a Enum> {
+    schema
+        .enums
+        .iter()
+        .filter(|e| e.library == library)
+        .collect()
+}
+
+/// Other libraries the structs and enums of `library` refer to.
+fn dependencies(schema: &Schema, library: &str) -> BTreeSet<&'static str> {
+    let mut libraries = BTreeSet::new();
+    for s in structs_of(schema, library) {
+        for field in &s.fields {
+            references(schema, &field.ty, &mut libraries);
+        }
+    }
+    libraries.remove(library);
+    libraries
+}
+
+/// `<Library>Types.sol`.
+pub fn types(schema: &Schema, library: &str) -> String {
+    let mut out = solidity_header(SOURCE, MAKE_TARGET);
+
+    writeln!(out).unwrap();
+    for dependency in dependencies(schema, library) {
+        let name = types_library(dependency);
+        writeln!(out, "import {{{}}} from \"./{}.sol\";", name, name).unwrap();
+    }
+    writeln!(out).unwrap();
+
+    if library == COMMON {
+        writeln!(
+            out,
+            "/// @title Filecoin actor types shared between actors."
+        )
+        .unwrap();
+    } else {
+        writeln!(
+            out,
+            "/// @title Filecoin {} actor types for Solidity.",
+            library
+        )
+        .unwrap();
+    }
+    writeln!(out, "/// @author Zondax AG").unwrap();
+    writeln!(out, "library {} {{", types_library(library)).unwrap();
+
+    let mut first = true;
+    for e in enums_of(schema, library) {
+        if !first {
+            writeln!(out).unwrap();
+        }
+        first = false;
+
+        writeln!(out, "    /// {}", e.rust).unwrap();
+        writeln!(out, "    enum {} {{", e.name).unwrap();
+        let names: Vec<&str> = e.variants.iter().map(|v| v.name).collect();
+        writeln!(out, "        {}", names.join(",\n        ")).unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+
+    for s in structs_of(schema, library) {
+        if !first {
+            writeln!(out).unwrap();
+        }
+        first = false;
+
+        writeln!(out, "    /// {}", s.rust).unwrap();
+        writeln!(out, "    struct {} {{", s.name).unwrap();
+        for field in &s.fields {
+            writeln!(
+                out,
+                "        {} {};",
+                solidity_type(schema, &field.ty, library),
+                field_name(field.name)
+            )
+            .unwrap();
+        }
+        writeln!(out, "    }}").unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+/// Statements writing `expr` to `buf`, indented by `indent` levels.
+fn write_value(out: &mut String, ty: &Type, expr: &str, indent: usize, depth: usize) {
+    let pad = "    ".repeat(indent);
+
+    match ty {
+        Type::Bool => writeln!(out, "{}buf.writeBool({});", pad, expr).unwrap(),
+        Type::Uint(_) => writeln!(out, "{}buf.writeUInt64({});", pad, expr).unwrap(),
+        Type::Int(_) => writeln!(out, "{}buf.writeInt64({});", pad, expr).unwrap(),
+        Type::BigInt => {
+            writeln!(out, "{}buf.writeBytes(Misc.toBigIntBytes({}));", pad, expr).unwrap()
+        }
+        Type::Bytes | Type::Address | Type::Cid => {
+            writeln!(out, "{}buf.writeBytes({});", pad, expr).unwrap()
+        }
+        Type::String | Type::Label => writeln!(out, "{}buf.writeString({});", pad, expr).unwrap(),
+        Type::Array(item) => {
+            let i = format!("i{}", depth);
+            writeln!(out, "{}buf.startFixedArray(uint64({}.length));", pad, expr).unwrap();
+            writeln!(
+                out,
+                "{}for (uint {i} = 0; {i} < {}.length; {i}++) {{",
+                pad,
+                expr,
+                i = i
+            )
+            .unwrap();
+            write_value(
+                out,
+                item,
+                &format!("{}[{}]", expr, i),
+                indent + 1,
+                depth + 1,
+            );
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+        Type::Optional(value) => match **value {
+            Type::Bytes | Type::Address | Type::Cid => {
+                writeln!(out, "{}if ({}.length == 0) {{", pad, expr).unwrap();
+                writeln!(out, "{}    buf.writeNull();", pad).unwrap();
+                writeln!(out, "{}}} else {{", pad).unwrap();
+                write_value(out, value, expr, indent + 1, depth);
+                writeln!(out, "{}}}", pad).unwrap();
+            }
+            _ => write_value(out, value, expr, indent, depth),
+        },
+        Type::Struct(name) => {
+            writeln!(out, "{}{}.write(buf, {});", pad, codec_library(name), expr).unwrap()
+        }
+        Type::Enum(name) => writeln!(
+            out,
+            "{}buf.writeUInt64({}.code({}));",
+            pad,
+            codec_library(name),
+            expr
+        )
+        .unwrap(),
+    }
+}
+
+/// Statements reading a value of type `ty` from `rawResp` at `byteIdx` into `target`.
+fn read_value(
+    out: &mut String,
+    schema: &Schema,
+    ty: &Type,
+    target: &str,
+    indent: usize,
+    depth: usize,
+) {
+    let pad = "    ".repeat(indent);
+    let read = |out: &mut String, function: &str| {
+        writeln!(
+            out,
+            "{}({}, byteIdx) = rawResp.{}(byteIdx);",
+            pad, target, function
+        )
+        .unwrap()
+    };
+
+    match ty {
+        Type::Bool => read(out, "readBool"),
+        Type::Uint(bits) => read(out, &format!("readUInt{}", bits)),
+        Type::Int(bits) => read(out, &format!("readInt{}", bits)),
+        Type::BigInt => {
+            writeln!(out, "{}(tmp, byteIdx) = rawResp.readBytes(byteIdx);", pad).unwrap();
+            writeln!(out, "{}{} = Misc.toBigInt(tmp);", pad, target).unwrap();
+        }
+        Type::Bytes | Type::Address | Type::Cid => read(out, "readBytes"),
+        Type::String => read(out, "readString"),
+        // Labels are either a text or a byte string
+        Type::Label => {
+            writeln!(out, "{}(maj, , ) = rawResp.parseCborHeader(byteIdx);", pad).unwrap();
+            writeln!(out, "{}if (maj == MajTextString) {{", pad).unwrap();
+            writeln!(
+                out,
+                "{}    ({}, byteIdx) = rawResp.readString(byteIdx);",
+                pad, target
+            )
+            .unwrap();
+            writeln!(out, "{}}} else {{", pad).unwrap();
+            writeln!(
+                out,
+                "{}    (tmp, byteIdx) = rawResp.readBytes(byteIdx);",
+                pad
+            )
+            .unwrap();
+            writeln!(out, "{}    {} = string(tmp);", pad, target).unwrap();
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+        Type::Array(item) => {
+            let i = format!("i{}", depth);
+            writeln!(
+                out,
+                "{}(len, byteIdx) = rawResp.readFixedArray(byteIdx);",
+                pad
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}{} = new {}(len);",
+                pad,
+                target,
+                solidity_type(schema, ty, "")
+            )
+            .unwrap();
+            writeln!(
+                out,
+                "{}for (uint {i} = 0; {i} < {}.length; {i}++) {{",
+                pad,
+                target,
+                i = i
+            )
+            .unwrap();
+            read_value(
+                out,
+                schema,
+                item,
+                &format!("{}[{}]", target, i),
+                indent + 1,
+                depth + 1,
+            );
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+        Type::Optional(value) => {
+            writeln!(out, "{}if (rawResp.isNullNext(byteIdx)) {{", pad).unwrap();
+            writeln!(out, "{}    byteIdx++;", pad).unwrap();
+            writeln!(out, "{}}} else {{", pad).unwrap();
+            read_value(out, schema, value, target, indent + 1, depth);
+            writeln!(out, "{}}}", pad).unwrap();
+        }
+        Type::Struct(name) => writeln!(
+            out,
+            "{}byteIdx = {}.read({}, rawResp, byteIdx);",
+            pad,
+            codec_library(name),
+            target
+        )
+        .unwrap(),
+        Type::Enum(name) => {
+            writeln!(out, "{}(code, byteIdx) = rawResp.readUInt64(byteIdx);", pad).unwrap();
+            writeln!(
+                out,
+                "{}{} = {}.fromCode(code);",
+                pad,
+                target,
+                codec_library(name)
+            )
+            .unwrap();
+        }
+    }
+}
+
+/// Whether reading `ty` needs the `len`, `tmp`, `maj` or `code` local.
+fn needs_local(ty: &Type, local: &str) -> bool {
+    match ty {
+        Type::Array(item) => local == "len" || needs_local(item, local),
+        Type::Optional(value) => needs_local(value, local),
+        Type::BigInt => local == "tmp",
+        Type::Label => local == "tmp" || local == "maj",
+        Type::Enum(_) => local == "code",
+        _ => false,
+    }
+}
+
+fn struct_codec(out: &mut String, schema: &Schema, s: &Struct) {
+    let ty = solidity_type(schema, &Type::Struct(s.name), "");
+    let tuple = s.encoding == Encoding::Tuple;
+
+    writeln!(out, "/// {}", s.rust).unwrap();
+    writeln!(out, "library {} {{", codec_library(s.name)).unwrap();
+    writeln!(out, "    using CBOR for CBOR.CBORBuffer;").unwrap();
+    writeln!(out, "    using CBORDecoder for bytes;").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    function serialize({} memory value) internal pure returns (bytes memory) {{",
+        ty
+    )
+    .unwrap();
+    writeln!(out, "        CBOR.CBORBuffer memory buf = CBOR.create(64);").unwrap();
+    writeln!(out, "        write(buf, value);").unwrap();
+    writeln!(out, "        return buf.data();").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    function deserialize({} memory ret, bytes memory rawResp) internal pure {{",
+        ty
+    )
+    .unwrap();
+    writeln!(out, "        read(ret, rawResp, 0);").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    function write(CBOR.CBORBuffer memory buf, {} memory value) internal pure {{",
+        ty
+    )
+    .unwrap();
+    if tuple {
+        writeln!(out, "        buf.startFixedArray({});", s.fields.len()).unwrap();
+    }
+    for field in &s.fields {
+        write_value(
+            out,
+            &field.ty,
+            &format!("value.{}", field_name(field.name)),
+            2,
+            0,
+        );
+    }
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    function read({} memory ret, bytes memory rawResp, uint byteIdx) internal pure returns (uint) {{",
+        ty
+    )
+    .unwrap();
+    let needs = |local: &str| s.fields.iter().any(|f| needs_local(&f.ty, local));
+    let mut locals = vec![];
+    if tuple || needs("len") {
+        locals.push("uint len;");
+    }
+    if needs("tmp") {
+        locals.push("bytes memory tmp;");
+    }
+    if needs("maj") {
+        locals.push("uint8 maj;");
+    }
+    if needs("code") {
+        locals.push("uint64 code;");
+    }
+    for local in &locals {
+        writeln!(out, "        {}", local).unwrap();
+    }
+    if !locals.is_empty() {
+        writeln!(out).unwrap();
+    }
+    if tuple {
+        writeln!(
+            out,
+            "        (len, byteIdx) = rawResp.readFixedArray(byteIdx);"
+        )
+        .unwrap();
+        writeln!(out, "        assert(len == {});", s.fields.len()).unwrap();
+        writeln!(out).unwrap();
+    }
+    for field in &s.fields {
+        read_value(
+            out,
+            schema,
+            &field.ty,
+            &format!("ret.{}", field_name(field.name)),
+            2,
+            0,
+        );
+    }
+    writeln!(out).unwrap();
+    writeln!(out, "        return byteIdx;").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+fn enum_codec(out: &mut String, schema: &Schema, e: &Enum) {
+    let ty = solidity_type(schema, &Type::Enum(e.name), "");
+
+    writeln!(out, "/// {}", e.rust).unwrap();
+    writeln!(out, "library {} {{", codec_library(e.name)).unwrap();
+
+    writeln!(
+        out,
+        "    function code({} value) internal pure returns (uint64) {{",
+        ty
+    )
+    .unwrap();
+    for variant in &e.variants {
+        writeln!(
+            out,
+            "        if (value == {}.{}) return {};",
+            ty, variant.name, variant.code
+        )
+        .unwrap();
+    }
+    writeln!(out, "        revert(\"unknown {}\");", e.name).unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "    function fromCode(uint64 code) internal pure returns ({}) {{",
+        ty
+    )
+    .unwrap();
+    for variant in &e.variants {
+        writeln!(
+            out,
+            "        if (code == {}) return {}.{};",
+            variant.code, ty, variant.name
+        )
+        .unwrap();
+    }
+    writeln!(out, "        revert(\"unknown {} code\");", e.name).unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "}}").unwrap();
+}
+
+/// `<Library>Cbor.sol`.
+pub fn codecs(schema: &Schema, library: &str) -> String {
+    let mut out = solidity_header(SOURCE, MAKE_TARGET);
+
+    writeln!(out).unwrap();
+    writeln!(out, "import \"solidity-cborutils/contracts/CBOR.sol\";").unwrap();
+    writeln!(out).unwrap();
+    let mut libraries = dependencies(schema, library);
+    libraries.insert(library);
+    for dependency in &libraries {
+        let name = types_library(dependency);
+        writeln!(out, "import {{{}}} from \"../types/{}.sol\";", name, name).unwrap();
+    }
+    for dependency in libraries.iter().filter(|l| **l != library) {
+        writeln!(out, "import \"./{}Cbor.sol\";", dependency).unwrap();
+    }
+    writeln!(out, "import \"../utils/CborDecode.sol\";").unwrap();
+    writeln!(out, "import \"../utils/Misc.sol\";").unwrap();
+
+    for e in enums_of(schema, library) {
+        writeln!(out).unwrap();
+        enum_codec(&mut out, schema, e);
+    }
+    for s in structs_of(schema, library) {
+        writeln!(out).unwrap();
+        struct_codec(&mut out, schema, s);
+    }
+
+    out
+}
+
+/// Every file, by path relative to the output directory.
+pub fn files(schema: &Schema) -> Vec<(PathBuf, String)> {
+    let mut files = vec![];
+
+    for library in schema.libraries() {
+        files.push((
+            Path::new("types").join(format!("{}.sol", types_library(library))),
+            types(schema, library),
+        ));
+        files.push((
+            Path::new("cbor").join(format!("{}Cbor.sol", library)),
+            codecs(schema, library),
+        ));
+    }
+
+    files
+}
+
+/// References to structs or enums the schema doesn't describe, which would not compile.
+pub fn unknown_references(schema: &Schema) -> Vec<String> {
+    let mut unknown = vec![];
+
+    for s in &schema.structs {
+        for field in &s.fields {
+            for name in schema.unknown_references(&field.ty) {
+                unknown.push(format!("{}.{}: {}", s.name, field.name, name));
+            }
+        }
+    }
+
+    unknown
+}
+
+pub fn write(schema: &Schema, dir: &Path) {
+    for (path, content) in files(schema) {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).expect("Unable to create output directory");
+        fs::write(&path, content).expect("Unable to write Solidity file");
+        println!("wrote {}", path.display());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reserved_names_get_an_underscore() {
+        assert_eq!(field_name("address"), "address_");
+        assert_eq!(field_name("amount"), "amount");
+    }
+
+    #[test]
+    fn types_of_other_libraries_are_qualified() {
+        let schema = Schema::new();
+        let deal = Type::Struct("DealProposal");
+
+        assert_eq!(solidity_type(&schema, &deal, COMMON), "DealProposal");
+        assert_eq!(
+            solidity_type(&schema, &Type::array(deal), "Market"),
+            "CommonTypes.DealProposal[]"
+        );
+        assert_eq!(
+            solidity_type(&schema, &Type::optional(Type::Address), "Market"),
+            "bytes"
+        );
+        assert_eq!(solidity_type(&schema, &Type::Label, "Market"), "string");
+    }
+
+    #[test]
+    fn writes_structs_in_field_order() {
+        let types = types(&Schema::new(), "VerifReg");
+
+        assert!(types.contains("import {CommonTypes} from \"./CommonTypes.sol\";"));
+        assert!(types.contains("library VerifRegTypes {"));
+        assert!(types.contains(
+            "    /// fil_actor_verifreg::VerifierParams\n    \
+             struct VerifierParams {\n        \
+             bytes address_;\n        \
+             int256 allowance;\n    \
+             }\n"
+        ));
+    }
+
+    #[test]
+    fn tuples_are_arrays() {
+        let codecs = codecs(&Schema::new(), "Market");
+        let codec = &codecs[codecs.find("library WithdrawBalanceParamsCBOR").unwrap()..];
+        let codec = &codec[..codec.find("\n}\n").unwrap()];
+
+        assert!(codec.contains("        buf.startFixedArray(2);\n"));
+        assert!(codec.contains("        buf.writeBytes(value.provider_or_client);\n"));
+        assert!(codec.contains("        buf.writeBytes(Misc.toBigIntBytes(value.amount));\n"));
+        assert!(codec.contains("        assert(len == 2);\n"));
+        assert!(codec.contains("        ret.amount = Misc.toBigInt(tmp);\n"));
+    }
+
+    #[test]
+    fn labels_are_text_or_bytes() {
+        let codecs = codecs(&Schema::new(), "Market");
+        let codec = &codecs[codecs.find("library GetDealLabelReturnCBOR").unwrap()..];
+        let codec = &codec[..codec.find("\n}\n").unwrap()];
+
+        assert!(codec.contains("        bytes memory tmp;\n        uint8 maj;\n"));
+        assert!(codec.contains(
+            "        (maj, , ) = rawResp.parseCborHeader(byteIdx);\n        \
+             if (maj == MajTextString) {\n            \
+             (ret.label, byteIdx) = rawResp.readString(byteIdx);\n        \
+             } else {\n            \
+             (tmp, byteIdx) = rawResp.readBytes(byteIdx);\n            \
+             ret.label = string(tmp);\n        \
+             }\n"
+        ));
+    }
+
+    #[test]
+    fn transparent_structs_are_their_field() {
+        let codecs = codecs(&Schema::new(), "Market");
+        let codec = &codecs[codecs.find("library DealQueryParamsCBOR").unwrap()..];
+        let codec = &codec[..codec.find("\n}\n").unwrap()];
+
+        assert!(!codec.contains("FixedArray"));
+        assert!(codec.contains("        buf.writeUInt64(value.id);\n"));
+        assert!(codec.contains("        (ret.id, byteIdx) = rawResp.readUInt64(byteIdx);\n"));
+    }
+
+    #[test]
+    fn enums_are_written_as_their_code() {
+        let codecs = codecs(&Schema::new(), COMMON);
+
+        assert!(
+            codecs.contains("        if (value == CommonTypes.SectorSize._2KiB) return 2048;\n")
+        );
+        assert!(codecs.contains("        if (code == 2048) return CommonTypes.SectorSize._2KiB;\n"));
+        assert!(codecs.contains("        revert(\"unknown SectorSize code\");\n"));
+    }
+
+    #[test]
+    fn empty_optional_bytes_are_null() {
+        let mut out = String::new();
+        write_value(
+            &mut out,
+            &Type::optional(Type::Address),
+            "value.proposed",
+            2,
+            0,
+        );
+
+        assert_eq!(
+            out,
+            "        if (value.proposed.length == 0) {\n            \
+             buf.writeNull();\n        \
+             } else {\n            \
+             buf.writeBytes(value.proposed);\n        \
+             }\n"
+        );
+    }
+
+    #[test]
+    fn arrays_index_by_depth() {
+        let mut out = String::new();
+        read_value(
+            &mut out,
+            &Schema::new(),
+            &Type::array(Type::array(Type::Uint(64))),
+            "ret.ids",
+            2,
+            0,
+        );
+
+        assert!(out.contains("ret.ids = new uint64[][](len);"));
+        assert!(out.contains("for (uint i0 = 0; i0 < ret.ids.length; i0++) {"));
+        assert!(out.contains("ret.ids[i0] = new uint64[](len);"));
+        assert!(out.contains("(ret.ids[i0][i1], byteIdx) = rawResp.readUInt64(byteIdx);"));
+    }
+
+    #[test]
+    fn imports_are_generated() {
+        let schema = Schema::new();
+        assert!(unknown_references(&schema).is_empty());
+
+        let files = files(&schema);
+        assert_eq!(files.len(), 2 * schema.libraries().len());
+
+        let paths: BTreeSet<PathBuf> = files.iter().map(|(path, _)| path.clone()).collect();
+        for (path, content) in &files {
+            for line in content.lines().filter(|l| l.starts_with("import ")) {
+                let import = line.split('"').nth(1).unwrap();
+                if import.starts_with("solidity-cborutils/") || import.starts_with("../utils/") {
+                    continue;
+                }
+
+                let target = path.parent().unwrap().join(import);
+                let target: PathBuf =
+                    target
+                        .components()
+                        .fold(PathBuf::new(), |mut resolved, component| {
+                            match component {
+                                std::path::Component::ParentDir => {
+                                    resolved.pop();
+                                }
+                                std::path::Component::CurDir => {}
+                                other => resolved.push(other),
+                            }
+                            resolved
+                        });
+                assert!(
+                    paths.contains(&target),
+                    "{} imports {}",
+                    path.display(),
+                    import
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,811 @@
+//! Compares the structs and enums of the Solidity `<Actor>Types` libraries with the actor
+//! types described in `testing/schema`.
+//!
+//! Fields are compared by position, as CBOR tuples encode them: a field missing or added,
+//! an integer of another width or signedness, or a struct or enum other than the actor's,
+//! all change what goes on the wire. Enum variants are compared by ordinal, since the
+//! hand-written codecs encode an enum as its ordinal. Field names are only reported, they
+//! don't change the encoding.
+//!
+//! Solidity structs are matched to the schema by name, or through [`ALIASES`] where the
+//! Solidity side names them after the API function. Those without a match, e.g. miner
+//! types no API passes yet, aren't compared. A trailing `Invalid` variant stands for the
+//! catch-all `Invalid(i64)` of the `fvm_shared` proof types and is left out too.
+//!
+//! The schema is itself checked against the actor crates by `testing/codec`, which encodes
+//! a value of every actor type and validates it against its struct, and compares the enum
+//! codes. A drift reported here is therefore one from the actor types, not from a schema
+//! that fell behind them.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use schema::{Enum, Schema, Struct, Type};
+
+/// Relative to the script directory, where `cargo r` is run from.
+pub const TYPES_DIR: &str = "../contracts/v0.8/types";
+
+/// Solidity library, Solidity name and schema name of the structs named differently.
+pub const ALIASES: &[(&str, &str, &str)] = &[
+    ("Market", "GetDealDataCommitmentParams", "DealQueryParams"),
+    ("Market", "GetDealClientParams", "DealQueryParams"),
+    ("Market", "GetDealProviderParams", "DealQueryParams"),
+    ("Market", "GetDealLabelParams", "DealQueryParams"),
+    ("Market", "GetDealTermParams", "DealQueryParams"),
+    ("Market", "GetDealEpochPriceParams", "DealQueryParams"),
+    ("Market", "GetDealClientCollateralParams", "DealQueryParams"),
+    (
+        "Market",
+        "GetDealProviderCollateralParams",
+        "DealQueryParams",
+    ),
+    ("Market", "GetDealVerifiedParams", "DealQueryParams"),
+    ("Market", "GetDealActivationParams", "DealQueryParams"),
+    (
+        "Market",
+        "GetDealEpochPriceReturn",
+        "GetDealTotalPriceReturn",
+    ),
+    ("VerifReg", "AddVerifierClientParams", "VerifierParams"),
+];
+
+const CATCH_ALL_VARIANT: &str = "Invalid";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Kind {
+    MissingField,
+    ExtraField,
+    /// Another integer width or signedness, or another struct or enum.
+    Type,
+    /// A variant encoded as something else than its ordinal, or unknown to the actor.
+    EnumOrder,
+    /// Doesn't change the encoding.
+    FieldName,
+}
+
+impl Kind {
+    /// Whether the drift changes what goes on the wire.
+    pub fn breaking(&self) -> bool {
+        *self != Kind::FieldName
+    }
+}
+
+impl fmt::Display for Kind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            Kind::MissingField => "missing field",
+            Kind::ExtraField => "extra field",
+            Kind::Type => "type",
+            Kind::EnumOrder => "enum order",
+            Kind::FieldName => "field name",
+        };
+        write!(f, "{}", kind)
+    }
+}
+
+pub struct Drift {
+    /// `path:line` of the Solidity declaration.
+    pub location: String,
+    pub kind: Kind,
+    pub message: String,
+}
+
+/// A struct field or an enum variant, with the line it's declared on.
+struct Member {
+    line: usize,
+    /// Solidity type, empty for enum variants.
+    ty: String,
+    name: String,
+}
+
+struct Declaration {
+    path: PathBuf,
+    line: usize,
+    /// Without the `Types` suffix, e.g. `Market`.
+    library: String,
+    name: String,
+    is_enum: bool,
+    members: Vec<Member>,
+}
+
+impl Declaration {
+    fn location(&self, line: usize) -> String {
+        format!("{}:{}", self.path.display(), line)
+    }
+
+    fn qualified_name(&self) -> String {
+        format!("{}Types.{}", self.library, self.name)
+    }
+}
+
+/// Struct and enum declarations of the `<Library>Types` libraries in `path`.
+fn declarations(path: &Path) -> Vec<Declaration> {
+    let content = fs::read_to_string(path).expect("Unable to read Solidity source");
+    let mut library = None;
+    let mut current: Option<Declaration> = None;
+    let mut out = vec![];
+
+    for (i, line) in content.lines().enumerate() {
+        let line = line.split("//").next().unwrap_or_default().trim();
+        let words: Vec<&str> = line
+            .split(|c: char| c.is_whitespace() || c == '{')
+            .filter(|word| !word.is_empty())
+            .collect();
+
+        if current.is_none() {
+            match words[..] {
+                ["library", name, ..] => {
+                    library = name.strip_suffix("Types").map(String::from);
+                }
+                [kind @ ("struct" | "enum"), name, ..] => {
+                    if let Some(library) = &library {
+                        current = Some(Declaration {
+                            path: path.to_path_buf(),
+                            line: i + 1,
+                            library: library.clone(),
+                            name: name.to_string(),
+                            is_enum: kind == "enum",
+                            members: vec![],
+                        });
+                    }
+                }
+                _ => {}
+            }
+        }
+        let declaration = match current.as_mut() {
+            Some(declaration) => declaration,
+            None => continue,
+        };
+
+        // Members can follow the opening brace, and precede the closing one
+        let body = match line.split_once('{') {
+            Some((_, body)) => body,
+            None if declaration.line == i + 1 => "",
+            None => line,
+        };
+        let (members, closed) = match body.split_once('}') {
+            Some((members, _)) => (members, true),
+            None => (body, false),
+        };
+
+        if declaration.is_enum {
+            for name in members.split(',').map(str::trim).filter(|n| !n.is_empty()) {
+                declaration.members.push(Member {
+                    line: i + 1,
+                    ty: String::new(),
+                    name: name.to_string(),
+                });
+            }
+        } else {
+            let terminated = members.rsplit_once(';').map_or("", |(fields, _)| fields);
+            for field in terminated.split(';').map(str::trim) {
+                if let Some((ty, name)) = field.rsplit_once(char::is_whitespace) {
+                    declaration.members.push(Member {
+                        line: i + 1,
+                        ty: ty.trim().to_string(),
+                        name: name.to_string(),
+                    });
+                }
+            }
+        }
+
+        if closed {
+            out.extend(current.take());
+        }
+    }
+
+    out
+}
+
+/// The schema name of the struct or enum `name` refers to, e.g. `CommonTypes.Claim` or
+/// `GetDealClientParams` from within `library`.
+fn schema_name<'a>(library: &str, name: &'a str) -> (String, &'a str) {
+    let (library, name) = match name.split_once('.') {
+        Some((qualifier, name)) => (qualifier.strip_suffix("Types").unwrap_or(qualifier), name),
+        None => (library, name),
+    };
+
+    let aliased = ALIASES
+        .iter()
+        .find(|(l, solidity, _)| *l == library && *solidity == name)
+        .map(|(_, _, schema)| *schema);
+
+    (aliased.unwrap_or(name).to_string(), name)
+}
+
+/// `uint64` to `(false, 64)`, `int256` to `(true, 256)`.
+fn integer(ty: &str) -> Option<(bool, u16)> {
+    let (signed, bits) = match ty.strip_prefix("uint") {
+        Some(bits) => (false, bits),
+        None => (true, ty.strip_prefix("int")?),
+    };
+    let bits = if bits.is_empty() {
+        256
+    } else {
+        bits.parse().ok()?
+    };
+    Some((signed, bits))
+}
+
+fn describe(ty: &Type) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::Uint(bits) => format!("uint{}", bits),
+        Type::Int(bits) => format!("int{}", bits),
+        Type::BigInt => "BigInt".to_string(),
+        Type::Bytes => "bytes".to_string(),
+        Type::Address => "Address".to_string(),
+        Type::Cid => "Cid".to_string(),
+        Type::String => "string".to_string(),
+        Type::Label => "Label".to_string(),
+        Type::Array(item) => format!("{}[]", describe(item)),
+        Type::Optional(value) => format!("Option<{}>", describe(value)),
+        Type::Struct(name) | Type::Enum(name) => name.to_string(),
+    }
+}
+
+/// Why the Solidity type `solidity` can't carry `ty`, if it can't.
+fn compare_type(library: &str, solidity: &str, ty: &Type) -> Option<String> {
+    let mismatch = || Some(format!("{} where the actor has {}", solidity, describe(ty)));
+
+    match ty {
+        Type::Optional(value) => compare_type(library, solidity, value),
+        Type::Array(item) => match solidity.strip_suffix("[]") {
+            Some(solidity_item) => compare_type(library, solidity_item, item),
+            None => mismatch(),
+        },
+        Type::Uint(bits) | Type::Int(bits) => {
+            let signed = matches!(ty, Type::Int(_));
+            match integer(solidity) {
+                Some((s, b)) if s == signed && b == *bits => None,
+                Some((s, _)) if s != signed => Some(format!(
+                    "{} where the actor has {}, the signedness differs",
+                    solidity,
+                    describe(ty)
+                )),
+                Some(_) => Some(format!(
+                    "{} where the actor has {}, the width differs",
+                    solidity,
+                    describe(ty)
+                )),
+                None => mismatch(),
+            }
+        }
+        Type::BigInt => match integer(solidity) {
+            Some((true, 256)) => None,
+            Some((false, 256)) => Some(format!("{} where the actor has a signed BigInt", solidity)),
+            _ => mismatch(),
+        },
+        Type::Bool => (solidity != "bool").then(mismatch).flatten(),
+        Type::Bytes | Type::Address | Type::Cid => (solidity != "bytes").then(mismatch).flatten(),
+        Type::String | Type::Label => (solidity != "string").then(mismatch).flatten(),
+        // Or the code itself, e.g. a sector size in bytes
+        Type::Enum(_) if matches!(integer(solidity), Some((false, 64))) => None,
+        Type::Struct(name) | Type::Enum(name) => {
+            let (schema, _) = schema_name(library, solidity);
+            (schema != *name).then(mismatch).flatten()
+        }
+    }
+}
+
+fn compare_struct(declaration: &Declaration, schema: &Struct) -> Vec<Drift> {
+    let mut drifts = vec![];
+    let mut drift = |line: usize, kind: Kind, message: String| {
+        drifts.push(Drift {
+            location: declaration.location(line),
+            kind,
+            message: format!("{}: {}", declaration.qualified_name(), message),
+        })
+    };
+
+    for (i, field) in schema.fields.iter().enumerate() {
+        let member = match declaration.members.get(i) {
+            Some(member) => member,
+            None => {
+                drift(
+                    declaration.line,
+                    Kind::MissingField,
+                    format!(
+                        "no field {} for the actor's {} {}",
+                        i,
+                        describe(&field.ty),
+                        field.name
+                    ),
+                );
+                continue;
+            }
+        };
+
+        if let Some(message) = compare_type(&declaration.library, &member.ty, &field.ty) {
+            drift(
+                member.line,
+                Kind::Type,
+                format!("field {}: {}", member.name, message),
+            );
+        }
+        if member.name.trim_end_matches('_') != field.name.trim_end_matches('_') {
+            drift(
+                member.line,
+                Kind::FieldName,
+                format!("field {} is {} in {}", member.name, field.name, schema.rust),
+            );
+        }
+    }
+
+    for member in declaration.members.iter().skip(schema.fields.len()) {
+        drift(
+            member.line,
+            Kind::ExtraField,
+            format!("field {} isn't in {}", member.name, schema.rust),
+        );
+    }
+
+    drifts
+}
+
+fn compare_enum(declaration: &Declaration, schema: &Enum) -> Vec<Drift> {
+    let mut drifts = vec![];
+    let mut drift = |line: usize, message: String| {
+        drifts.push(Drift {
+            location: declaration.location(line),
+            kind: Kind::EnumOrder,
+            message: format!("{}: {}", declaration.qualified_name(), message),
+        })
+    };
+
+    let mut members: Vec<&Member> = declaration.members.iter().collect();
+    if members.last().map(|m| m.name.as_str()) == Some(CATCH_ALL_VARIANT) {
+        members.pop();
+    }
+
+    // Codes that aren't ordinals, e.g. sector sizes, have to be mapped by the codecs
+    // anyway, only their order is compared.
+    let mut codes: Vec<u64> = schema.variants.iter().map(|v| v.code).collect();
+    codes.sort_unstable();
+    let ordinals = codes.iter().enumerate().all(|(i, code)| *code == i as u64);
+
+    for (ordinal, member) in members.iter().enumerate() {
+        let expected = if ordinals {
+            Some(ordinal as u64)
+        } else {
+            codes.get(ordinal).copied()
+        };
+
+        match schema.variants.iter().find(|v| v.name == member.name) {
+            Some(variant) if Some(variant.code) == expected => {}
+            Some(variant) if !ordinals => drift(
+                member.line,
+                format!(
+                    "{} is ordinal {} but {} by code in {}",
+                    member.name,
+                    ordinal,
+                    codes
+                        .iter()
+                        .position(|c| *c == variant.code)
+                        .unwrap_or_default(),
+                    schema.rust
+                ),
+            ),
+            Some(variant) => drift(
+                member.line,
+                format!(
+                    "{} is ordinal {} but {} encodes it as {}",
+                    member.name, ordinal, schema.rust, variant.code
+                ),
+            ),
+            None => drift(
+                member.line,
+                format!("{} isn't a variant of {}", member.name, schema.rust),
+            ),
+        }
+    }
+
+    for variant in &schema.variants {
+        if !members.iter().any(|m| m.name == variant.name) {
+            drift(
+                declaration.line,
+                format!("no variant for {} {}", schema.rust, variant.name),
+            );
+        }
+    }
+
+    drifts
+}
+
+/// Drifts of every declaration in `dir` that has a match in the schema, and the number of
+/// declarations compared.
+pub fn drift(schema: &Schema, dir: &Path) -> (Vec<Drift>, usize) {
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .expect("Unable to read types directory")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().map_or(false, |ext| ext == "sol"))
+        .collect();
+    paths.sort();
+
+    let mut drifts = vec![];
+    let mut compared = 0;
+
+    for declaration in paths.iter().flat_map(|path| declarations(path)) {
+        let (name, _) = schema_name(&declaration.library, &declaration.name);
+
+        let found = if declaration.is_enum {
+            schema
+                .find_enum(&name)
+                .map(|e| compare_enum(&declaration, e))
+        } else {
+            schema
+                .find_struct(&name)
+                .map(|s| compare_struct(&declaration, s))
+        };
+
+        if let Some(found) = found {
+            compared += 1;
+            drifts.extend(found);
+        }
+    }
+
+    (drifts, compared)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use schema::{Encoding, Field};
+
+    const MARKET_TYPES: &str = "\
+library MarketTypes {
+    struct WithdrawBalanceParams {
+        bytes provider_or_client;
+        int256 amount; // signed, as the actor's
+    }
+
+    struct GetDealClientParams {
+        uint64 id;
+    }
+
+    struct GetBalanceReturn {
+        int256 balance;
+        uint256 locked;
+        uint64 extra;
+    }
+
+    struct NotInTheSchema {
+        bool flag;
+    }
+}
+";
+
+    /// Writes `content` to `<name>.sol` in a directory of its own.
+    fn types_dir(name: &str, content: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("drift-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join(format!("{}.sol", name)), content).unwrap();
+        dir
+    }
+
+    fn declaration(
+        library: &str,
+        name: &str,
+        is_enum: bool,
+        members: &[(&str, &str)],
+    ) -> Declaration {
+        Declaration {
+            path: PathBuf::from("Types.sol"),
+            line: 1,
+            library: library.to_string(),
+            name: name.to_string(),
+            is_enum,
+            members: members
+                .iter()
+                .enumerate()
+                .map(|(i, (ty, name))| Member {
+                    line: i + 2,
+                    ty: ty.to_string(),
+                    name: name.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    fn kinds(drifts: &[Drift]) -> Vec<Kind> {
+        drifts.iter().map(|drift| drift.kind).collect()
+    }
+
+    #[test]
+    fn reads_declarations() {
+        let dir = types_dir("MarketTypes", MARKET_TYPES);
+        let declarations = declarations(&dir.join("MarketTypes.sol"));
+        fs::remove_dir_all(dir).unwrap();
+
+        let names: Vec<&str> = declarations.iter().map(|d| d.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "WithdrawBalanceParams",
+                "GetDealClientParams",
+                "GetBalanceReturn",
+                "NotInTheSchema"
+            ]
+        );
+
+        let params = &declarations[0];
+        assert_eq!(params.library, "Market");
+        assert_eq!(params.line, 2);
+        assert_eq!(params.members[1].ty, "int256");
+        assert_eq!(params.members[1].name, "amount");
+        assert_eq!(params.members[1].line, 4);
+    }
+
+    #[test]
+    fn reads_declarations_on_one_line() {
+        let dir = types_dir(
+            "CommonTypes",
+            "library CommonTypes {\n    \
+             enum Flag { Off, On }\n    \
+             struct Pair { uint64 a; int64 b; }\n\
+             }\n",
+        );
+        let declarations = declarations(&dir.join("CommonTypes.sol"));
+        fs::remove_dir_all(dir).unwrap();
+
+        assert!(declarations[0].is_enum);
+        let variants: Vec<&str> = declarations[0]
+            .members
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(variants, ["Off", "On"]);
+
+        let fields: Vec<(&str, &str)> = declarations[1]
+            .members
+            .iter()
+            .map(|m| (m.ty.as_str(), m.name.as_str()))
+            .collect();
+        assert_eq!(fields, [("uint64", "a"), ("int64", "b")]);
+    }
+
+    #[test]
+    fn resolves_aliases_and_qualified_names() {
+        assert_eq!(
+            schema_name("Market", "GetDealClientParams"),
+            ("DealQueryParams".to_string(), "GetDealClientParams")
+        );
+        assert_eq!(
+            schema_name("Market", "CommonTypes.Claim"),
+            ("Claim".to_string(), "Claim")
+        );
+        // Aliases only apply within their library
+        assert_eq!(
+            schema_name("Miner", "GetDealClientParams"),
+            ("GetDealClientParams".to_string(), "GetDealClientParams")
+        );
+    }
+
+    #[test]
+    fn aliases_are_in_the_schema() {
+        let schema = Schema::new();
+        for (library, solidity, name) in ALIASES {
+            assert!(
+                schema.find_struct(name).is_some(),
+                "{}Types.{} is aliased to {}",
+                library,
+                solidity,
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn compares_integers() {
+        assert_eq!(integer("uint"), Some((false, 256)));
+        assert_eq!(integer("int64"), Some((true, 64)));
+        assert_eq!(integer("bytes"), None);
+
+        assert_eq!(compare_type("Market", "uint64", &Type::Uint(64)), None);
+        assert_eq!(
+            compare_type("Market", "uint32", &Type::Uint(64)),
+            Some("uint32 where the actor has uint64, the width differs".to_string())
+        );
+        assert_eq!(
+            compare_type("Market", "uint64", &Type::Int(64)),
+            Some("uint64 where the actor has int64, the signedness differs".to_string())
+        );
+        assert_eq!(
+            compare_type("Market", "uint256", &Type::BigInt),
+            Some("uint256 where the actor has a signed BigInt".to_string())
+        );
+    }
+
+    #[test]
+    fn compares_nested_types() {
+        let ids = Type::array(Type::Uint(64));
+        assert_eq!(compare_type("Market", "uint64[]", &ids), None);
+        assert_eq!(
+            compare_type("Market", "uint64", &ids),
+            Some("uint64 where the actor has uint64[]".to_string())
+        );
+
+        let address = Type::optional(Type::Address);
+        assert_eq!(compare_type("Miner", "bytes", &address), None);
+
+        let claim = Type::Struct("Claim");
+        assert_eq!(compare_type("VerifReg", "CommonTypes.Claim", &claim), None);
+        assert_eq!(
+            compare_type("VerifReg", "CommonTypes.ClaimTerm", &claim),
+            Some("CommonTypes.ClaimTerm where the actor has Claim".to_string())
+        );
+
+        // Enums can also be passed as their code
+        let size = Type::Enum("SectorSize");
+        assert_eq!(compare_type("Miner", "uint64", &size), None);
+        assert_eq!(compare_type("Miner", "CommonTypes.SectorSize", &size), None);
+    }
+
+    #[test]
+    fn compares_fields_by_position() {
+        let schema = Struct {
+            name: "GetBalanceReturn",
+            library: "Market",
+            rust: "fil_actor_market::GetBalanceReturn",
+            encoding: Encoding::Tuple,
+            fields: vec![
+                Field {
+                    name: "balance",
+                    ty: Type::BigInt,
+                },
+                Field {
+                    name: "locked",
+                    ty: Type::BigInt,
+                },
+            ],
+        };
+
+        let same = declaration(
+            "Market",
+            "GetBalanceReturn",
+            false,
+            &[("int256", "balance"), ("int256", "locked")],
+        );
+        assert!(compare_struct(&same, &schema).is_empty());
+
+        let renamed = declaration(
+            "Market",
+            "GetBalanceReturn",
+            false,
+            &[("int256", "balance"), ("int256", "locked_")],
+        );
+        assert!(compare_struct(&renamed, &schema).is_empty());
+        let renamed = declaration(
+            "Market",
+            "GetBalanceReturn",
+            false,
+            &[("int256", "total"), ("int256", "locked")],
+        );
+        let drifts = compare_struct(&renamed, &schema);
+        assert_eq!(kinds(&drifts), [Kind::FieldName]);
+        assert!(!drifts[0].kind.breaking());
+        assert_eq!(drifts[0].location, "Types.sol:2");
+        assert_eq!(
+            drifts[0].message,
+            "MarketTypes.GetBalanceReturn: field total is balance in fil_actor_market::GetBalanceReturn"
+        );
+
+        let missing = declaration(
+            "Market",
+            "GetBalanceReturn",
+            false,
+            &[("int256", "balance")],
+        );
+        let drifts = compare_struct(&missing, &schema);
+        assert_eq!(kinds(&drifts), [Kind::MissingField]);
+        assert_eq!(drifts[0].location, "Types.sol:1");
+        assert_eq!(
+            drifts[0].message,
+            "MarketTypes.GetBalanceReturn: no field 1 for the actor's BigInt locked"
+        );
+
+        let extra = declaration(
+            "Market",
+            "GetBalanceReturn",
+            false,
+            &[
+                ("int256", "balance"),
+                ("uint256", "locked"),
+                ("bool", "extra"),
+            ],
+        );
+        assert_eq!(
+            kinds(&compare_struct(&extra, &schema)),
+            [Kind::Type, Kind::ExtraField]
+        );
+    }
+
+    #[test]
+    fn compares_enums_by_ordinal() {
+        let schema = Schema::new();
+        let proofs = schema.find_enum("RegisteredUpdateProof").unwrap();
+
+        let same = declaration(
+            "Common",
+            "RegisteredUpdateProof",
+            true,
+            &[
+                ("", "StackedDRG2KiBV1"),
+                ("", "StackedDRG8MiBV1"),
+                ("", "StackedDRG512MiBV1"),
+                ("", "StackedDRG32GiBV1"),
+                ("", "StackedDRG64GiBV1"),
+                ("", "Invalid"),
+            ],
+        );
+        assert!(compare_enum(&same, proofs).is_empty());
+
+        let swapped = declaration(
+            "Common",
+            "RegisteredUpdateProof",
+            true,
+            &[
+                ("", "StackedDRG2KiBV1"),
+                ("", "StackedDRG512MiBV1"),
+                ("", "StackedDRG8MiBV1"),
+                ("", "StackedDRG32GiBV1"),
+            ],
+        );
+        let drifts = compare_enum(&swapped, proofs);
+        assert_eq!(kinds(&drifts), [Kind::EnumOrder; 3]);
+        assert_eq!(
+            drifts[0].message,
+            "CommonTypes.RegisteredUpdateProof: StackedDRG512MiBV1 is ordinal 1 but \
+             fvm_shared::sector::RegisteredUpdateProof encodes it as 2"
+        );
+        assert_eq!(
+            drifts[2].message,
+            "CommonTypes.RegisteredUpdateProof: no variant for \
+             fvm_shared::sector::RegisteredUpdateProof StackedDRG64GiBV1"
+        );
+    }
+
+    #[test]
+    fn compares_codes_by_order() {
+        let schema = Schema::new();
+        let sizes = schema.find_enum("SectorSize").unwrap();
+
+        let swapped = declaration(
+            "Common",
+            "SectorSize",
+            true,
+            &[
+                ("", "_2KiB"),
+                ("", "_8MiB"),
+                ("", "_512MiB"),
+                ("", "_64GiB"),
+                ("", "_32GiB"),
+            ],
+        );
+        let drifts = compare_enum(&swapped, sizes);
+        assert_eq!(kinds(&drifts), [Kind::EnumOrder; 2]);
+        assert_eq!(
+            drifts[0].message,
+            "CommonTypes.SectorSize: _64GiB is ordinal 3 but 4 by code in \
+             fvm_shared::sector::SectorSize"
+        );
+    }
+
+    #[test]
+    fn drift_compares_what_the_schema_has() {
+        let dir = types_dir("MarketTypes", MARKET_TYPES);
+        let (drifts, compared) = drift(&Schema::new(), &dir);
+        fs::remove_dir_all(dir).unwrap();
+
+        // NotInTheSchema isn't compared, GetDealClientParams is DealQueryParams
+        assert_eq!(compared, 3);
+        assert_eq!(kinds(&drifts), [Kind::Type, Kind::ExtraField]);
+        assert!(drifts[0].location.ends_with("MarketTypes.sol:13"));
+        assert_eq!(
+            drifts[0].message,
+            "MarketTypes.GetBalanceReturn: field locked: uint256 where the actor has a signed BigInt"
+        );
+    }
+}
@@ -0,0 +1,129 @@
+//! Method numbers of arbitrary names, written as text, JSON or CSV.
+
+use std::str::FromStr;
+
+use serde::Serialize;
+
+use crate::actors::MethodNum;
+use crate::hash;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("unknown format {}, expected text, json or csv", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Entry {
+    pub name: String,
+    /// None when the name breaks the naming rules, `method_hash!` has no number for it.
+    pub method_num: Option<MethodNum>,
+    /// Why `method_hash!` would reject the name.
+    pub problem: Option<String>,
+}
+
+impl Entry {
+    pub fn new(name: &str) -> Self {
+        let (method_num, problem) = match hash::method_number(name) {
+            Ok(number) => (Some(number), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+
+        Entry {
+            name: name.to_string(),
+            method_num,
+            problem,
+        }
+    }
+}
+
+/// Quotes `field` when it has a separator, a quote or a line break in it.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+fn text(entries: &[Entry]) -> String {
+    let width = entries.iter().map(|e| e.name.len()).max().unwrap_or(0);
+
+    entries
+        .iter()
+        .map(|entry| {
+            let number = entry
+                .method_num
+                .map_or_else(|| "-".to_string(), |number| number.to_string());
+            match &entry.problem {
+                Some(problem) => format!("{:width$} {:>10}  ({})\n", entry.name, number, problem),
+                None => format!("{:width$} {:>10}\n", entry.name, number),
+            }
+        })
+        .collect()
+}
+
+fn csv(entries: &[Entry]) -> String {
+    let mut out = String::from("name,method_num,problem\n");
+
+    for entry in entries {
+        out.push_str(&format!(
+            "{},{},{}\n",
+            csv_field(&entry.name),
+            entry.method_num.map(|n| n.to_string()).unwrap_or_default(),
+            csv_field(entry.problem.as_deref().unwrap_or_default())
+        ));
+    }
+
+    out
+}
+
+pub fn render(entries: &[Entry], format: Format) -> String {
+    match format {
+        Format::Text => text(entries),
+        Format::Json => serde_json::to_string_pretty(entries).unwrap() + "\n",
+        Format::Csv => csv(entries),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_names_have_no_number() {
+        let entry = Entry::new("receive");
+
+        assert_eq!(entry.method_num, None);
+        assert_eq!(
+            entry.problem.as_deref(),
+            Some("method name does not start with an uppercase letter or _")
+        );
+    }
+
+    #[test]
+    fn csv_quotes_separators() {
+        let entries = [Entry::new("Receive"), Entry::new("Add,Balance")];
+
+        assert_eq!(
+            csv(&entries),
+            "name,method_num,problem\n\
+             Receive,3726118371,\n\
+             \"Add,Balance\",,method name has characters outside [a-zA-Z0-9_]\n"
+        );
+    }
+}
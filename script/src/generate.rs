@@ -0,0 +1,183 @@
+//! Writes the method numbers as a Solidity library per actor and as a Rust module.
+//!
+//! Methods are sorted by name, so the output only changes where a method was added,
+//! removed or renamed.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use crate::actors::{Actor, Method};
+
+/// Relative to the script directory, where `cargo r` is run from.
+pub const SOLIDITY_DIR: &str = "../contracts/v0.8/types";
+pub const RUST_MODULE_PATH: &str = "../testing/harness/src/method_nums.rs";
+
+/// License and pragma of the Solidity files written by `source`, which `make target`
+/// regenerates.
+pub fn solidity_header(source: &str, target: &str) -> String {
+    format!(
+        "\
+/*******************************************************************************
+ *   (c) 2022 Zondax AG
+ *
+ *  Licensed under the Apache License, Version 2.0 (the \"License\");
+ *  you may not use this file except in compliance with the License.
+ *  You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ *  Unless required by applicable law or agreed to in writing, software
+ *  distributed under the License is distributed on an \"AS IS\" BASIS,
+ *  WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ *  See the License for the specific language governing permissions and
+ *  limitations under the License.
+ ********************************************************************************/
+//
+// GENERATED BY {} - DO NOT EDIT, RUN `make {}`
+//
+pragma solidity >=0.4.25 <=0.8.17;
+",
+        source, target
+    )
+}
+
+fn sorted(actor: &Actor) -> Vec<&Method> {
+    let mut methods: Vec<&Method> = actor.methods.iter().collect();
+    methods.sort_by_key(|method| method.name);
+    methods
+}
+
+/// `ChangePeerID` to `CHANGE_PEER_ID`.
+fn screaming_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut out = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        if i > 0 && c.is_uppercase() {
+            let previous = chars[i - 1];
+            let next_is_lower = chars.get(i + 1).map_or(false, |next| next.is_lowercase());
+            if !previous.is_uppercase() || next_is_lower {
+                out.push('_');
+            }
+        }
+        out.push(c.to_ascii_uppercase());
+    }
+
+    out
+}
+
+pub fn solidity_library(actor: &Actor) -> String {
+    format!("{}MethodNums", actor.name)
+}
+
+/// `<Actor>MethodNums.sol`, with one `uint64 constant` per method named after it.
+pub fn solidity(actor: &Actor) -> String {
+    let mut out = solidity_header("script/src/generate.rs", "generate_method_nums");
+
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// @title Filecoin {} actor method numbers for Solidity.",
+        actor.module
+    )
+    .unwrap();
+    writeln!(out, "/// @author Zondax AG").unwrap();
+    writeln!(out, "library {} {{", solidity_library(actor)).unwrap();
+    for method in sorted(actor) {
+        writeln!(
+            out,
+            "    uint64 constant {} = {};",
+            method.name, method.number
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+
+    out
+}
+
+/// A module per actor with one constant per method and the `METHODS` table, plus
+/// `ACTORS` to look methods up across actors.
+pub fn rust(actors: &[Actor]) -> String {
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "//! Method numbers of the builtin actor methods the Solidity APIs call."
+    )
+    .unwrap();
+    writeln!(out, "//!").unwrap();
+    writeln!(
+        out,
+        "//! Generated by `script/src/generate.rs`, do not edit. Run `make generate_method_nums`."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use fvm_shared::MethodNum;").unwrap();
+
+    for actor in actors {
+        let methods = sorted(actor);
+
+        writeln!(out).unwrap();
+        writeln!(out, "pub mod {} {{", actor.module).unwrap();
+        writeln!(out, "    use fvm_shared::MethodNum;").unwrap();
+        writeln!(out).unwrap();
+        for method in &methods {
+            writeln!(
+                out,
+                "    pub const {}: MethodNum = {};",
+                screaming_snake_case(method.name),
+                method.number
+            )
+            .unwrap();
+        }
+        writeln!(out).unwrap();
+        writeln!(out, "    pub const METHODS: &[(&str, MethodNum)] = &[").unwrap();
+        for method in &methods {
+            writeln!(
+                out,
+                "        (\"{}\", {}),",
+                method.name,
+                screaming_snake_case(method.name)
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ];").unwrap();
+        writeln!(out, "}}").unwrap();
+    }
+
+    writeln!(out).unwrap();
+    writeln!(out, "/// Every actor with its methods.").unwrap();
+    writeln!(
+        out,
+        "pub const ACTORS: &[(&str, &[(&str, MethodNum)])] = &["
+    )
+    .unwrap();
+    for actor in actors {
+        writeln!(out, "    (\"{}\", {}::METHODS),", actor.name, actor.module).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    out
+}
+
+/// Writes every file. The Rust module goes through `rustfmt` when it is available.
+pub fn write(actors: &[Actor]) {
+    for actor in actors {
+        let path = Path::new(SOLIDITY_DIR).join(format!("{}.sol", solidity_library(actor)));
+        fs::write(&path, solidity(actor)).expect("Unable to write Solidity library");
+        println!("wrote {}", path.display());
+    }
+
+    fs::write(RUST_MODULE_PATH, rust(actors)).expect("Unable to write Rust module");
+    match Command::new("rustfmt")
+        .args(["--edition", "2021", RUST_MODULE_PATH])
+        .status()
+    {
+        Ok(status) if status.success() => {}
+        _ => println!("rustfmt failed, {} is left unformatted", RUST_MODULE_PATH),
+    }
+    println!("wrote {}", RUST_MODULE_PATH);
+}
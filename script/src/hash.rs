@@ -0,0 +1,153 @@
+//! FRC-42 method numbers computed at runtime, for names that aren't known at compile time.
+//!
+//! Same algorithm as `frc42_dispatch::method_hash!`: the number is the first 4-byte chunk
+//! of the blake2b-512 digest of `1|<name>` that isn't below 2^24, read big endian.
+//! `Constructor` is always method 1.
+
+use std::fmt;
+
+use crate::actors::MethodNum;
+
+pub const CONSTRUCTOR_METHOD_NAME: &str = "Constructor";
+pub const CONSTRUCTOR_METHOD_NUM: MethodNum = 1;
+
+/// Exported methods are numbered from here on, lower numbers are reserved.
+pub const FIRST_EXPORTED_METHOD: MethodNum = 1 << 24;
+
+const DIGEST_CHUNK_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameError {
+    Empty,
+    /// Names start with an uppercase letter or `_`.
+    InvalidStart,
+    /// Names only contain `[a-zA-Z0-9_]`.
+    InvalidCharacters,
+    /// No chunk of the digest is a valid method number, the name has to change.
+    NoMethodNumber,
+}
+
+impl fmt::Display for NameError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NameError::Empty => write!(f, "empty method name"),
+            NameError::InvalidStart => {
+                write!(
+                    f,
+                    "method name does not start with an uppercase letter or _"
+                )
+            }
+            NameError::InvalidCharacters => {
+                write!(f, "method name has characters outside [a-zA-Z0-9_]")
+            }
+            NameError::NoMethodNumber => {
+                write!(f, "no method number can be derived from the name")
+            }
+        }
+    }
+}
+
+/// Checks `name` against the FRC-42 naming rules.
+pub fn validate(name: &str) -> Result<(), NameError> {
+    let first = name.chars().next().ok_or(NameError::Empty)?;
+
+    if !(first.is_ascii_uppercase() || first == '_') {
+        return Err(NameError::InvalidStart);
+    }
+    if !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+        return Err(NameError::InvalidCharacters);
+    }
+
+    Ok(())
+}
+
+/// The hash alone, whether or not `name` follows the naming rules.
+fn hash(name: &str) -> Result<MethodNum, NameError> {
+    if name == CONSTRUCTOR_METHOD_NAME {
+        return Ok(CONSTRUCTOR_METHOD_NUM);
+    }
+
+    let digest = blake2b_simd::Params::new()
+        .hash_length(64)
+        .hash(format!("1|{}", name).as_bytes());
+
+    digest
+        .as_bytes()
+        .chunks_exact(DIGEST_CHUNK_LENGTH)
+        .map(|chunk| u32::from_be_bytes(chunk.try_into().unwrap()) as MethodNum)
+        .find(|number| *number >= FIRST_EXPORTED_METHOD)
+        .ok_or(NameError::NoMethodNumber)
+}
+
+/// What `method_hash!(name)` gives, or why it refuses to compile.
+pub fn method_number(name: &str) -> Result<MethodNum, NameError> {
+    validate(name)?;
+    hash(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constructor_is_method_1() {
+        assert_eq!(method_number("Constructor"), Ok(CONSTRUCTOR_METHOD_NUM));
+        assert_eq!(CONSTRUCTOR_METHOD_NUM, 1);
+    }
+
+    #[test]
+    fn receive_is_the_frc46_hook_number() {
+        assert_eq!(method_number("Receive"), Ok(3726118371));
+    }
+
+    #[test]
+    fn matches_method_hash() {
+        assert_eq!(
+            method_number("AddBalance"),
+            Ok(frc42_dispatch::method_hash!("AddBalance"))
+        );
+        assert_eq!(
+            method_number("GetDealDataCommitment"),
+            Ok(frc42_dispatch::method_hash!("GetDealDataCommitment"))
+        );
+        assert_eq!(
+            method_number("_Private1"),
+            Ok(frc42_dispatch::method_hash!("_Private1"))
+        );
+    }
+
+    #[test]
+    fn exported_methods_are_not_reserved() {
+        for name in ["Receive", "AddBalance", "Transfer", "_Private1"] {
+            assert!(method_number(name).unwrap() >= FIRST_EXPORTED_METHOD);
+        }
+    }
+
+    #[test]
+    fn rejects_empty_names() {
+        assert_eq!(method_number(""), Err(NameError::Empty));
+    }
+
+    #[test]
+    fn rejects_lowercase_starts() {
+        assert_eq!(method_number("receive"), Err(NameError::InvalidStart));
+        assert_eq!(method_number("1Receive"), Err(NameError::InvalidStart));
+    }
+
+    #[test]
+    fn rejects_non_ascii_names() {
+        assert_eq!(method_number("Émettre"), Err(NameError::InvalidStart));
+        assert_eq!(
+            method_number("Transféré"),
+            Err(NameError::InvalidCharacters)
+        );
+        assert_eq!(
+            method_number("Add Balance"),
+            Err(NameError::InvalidCharacters)
+        );
+        assert_eq!(
+            method_number("Add-Balance"),
+            Err(NameError::InvalidCharacters)
+        );
+    }
+}
@@ -1,173 +1,307 @@
+//! Method numbers of the builtin actor methods the Solidity APIs call.
+//!
+//! `cargo r` prints them, `cargo r -- generate` writes them as Solidity libraries and as
+//! a Rust module, see [`generate`]. `cargo r -- check` checks the constants in the
+//! Solidity sources, see [`check`]. `cargo r -- hash` computes the numbers of any names,
+//! e.g. the methods of a user-defined actor, see [`hash`]. `cargo r -- manifest` saves
+//! those the bundle handles, as found by the dispatch suite, for the current bundle
+//! version and `cargo r -- diff` compares two versions, see [`manifest`].
+//! `cargo r -- codegen` writes Solidity types and CBOR codecs for the params and returns
+//! of the methods, see [`codegen`]. `cargo r -- drift` compares the hand-written Solidity
+//! types with the same schema, see [`drift`]. `cargo r -- cddl` exports the schema as
+//! CDDL and `cargo r -- validate` checks captured payloads against it.
+
+mod actors;
+mod check;
+mod codegen;
+mod drift;
+mod format;
+mod generate;
+mod hash;
+mod manifest;
+
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::exit;
+
+use actors::actors;
+use format::{Entry, Format};
+use manifest::Manifest;
+use schema::Schema;
+
+const USAGE: &str = "\
+usage: script [generate | check]
+       script hash [--format text|json|csv] [--file <path>] [<name>...]
+       script manifest [--version <bundle version>]
+       script diff <old manifest> <new manifest>
+       script codegen [--out <dir>]
+       script drift
+       script cddl [--out <path>]
+       script validate [--file <path>] [<actor> <method> params|return <hex>]";
+
+fn print() {
+    for actor in actors() {
+        println!("{} METHOD NUM", actor.name.to_uppercase());
+        for method in &actor.methods {
+            println!("{} {}", method.name, method.number);
+        }
+        println!();
+    }
+}
+
+fn check_sources() {
+    let (problems, checked) = check::check(Path::new(check::CONTRACTS_DIR));
+
+    for problem in &problems {
+        println!("{}: {}", problem.location, problem.message);
+    }
+    println!(
+        "{} method number constants checked, {} problems",
+        checked,
+        problems.len()
+    );
+
+    if !problems.is_empty() {
+        exit(1);
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{}", USAGE);
+    exit(1);
+}
+
+/// Names are read from the arguments and from `--file`, one per line. Blank lines and
+/// `#` comments are skipped. Exits with 1 if a name breaks the FRC-42 naming rules.
+fn hash_names(args: &[&str]) {
+    let mut format = Format::Text;
+    let mut names: Vec<String> = vec![];
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match *arg {
+            "--format" => {
+                format = match args.next().map(|format| format.parse()) {
+                    Some(Ok(format)) => format,
+                    Some(Err(err)) => {
+                        eprintln!("{}", err);
+                        usage()
+                    }
+                    None => usage(),
+                }
+            }
+            "--file" => {
+                let path = args.next().unwrap_or_else(|| usage());
+                let content = fs::read_to_string(path).expect("Unable to read names");
+                names.extend(
+                    content
+                        .lines()
+                        .map(str::trim)
+                        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                        .map(String::from),
+                );
+            }
+            name if name.starts_with("--") => usage(),
+            name => names.push(name.to_string()),
+        }
+    }
+
+    if names.is_empty() {
+        usage();
+    }
+
+    let entries: Vec<Entry> = names.iter().map(|name| Entry::new(name)).collect();
+    print!("{}", format::render(&entries, format));
+
+    let invalid = entries.iter().filter(|e| e.problem.is_some()).count();
+    if invalid > 0 {
+        eprintln!("{} names break the FRC-42 naming rules", invalid);
+        exit(1);
+    }
+}
+
+fn save_manifest(version: Option<&str>) {
+    let methods =
+        manifest::exported_methods(Path::new(manifest::EXPORTED_METHODS)).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            exit(1)
+        });
+
+    let version = match version {
+        Some(version) => version.to_string(),
+        None => manifest::bundle_version(Path::new(manifest::BUILTIN_ACTORS_MANIFEST))
+            .unwrap_or_else(|| {
+                eprintln!(
+                    "No version in {}, pass --version",
+                    manifest::BUILTIN_ACTORS_MANIFEST
+                );
+                exit(1)
+            }),
+    };
+
+    let path = Manifest::new(&version, methods).save(Path::new(manifest::MANIFEST_DIR));
+    println!("wrote {}", path.display());
+}
+
+/// Exits with 1 if the manifests differ, like `diff`.
+fn diff_manifests(old: &str, new: &str) {
+    let load = |path: &str| {
+        Manifest::load(Path::new(path)).unwrap_or_else(|err| {
+            eprintln!("{}", err);
+            exit(1)
+        })
+    };
+    let (old, new) = (load(old), load(new));
+
+    let diff = manifest::diff(&old, &new);
+    for (actor, changes) in &diff {
+        println!("{}", actor);
+        for change in changes {
+            println!("  {}", change);
+        }
+    }
+    println!(
+        "{} -> {}: {} actors changed",
+        old.bundle_version,
+        new.bundle_version,
+        diff.len()
+    );
+
+    if !diff.is_empty() {
+        exit(1);
+    }
+}
+
+/// Exits with 1 without writing anything if a type refers to one the schema lacks.
+fn generate_codecs(dir: &str) {
+    let schema = Schema::new();
+
+    let unknown = codegen::unknown_references(&schema);
+    if !unknown.is_empty() {
+        for reference in &unknown {
+            eprintln!("unknown type {}", reference);
+        }
+        exit(1);
+    }
+
+    codegen::write(&schema, Path::new(dir));
+}
+
+/// Exits with 1 on drift that changes the encoding, renamed fields alone don't count.
+fn report_drift() {
+    let (drifts, compared) = drift::drift(&Schema::new(), Path::new(drift::TYPES_DIR));
+
+    for drift in &drifts {
+        println!("{}: {}: {}", drift.location, drift.kind, drift.message);
+    }
+    let breaking = drifts.iter().filter(|drift| drift.kind.breaking()).count();
+    println!(
+        "{} Solidity types compared, {} drifts, {} changing the encoding",
+        compared,
+        drifts.len(),
+        breaking
+    );
+
+    if breaking > 0 {
+        exit(1);
+    }
+}
+
+fn export_cddl(path: Option<&str>) {
+    let cddl = schema::cddl::render(&Schema::new());
+
+    match path {
+        Some(path) => {
+            let path = Path::new(path);
+            if let Some(dir) = path.parent() {
+                fs::create_dir_all(dir).expect("Unable to create output directory");
+            }
+            fs::write(path, cddl).expect("Unable to write CDDL");
+            println!("wrote {}", path.display());
+        }
+        None => print!("{}", cddl),
+    }
+}
+
+/// Checks one captured payload, `<actor> <method> params|return <hex>`.
+fn validate_payload(schema: &Schema, line: &str) -> Result<(), String> {
+    let (actor, method, kind, payload) = match line.split_whitespace().collect::<Vec<_>>()[..] {
+        [actor, method, kind] => (actor, method, kind, ""),
+        [actor, method, kind, payload] => (actor, method, kind, payload),
+        _ => return Err("expected <actor> <method> params|return <hex>".to_string()),
+    };
+
+    let method = schema
+        .method(actor, method)
+        .ok_or_else(|| format!("no method {}.{} in the schema", actor, method))?;
+    let payload =
+        hex::decode(payload.trim_start_matches("0x")).map_err(|e| format!("invalid hex: {}", e))?;
+
+    let validated = match kind {
+        "params" => schema.validate_params(method, &payload),
+        "return" => schema.validate_return(method, &payload),
+        _ => return Err(format!("{} is neither params nor return", kind)),
+    };
+    validated.map_err(|invalid| invalid.to_string())
+}
+
+/// Payloads are read from the arguments and from `--file`, one per line. Blank lines and
+/// `#` comments are skipped. Exits with 1 if a payload doesn't match the schema.
+fn validate_payloads(args: &[&str]) {
+    let mut lines: Vec<String> = vec![];
+
+    match args {
+        ["--file", path] => {
+            let content = fs::read_to_string(path).expect("Unable to read payloads");
+            lines.extend(
+                content
+                    .lines()
+                    .map(str::trim)
+                    .filter(|line| !line.is_empty() && !line.starts_with('#'))
+                    .map(String::from),
+            );
+        }
+        [] => usage(),
+        args => lines.push(args.join(" ")),
+    }
+
+    let schema = Schema::new();
+    let mut invalid = 0;
+
+    for line in &lines {
+        match validate_payload(&schema, line) {
+            Ok(()) => println!("ok      {}", line),
+            Err(problem) => {
+                invalid += 1;
+                println!("INVALID {}\n        {}", line, problem);
+            }
+        }
+    }
+    println!("{} payloads checked, {} invalid", lines.len(), invalid);
+
+    if invalid > 0 {
+        exit(1);
+    }
+}
+
 fn main() {
-    println!("MARKET EXPORTED METHOD NUM");
-
-    let AddBalanceExported = frc42_dispatch::method_hash!("AddBalance");
-    let WithdrawBalanceExported = frc42_dispatch::method_hash!("WithdrawBalance");
-    let PublishStorageDealsExported = frc42_dispatch::method_hash!("PublishStorageDeals");
-    let GetBalanceExported = frc42_dispatch::method_hash!("GetBalance");
-    let GetDealDataCommitmentExported = frc42_dispatch::method_hash!("GetDealDataCommitment");
-    let GetDealClientExported = frc42_dispatch::method_hash!("GetDealClient");
-    let GetDealProviderExported = frc42_dispatch::method_hash!("GetDealProvider");
-    let GetDealLabelExported = frc42_dispatch::method_hash!("GetDealLabel");
-    let GetDealTermExported = frc42_dispatch::method_hash!("GetDealTerm");
-    let GetDealTotalPriceExported = frc42_dispatch::method_hash!("GetDealTotalPrice");
-    let GetDealClientCollateralExported = frc42_dispatch::method_hash!("GetDealClientCollateral");
-    let GetDealProviderCollateralExported = frc42_dispatch::method_hash!("GetDealProviderCollateral");
-    let GetDealVerifiedExported = frc42_dispatch::method_hash!("GetDealVerified");
-    let GetDealActivationExported = frc42_dispatch::method_hash!("GetDealActivation");
-
-    println!("AddBalanceExported {}", AddBalanceExported);
-    println!("WithdrawBalanceExported {}", WithdrawBalanceExported);
-    println!("PublishStorageDealsExported {}", PublishStorageDealsExported);
-    println!("GetBalanceExported {}", GetBalanceExported);
-    println!("GetDealDataCommitmentExported {}", GetDealDataCommitmentExported);
-    println!("GetDealClientExported {}", GetDealClientExported);
-    println!("GetDealProviderExported {}", GetDealProviderExported);
-    println!("GetDealLabelExported {}", GetDealLabelExported);
-    println!("GetDealTermExported {}", GetDealTermExported);
-    println!("GetDealTotalPriceExported {}", GetDealTotalPriceExported);
-    println!("GetDealClientCollateralExported {}", GetDealClientCollateralExported);
-    println!("GetDealProviderCollateralExported {}", GetDealProviderCollateralExported);
-    println!("GetDealVerifiedExported {}", GetDealVerifiedExported);
-    println!("GetDealActivationExported {}", GetDealActivationExported);
-
-    println!("MINER EXPORTED METHOD NUM");
-
-    let ChangeWorkerAddressExported = frc42_dispatch::method_hash!("ChangeWorkerAddress");
-    let ChangePeerIDExported = frc42_dispatch::method_hash!("ChangePeerID");
-    let WithdrawBalanceExported = frc42_dispatch::method_hash!("WithdrawBalance");
-    let ChangeMultiaddrsExported = frc42_dispatch::method_hash!("ChangeMultiaddrs");
-    let ConfirmChangeWorkerAddressExported = frc42_dispatch::method_hash!("ConfirmChangeWorkerAddress");
-    let RepayDebtExported = frc42_dispatch::method_hash!("RepayDebt");
-    let ChangeOwnerAddressExported = frc42_dispatch::method_hash!("ChangeOwnerAddress");
-    let ChangeBenificiaryExported = frc42_dispatch::method_hash!("ChangeBeneficiary");
-    let GetBeneficiaryExported = frc42_dispatch::method_hash!("GetBeneficiary");
-    let GetOwnerExported = frc42_dispatch::method_hash!("GetOwner");
-    let IsControllingAddressExported = frc42_dispatch::method_hash!("IsControllingAddress");
-    let GetSectorSizeExported = frc42_dispatch::method_hash!("GetSectorSize");
-    let GetAvailableBalanceExported = frc42_dispatch::method_hash!("GetAvailableBalance");
-    let GetVestingFundsExported = frc42_dispatch::method_hash!("GetVestingFunds");
-    let GetPeerIDExported = frc42_dispatch::method_hash!("GetPeerID");
-    let GetMultiaddrsExported = frc42_dispatch::method_hash!("GetMultiaddrs");
-
-    println!("ChangeWorkerAddressExported {}", ChangeWorkerAddressExported);
-    println!("ChangePeerIDExported {}", ChangePeerIDExported);
-    println!("WithdrawBalanceExported {}", WithdrawBalanceExported);
-    println!("ChangeMultiaddrsExported {}", ChangeMultiaddrsExported);
-    println!("ConfirmChangeWorkerAddressExported {}", ConfirmChangeWorkerAddressExported);
-    println!("RepayDebtExported {}", RepayDebtExported);
-    println!("ChangeOwnerAddressExported {}", ChangeOwnerAddressExported);
-    println!("ChangeBenificiaryExported {}", ChangeBenificiaryExported);
-    println!("GetBeneficiaryExported {}", GetBeneficiaryExported);
-    println!("GetOwnerExported {}", GetOwnerExported);
-    println!("IsControllingAddressExported {}", IsControllingAddressExported);
-    println!("GetSectorSizeExported {}", GetSectorSizeExported);
-    println!("GetAvailableBalanceExported {}", GetAvailableBalanceExported);
-    println!("GetVestingFundsExported {}", GetVestingFundsExported);
-    println!("GetPeerIDExported {}", GetPeerIDExported);
-    println!("GetMultiaddrsExported {}", GetMultiaddrsExported);
-
-
-    println!("POWER EXPORTED METHOD NUM");
-
-    let CreateMinerExported = frc42_dispatch::method_hash!("CreateMiner");
-    let NetworkRawPowerExported = frc42_dispatch::method_hash!("NetworkRawPower");
-    let MinerRawPowerExported = frc42_dispatch::method_hash!("MinerRawPower");
-    let MinerCountExported = frc42_dispatch::method_hash!("MinerCount");
-    let MinerConsensusCountExported = frc42_dispatch::method_hash!("MinerConsensusCount");
-
-    println!("CreateMinerExported {}", CreateMinerExported);
-    println!("NetworkRawPowerExported {}", NetworkRawPowerExported);
-    println!("MinerRawPowerExported {}", MinerRawPowerExported);
-    println!("MinerCountExported {}", MinerCountExported);
-    println!("MinerConsensusCountExported {}", MinerConsensusCountExported);
-
-
-    println!("VERIFIED REGISTRY EXPORTED METHOD NUM");
-
-    let AddVerifiedClientExported = frc42_dispatch::method_hash!("AddVerifiedClient");
-    let RemoveExpiredAllocationsExported = frc42_dispatch::method_hash!("RemoveExpiredAllocations");
-    let GetClaimsExported = frc42_dispatch::method_hash!("GetClaims");
-    let ExtendClaimTermsExported = frc42_dispatch::method_hash!("ExtendClaimTerms");
-    let RemoveExpiredClaimsExported = frc42_dispatch::method_hash!("RemoveExpiredClaims");
-    let UniversalReceiverHook = frc42_dispatch::method_hash!("Receive");
-
-    println!("AddVerifiedClientExported {}", AddVerifiedClientExported);
-    println!("RemoveExpiredAllocationsExported {}", RemoveExpiredAllocationsExported);
-    println!("GetClaimsExported {}", GetClaimsExported);
-    println!("ExtendClaimTermsExported {}", ExtendClaimTermsExported);
-    println!("RemoveExpiredClaimsExported {}", RemoveExpiredClaimsExported);
-    println!("UniversalReceiverHook {}", UniversalReceiverHook);
-
-    println!("DATA CAP EXPORTED METHOD NUM");
-
-    let MintExported = frc42_dispatch::method_hash!("Mint");
-    let DestroyExported = frc42_dispatch::method_hash!("Destroy");
-    let NameExported = frc42_dispatch::method_hash!("Name");
-    let SymbolExported = frc42_dispatch::method_hash!("Symbol");
-    let GranularityExported = frc42_dispatch::method_hash!("GranularityExported");
-    let TotalSupplyExported = frc42_dispatch::method_hash!("TotalSupply");
-    let BalanceExported = frc42_dispatch::method_hash!("Balance");
-    let TransferExported = frc42_dispatch::method_hash!("Transfer");
-    let TransferFromExported = frc42_dispatch::method_hash!("TransferFrom");
-    let IncreaseAllowanceExported = frc42_dispatch::method_hash!("IncreaseAllowance");
-    let DecreaseAllowanceExported = frc42_dispatch::method_hash!("DecreaseAllowance");
-    let RevokeAllowanceExported = frc42_dispatch::method_hash!("RevokeAllowance");
-    let BurnExported = frc42_dispatch::method_hash!("Burn");
-    let BurnFromExported = frc42_dispatch::method_hash!("BurnFrom");
-    let AllowanceExported = frc42_dispatch::method_hash!("Allowance");
-
-
-    println!("MintExported {}", MintExported);
-    println!("DestroyExported {}", DestroyExported);
-    println!("NameExported {}", NameExported);
-    println!("SymbolExported {}", SymbolExported);
-    println!("GranularityExported {}", GranularityExported);
-    println!("TotalSupplyExported {}", TotalSupplyExported);
-    println!("BalanceExported {}", BalanceExported);
-    println!("TransferExported {}", TransferExported);
-    println!("TransferFromExported {}", TransferFromExported);
-    println!("IncreaseAllowanceExported {}", IncreaseAllowanceExported);
-    println!("DecreaseAllowanceExported {}", DecreaseAllowanceExported);
-    println!("RevokeAllowanceExported {}", RevokeAllowanceExported);
-    println!("BurnExported {}", BurnExported);
-    println!("BurnFromExported {}", BurnFromExported);
-    println!("AllowanceExported {}", AllowanceExported);
-
-    println!("INIT EXPORTED METHOD NUM");
-
-    let ExecExported = frc42_dispatch::method_hash!("Exec");
-    println!("ExecExported {}", ExecExported);
-
-
-    println!("ACCOUNT EXPORTED METHOD NUM");
-
-    let AuthenticateMessageExported = frc42_dispatch::method_hash!("AuthenticateMessage");
-    let UniversalReceiverHook = frc42_dispatch::method_hash!("Receive");
-
-    println!("AuthenticateMessageExported {}", AuthenticateMessageExported);
-    println!("UniversalReceiverHook {}", UniversalReceiverHook);
-
-    println!("MULTISIG EXPORTED METHOD NUM");
-
-    let ProposeExported = frc42_dispatch::method_hash!("Propose");
-    let ApproveExported = frc42_dispatch::method_hash!("Approve");
-    let CancelExported = frc42_dispatch::method_hash!("Cancel");
-    let AddSignerExported = frc42_dispatch::method_hash!("AddSigner");
-    let RemoveSignerExported = frc42_dispatch::method_hash!("RemoveSigner");
-    let SwapSignerExported = frc42_dispatch::method_hash!("SwapSigner");
-    let ChangeNumApprovalsThresholdExported =
-        frc42_dispatch::method_hash!("ChangeNumApprovalsThreshold");
-    let LockBalanceExported = frc42_dispatch::method_hash!("LockBalance");
-    let UniversalReceiverHook = frc42_dispatch::method_hash!("Receive");
-
-    println!("ProposeExported {}", ProposeExported);
-    println!("ApproveExported {}", ApproveExported);
-    println!("CancelExported {}", CancelExported);
-    println!("AddSignerExported {}", AddSignerExported);
-    println!("RemoveSignerExported {}", RemoveSignerExported);
-    println!("SwapSignerExported {}", SwapSignerExported);
-    println!("ChangeNumApprovalsThresholdExported {}", ChangeNumApprovalsThresholdExported);
-    println!("LockBalanceExported {}", LockBalanceExported);
-    println!("UniversalReceiverHook {}", UniversalReceiverHook);
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        [] => print(),
+        ["generate"] => generate::write(&actors()),
+        ["check"] => check_sources(),
+        ["hash", ref names @ ..] => hash_names(names),
+        ["manifest"] => save_manifest(None),
+        ["manifest", "--version", version] => save_manifest(Some(version)),
+        ["diff", old, new] => diff_manifests(old, new),
+        ["codegen"] => generate_codecs(codegen::CODEGEN_DIR),
+        ["codegen", "--out", dir] => generate_codecs(dir),
+        ["drift"] => report_drift(),
+        ["cddl"] => export_cddl(None),
+        ["cddl", "--out", path] => export_cddl(Some(path)),
+        ["validate", ref args @ ..] => validate_payloads(args),
+        _ => usage(),
+    }
 }
@@ -0,0 +1,396 @@
+//! Method numbers of every actor, saved per builtin-actors bundle version.
+//!
+//! `cargo r -- manifest` writes `manifests/<version>.json`, the version being that of the
+//! `testing/builtin-actors` checkout unless `--version` says otherwise. The methods are not
+//! taken from the list in [`crate::actors`] but from what the bundle answered: the dispatch
+//! suite sends every listed method to a live actor and writes those it handled to
+//! [`EXPORTED_METHODS`]. A method builtin-actors stopped exporting is left out of the
+//! manifest, and shows up as removed in the diff. `cargo r -- diff`
+//! compares two of them: methods added, removed, renamed or renumbered between the two
+//! versions are what the Solidity APIs need to follow.
+//!
+//! Exported method numbers are hashes of their names, so a renamed method gets a new
+//! number. Renames are paired by number for the few methods that aren't exported, and
+//! otherwise guessed from the names of the methods removed and added on the same actor.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::actors::MethodNum;
+
+/// Relative to the script directory, where `cargo r` is run from.
+pub const MANIFEST_DIR: &str = "manifests";
+pub const BUILTIN_ACTORS_MANIFEST: &str = "../testing/builtin-actors/Cargo.toml";
+/// Written by the dispatch suite, `make method_nums_manifest` runs it first.
+pub const EXPORTED_METHODS: &str = "../testing/dispatch/reports/exported-methods.json";
+
+/// Method numbers by method name, by actor.
+pub type Methods = BTreeMap<String, BTreeMap<String, MethodNum>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub bundle_version: String,
+    pub actors: Methods,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    Added(String, MethodNum),
+    Removed(String, MethodNum),
+    /// Old and new name with their numbers. `guessed` when paired by name only.
+    Renamed {
+        from: (String, MethodNum),
+        to: (String, MethodNum),
+        guessed: bool,
+    },
+    Renumbered(String, MethodNum, MethodNum),
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::Added(name, number) => write!(f, "+ {} {}", name, number),
+            Change::Removed(name, number) => write!(f, "- {} {}", name, number),
+            Change::Renamed { from, to, guessed } => write!(
+                f,
+                "~ {} {} -> {} {}{}",
+                from.0,
+                from.1,
+                to.0,
+                to.1,
+                if *guessed {
+                    " (guessed from the names)"
+                } else {
+                    ""
+                }
+            ),
+            Change::Renumbered(name, from, to) => write!(f, "# {} {} -> {}", name, from, to),
+        }
+    }
+}
+
+/// The `[package]` version of the builtin-actors checkout.
+pub fn bundle_version(cargo_toml: &Path) -> Option<String> {
+    let content = fs::read_to_string(cargo_toml).ok()?;
+    let mut in_package = false;
+
+    for line in content.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_package = line == "[package]";
+        } else if in_package {
+            if let Some(value) = line.strip_prefix("version") {
+                let value = value.trim_start().strip_prefix('=')?.trim();
+                return Some(value.trim_matches('"').to_string());
+            }
+        }
+    }
+
+    None
+}
+
+/// The methods the dispatch suite found the bundle to handle, see [`EXPORTED_METHODS`].
+pub fn exported_methods(path: &Path) -> Result<Methods, String> {
+    let content = fs::read_to_string(path).map_err(|err| {
+        format!(
+            "unable to read {}, `make method_nums_manifest` runs the dispatch suite first: {}",
+            path.display(),
+            err
+        )
+    })?;
+    serde_json::from_str(&content)
+        .map_err(|err| format!("invalid exported methods {}: {}", path.display(), err))
+}
+
+impl Manifest {
+    pub fn new(bundle_version: &str, actors: Methods) -> Self {
+        Manifest {
+            bundle_version: bundle_version.to_string(),
+            actors,
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("unable to read {}: {}", path.display(), err))?;
+        serde_json::from_str(&content)
+            .map_err(|err| format!("invalid manifest {}: {}", path.display(), err))
+    }
+
+    /// Writes `<bundle version>.json` to `dir` and returns its path.
+    pub fn save(&self, dir: &Path) -> PathBuf {
+        fs::create_dir_all(dir).expect("Unable to create manifest directory");
+        let path = dir.join(format!("{}.json", self.bundle_version));
+        fs::write(&path, serde_json::to_string_pretty(self).unwrap() + "\n")
+            .expect("Unable to write manifest");
+        path
+    }
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous + usize::from(ca != *cb);
+            previous = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(row[j + 1] + 1);
+        }
+    }
+
+    row[b.len()]
+}
+
+/// Whether `to` looks like `from` renamed: at most half of the longer name changed.
+fn similar(from: &str, to: &str) -> bool {
+    edit_distance(from, to) * 2 <= from.len().max(to.len())
+}
+
+fn diff_methods(
+    old: &BTreeMap<String, MethodNum>,
+    new: &BTreeMap<String, MethodNum>,
+) -> Vec<Change> {
+    let mut changes = vec![];
+
+    for (name, number) in old {
+        if let Some(new_number) = new.get(name) {
+            if new_number != number {
+                changes.push(Change::Renumbered(name.clone(), *number, *new_number));
+            }
+        }
+    }
+
+    let mut removed: Vec<(String, MethodNum)> = old
+        .iter()
+        .filter(|(name, _)| !new.contains_key(*name))
+        .map(|(name, number)| (name.clone(), *number))
+        .collect();
+    let mut added: Vec<(String, MethodNum)> = new
+        .iter()
+        .filter(|(name, _)| !old.contains_key(*name))
+        .map(|(name, number)| (name.clone(), *number))
+        .collect();
+
+    // Same number under another name, then the closest names
+    for guessed in [false, true] {
+        let mut i = 0;
+        while i < removed.len() {
+            let from = &removed[i];
+            let candidate = added
+                .iter()
+                .enumerate()
+                .filter(|(_, to)| {
+                    if guessed {
+                        similar(&from.0, &to.0)
+                    } else {
+                        to.1 == from.1
+                    }
+                })
+                .min_by_key(|(_, to)| edit_distance(&from.0, &to.0))
+                .map(|(j, _)| j);
+
+            match candidate {
+                Some(j) => {
+                    let to = added.remove(j);
+                    let from = removed.remove(i);
+                    changes.push(Change::Renamed { from, to, guessed });
+                }
+                None => i += 1,
+            }
+        }
+    }
+
+    changes.extend(
+        removed
+            .into_iter()
+            .map(|(name, number)| Change::Removed(name, number)),
+    );
+    changes.extend(
+        added
+            .into_iter()
+            .map(|(name, number)| Change::Added(name, number)),
+    );
+
+    changes
+}
+
+/// Changes from `old` to `new` by actor, leaving out actors that didn't change.
+pub fn diff(old: &Manifest, new: &Manifest) -> BTreeMap<String, Vec<Change>> {
+    let empty = BTreeMap::new();
+    let actors = old.actors.keys().chain(new.actors.keys());
+
+    actors
+        .map(|actor| {
+            let changes = diff_methods(
+                old.actors.get(actor).unwrap_or(&empty),
+                new.actors.get(actor).unwrap_or(&empty),
+            );
+            (actor.clone(), changes)
+        })
+        .filter(|(_, changes)| !changes.is_empty())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn methods(entries: &[(&str, MethodNum)]) -> BTreeMap<String, MethodNum> {
+        entries
+            .iter()
+            .map(|(name, number)| (name.to_string(), *number))
+            .collect()
+    }
+
+    fn renamed(from: (&str, MethodNum), to: (&str, MethodNum), guessed: bool) -> Change {
+        Change::Renamed {
+            from: (from.0.to_string(), from.1),
+            to: (to.0.to_string(), to.1),
+            guessed,
+        }
+    }
+
+    #[test]
+    fn edit_distance_counts_single_character_edits() {
+        assert_eq!(edit_distance("", ""), 0);
+        assert_eq!(edit_distance("Exec", "Exec"), 0);
+        assert_eq!(edit_distance("", "Exec"), 4);
+        assert_eq!(edit_distance("Exec", "Exec4"), 1);
+        assert_eq!(edit_distance("GetOwner", "GetOwnr"), 1);
+        assert_eq!(edit_distance("GetOwner", "SetOwner"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn similar_allows_half_of_the_longer_name() {
+        assert!(similar("GetDealEpochPrice", "GetDealTotalPrice"));
+        assert!(similar("AddVerifierClient", "AddVerifiedClient"));
+        assert!(similar("BalanceOf", "Balance"));
+        assert!(!similar("Name", "Symbol"));
+        assert!(!similar("Mint", "Burn"));
+    }
+
+    #[test]
+    fn unchanged_methods_have_no_changes() {
+        let old = methods(&[("AddBalance", 822473126), ("Exec4", 3)]);
+
+        assert_eq!(diff_methods(&old, &old), vec![]);
+    }
+
+    #[test]
+    fn renumbered_methods() {
+        let old = methods(&[("Exec4", 3)]);
+        let new = methods(&[("Exec4", 4)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![Change::Renumbered("Exec4".to_string(), 3, 4)]
+        );
+    }
+
+    #[test]
+    fn renames_keeping_the_number_are_not_guessed() {
+        let old = methods(&[("Exec4", 3)]);
+        let new = methods(&[("ExecFromEvm", 3)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![renamed(("Exec4", 3), ("ExecFromEvm", 3), false)]
+        );
+    }
+
+    #[test]
+    fn renames_are_guessed_from_similar_names() {
+        let old = methods(&[("GetDealEpochPrice", 4287162428)]);
+        let new = methods(&[("GetDealTotalPrice", 4287162428 + 1)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![renamed(
+                ("GetDealEpochPrice", 4287162428),
+                ("GetDealTotalPrice", 4287162429),
+                true
+            )]
+        );
+    }
+
+    #[test]
+    fn guesses_pair_the_closest_names() {
+        let old = methods(&[("GetOwner", 1), ("GetWorker", 2)]);
+        let new = methods(&[("GetWorkr", 10), ("GetOwnr", 20)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![
+                renamed(("GetOwner", 1), ("GetOwnr", 20), true),
+                renamed(("GetWorker", 2), ("GetWorkr", 10), true),
+            ]
+        );
+    }
+
+    #[test]
+    fn dissimilar_names_are_removed_and_added() {
+        let old = methods(&[("Mint", 116935346)]);
+        let new = methods(&[("Burn", 1434719642)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![
+                Change::Removed("Mint".to_string(), 116935346),
+                Change::Added("Burn".to_string(), 1434719642),
+            ]
+        );
+    }
+
+    #[test]
+    fn same_number_wins_over_a_closer_name() {
+        let old = methods(&[("Exec4", 3)]);
+        let new = methods(&[("Exec5", 5), ("ExecFromEvm", 3)]);
+
+        assert_eq!(
+            diff_methods(&old, &new),
+            vec![
+                renamed(("Exec4", 3), ("ExecFromEvm", 3), false),
+                Change::Added("Exec5".to_string(), 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn diff_leaves_out_unchanged_actors() {
+        let old = Manifest::new(
+            "1",
+            [
+                ("Init".to_string(), methods(&[("Exec4", 3)])),
+                ("Account".to_string(), methods(&[("Receive", 3726118371)])),
+            ]
+            .into_iter()
+            .collect(),
+        );
+        let new = Manifest::new(
+            "2",
+            [
+                ("Init".to_string(), methods(&[("Exec4", 4)])),
+                ("Account".to_string(), methods(&[("Receive", 3726118371)])),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        assert_eq!(
+            diff(&old, &new),
+            [(
+                "Init".to_string(),
+                vec![Change::Renumbered("Exec4".to_string(), 3, 4)]
+            )]
+            .into_iter()
+            .collect()
+        );
+    }
+}
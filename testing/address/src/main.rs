@@ -0,0 +1,220 @@
+//! Cross-protocol address suite.
+//!
+//! The same actors are referred to by every address protocol they have: the sender account
+//! by `f0` and `f1`, a BLS account by `f0` and `f3`, and a contract by `f0`, `f2` and
+//! `f410`. Every form goes through the address helpers and through the API wrappers that
+//! take an address, compared with the native actor. Wrappers that call an actor directly
+//! have to reject every target that is not an ID address.
+
+use std::process::exit;
+
+use ethabi::Token;
+use fil_actors_runtime::{DATACAP_TOKEN_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR};
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::{Address, Protocol};
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::ActorID;
+use harness::address::{from_eth, from_hex, parse, protocol_name, to_eth, to_hex};
+use harness::checks::{check, report, Results};
+use harness::contract::revert_reason;
+use harness::differential::{Case, Differential};
+use harness::method_nums;
+use harness::tokens::address;
+use harness::{Contract, Harness};
+
+const MARKET_API_ARTIFACT: &str = "../../build/v0.8/MarketAPI";
+const DATACAP_API_ARTIFACT: &str = "../../build/v0.8/DataCapAPI";
+const ACCOUNT_API_ARTIFACT: &str = "../../build/v0.8/AccountAPI";
+
+/// Revert reason of `Actor.call` for targets that are not ID addresses.
+const NON_ID_TARGET: &str = "actor address needs to be type ID";
+
+/// Deploys `artifact` next to a BLS account and returns every address form of the sender,
+/// the BLS account and the contract.
+fn setup(artifact: &str) -> (Harness, Contract, Vec<Address>) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, artifact);
+
+    let bls = harness.bls_worker(sender[0].1);
+    let bls_id = harness.lookup_id(&bls).unwrap();
+
+    let forms = vec![
+        Address::new_id(sender[0].0),
+        sender[0].1,
+        Address::new_id(bls_id),
+        bls,
+        contract.address,
+        contract.robust_address.unwrap(),
+        contract.delegated_address.unwrap(),
+    ];
+
+    (harness, contract, forms)
+}
+
+/// Textual, binary and Ethereum forms have to round-trip for every protocol.
+fn helpers(results: &mut Results, forms: &[Address]) {
+    println!("Checking address helpers");
+
+    // The byte encoding the older tests embed in their calldata
+    check(
+        results,
+        "0065 is f0101".to_string(),
+        match (from_hex("0065"), parse("f0101")) {
+            (Ok(decoded), Ok(parsed)) if decoded == parsed && decoded == Address::new_id(101) => {
+                Ok(())
+            }
+            other => Err(format!("got {:?}", other)),
+        },
+    );
+
+    for addr in forms {
+        let name = format!("{} {}", protocol_name(addr), addr);
+
+        check(
+            results,
+            format!("{} text", name),
+            match parse(&addr.to_string()) {
+                Ok(parsed) if parsed == *addr => Ok(()),
+                other => Err(format!("parsed back as {:?}", other)),
+            },
+        );
+
+        check(
+            results,
+            format!("{} bytes", name),
+            match from_hex(&to_hex(addr)) {
+                Ok(decoded) if decoded == *addr => Ok(()),
+                other => Err(format!("decoded back as {:?}", other)),
+            },
+        );
+
+        let has_eth = matches!(addr.protocol(), Protocol::ID | Protocol::Delegated);
+        check(
+            results,
+            format!("{} eth", name),
+            match to_eth(addr) {
+                Some(eth) if from_eth(eth) == *addr => Ok(()),
+                Some(eth) => Err(format!(
+                    "0x{} converts back to {}",
+                    hex::encode(eth),
+                    from_eth(eth)
+                )),
+                None if has_eth => Err("no ethereum form".to_string()),
+                None => Ok(()),
+            },
+        );
+    }
+}
+
+/// The market resolves every form to the same escrow account.
+fn market(results: &mut Results) {
+    println!("Passing every address protocol to MarketAPI");
+
+    let (mut differential, forms) = Differential::new(|| setup(MARKET_API_ARTIFACT));
+
+    for addr in &forms {
+        differential.run(
+            Case::new("add_balance", vec![address(addr)])
+                .native(
+                    STORAGE_MARKET_ACTOR_ADDR,
+                    method_nums::market::ADD_BALANCE,
+                    RawBytes::serialize(addr).unwrap(),
+                )
+                .value(TokenAmount::from_atto(100)),
+        );
+
+        differential.run(Case::new("get_balance", vec![address(addr)]).native(
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::GET_BALANCE,
+            RawBytes::serialize(addr).unwrap(),
+        ));
+    }
+
+    differential.report();
+    results.extend(differential.results());
+}
+
+fn datacap(results: &mut Results) {
+    println!("Passing every address protocol to DataCapAPI");
+
+    let (mut differential, forms) = Differential::new(|| setup(DATACAP_API_ARTIFACT));
+
+    for addr in &forms {
+        differential.run(Case::new("balance", vec![address(addr)]).native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::BALANCE,
+            RawBytes::serialize(addr).unwrap(),
+        ));
+    }
+
+    differential.report();
+    results.extend(differential.results());
+}
+
+/// `Actor.call` has to refuse every target that is not an ID address, and only those.
+/// `harness`, `contract` and `forms` come from [`setup`] with the `AccountAPI`.
+fn targets(results: &mut Results, mut harness: Harness, contract: &Contract, forms: &[Address]) {
+    println!("Passing every address protocol as AccountAPI target");
+
+    let sender = harness.accounts[0].1;
+
+    let params = RawBytes::serialize(UniversalReceiverParams {
+        type_: 0,
+        payload: RawBytes::default(),
+    })
+    .unwrap();
+
+    for addr in forms {
+        let res = harness.call(
+            sender,
+            contract,
+            "universal_receiver_hook",
+            &[address(addr), Token::Bytes(params.to_vec())],
+            TokenAmount::default(),
+        );
+        let reason = revert_reason(&res);
+        let rejected = reason.as_deref() == Some(NON_ID_TARGET);
+
+        let outcome = match (addr.protocol() == Protocol::ID, rejected) {
+            (true, false) | (false, true) => Ok(()),
+            (true, true) => Err("ID target rejected".to_string()),
+            (false, false) => Err(format!(
+                "non-ID target accepted (exit code {}, {:?})",
+                res.msg_receipt.exit_code.value(),
+                reason
+            )),
+        };
+
+        check(
+            results,
+            format!("{} target {}", protocol_name(addr), addr),
+            outcome,
+        );
+    }
+}
+
+fn main() {
+    println!("Testing address protocols through the API contracts");
+
+    let (harness, contract, forms) = setup(ACCOUNT_API_ARTIFACT);
+
+    let mut results = vec![];
+
+    helpers(&mut results, &forms);
+    market(&mut results);
+    datacap(&mut results);
+    targets(&mut results, harness, &contract, &forms);
+
+    let failures = report("address", &results);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
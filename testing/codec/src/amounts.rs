@@ -0,0 +1,207 @@
+use ethabi::Token;
+use fil_actor_market::{GetBalanceReturn, WithdrawBalanceParams, WithdrawBalanceReturn};
+use fil_actor_miner::{GetAvailableBalanceReturn, GetVestingFundsReturn};
+use frc46_token::token::types::{
+    DecreaseAllowanceParams, IncreaseAllowanceParams, TransferFromReturn,
+};
+use fvm_ipld_encoding::{BytesSer, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::{BigInt, Sign};
+use fvm_shared::econ::TokenAmount;
+use harness::tokens::{address, big_int, int};
+use serde::Serialize;
+
+use crate::conformance::Conformance;
+use crate::datacap::DATACAP_CODEC_ARTIFACT;
+use crate::market::MARKET_CODEC_ARTIFACT;
+use crate::miner::MINER_CODEC_ARTIFACT;
+
+/// Powers of two around which the encodings change: sign byte handling, extra magnitude
+/// bytes, the 64 bit boundary and the limits of `int256` and `uint256`.
+const POWERS: [usize; 9] = [0, 7, 8, 15, 63, 64, 128, 255, 256];
+
+/// Zero, and `2^n - 1`, `2^n`, `-2^n` and `-2^n - 1` for each power.
+fn edges() -> Vec<BigInt> {
+    let mut edges = vec![BigInt::from(0)];
+
+    for power in POWERS {
+        let value = BigInt::from(1) << power;
+        edges.extend([&value - 1, value.clone(), -&value, -&value - 1]);
+    }
+
+    edges.sort();
+    edges.dedup();
+    edges
+}
+
+/// The `int256` token for `value`, if it is in range.
+fn int256(value: &BigInt) -> Option<Token> {
+    let bound = BigInt::from(1) << 255;
+    (value >= &-&bound && value < &bound).then(|| Token::Int(big_int(value)))
+}
+
+/// The `uint256` token for `value`, if it is in range.
+fn uint256(value: &BigInt) -> Option<Token> {
+    (value.sign() != Sign::Minus && value.bits() <= 256).then(|| Token::Uint(big_int(value)))
+}
+
+/// Values the solidity type can hold have to decode exactly, the rest have to revert.
+fn deserialize<T: Serialize>(
+    conformance: &mut Conformance,
+    function: &str,
+    native: &T,
+    expected: Option<Vec<Token>>,
+) {
+    match expected {
+        Some(expected) => conformance.deserialize(function, native, expected),
+        None => conformance.rejects(function, native),
+    }
+}
+
+fn market(edges: &[BigInt]) -> usize {
+    println!("Checking amounts in MarketCbor.sol");
+
+    let mut conformance = Conformance::new(MARKET_CODEC_ARTIFACT);
+    let provider_or_client = Address::new_id(1000);
+
+    for value in edges {
+        let atto = TokenAmount::from_atto(value.clone());
+
+        if let Some(token) = int256(value) {
+            conformance.serialize(
+                "serialize_withdraw_balance",
+                vec![Token::Tuple(vec![address(&provider_or_client), token])],
+                &WithdrawBalanceParams {
+                    provider_or_client,
+                    amount: atto.clone(),
+                },
+            );
+        }
+
+        deserialize(
+            &mut conformance,
+            "deserialize_withdraw_balance",
+            &WithdrawBalanceReturn {
+                amount_withdrawn: atto.clone(),
+            },
+            uint256(value).map(|token| vec![Token::Tuple(vec![token])]),
+        );
+
+        deserialize(
+            &mut conformance,
+            "deserialize_get_balance",
+            &GetBalanceReturn {
+                balance: atto.clone(),
+                locked: atto,
+            },
+            int256(value).map(|token| vec![Token::Tuple(vec![token.clone(), token])]),
+        );
+    }
+
+    conformance.report()
+}
+
+fn miner(edges: &[BigInt]) -> usize {
+    println!("Checking amounts in MinerCbor.sol");
+
+    let mut conformance = Conformance::new(MINER_CODEC_ARTIFACT);
+
+    for value in edges {
+        let atto = TokenAmount::from_atto(value.clone());
+
+        deserialize(
+            &mut conformance,
+            "deserialize_get_available_balance",
+            &GetAvailableBalanceReturn {
+                available_balance: atto.clone(),
+            },
+            int256(value).map(|token| vec![Token::Tuple(vec![token])]),
+        );
+
+        deserialize(
+            &mut conformance,
+            "deserialize_get_vesting_funds",
+            &GetVestingFundsReturn {
+                vesting_funds: vec![(100, atto)],
+            },
+            int256(value).map(|token| {
+                vec![Token::Tuple(vec![Token::Array(vec![Token::Tuple(vec![
+                    int(100),
+                    token,
+                ])])])]
+            }),
+        );
+    }
+
+    conformance.report()
+}
+
+fn datacap(edges: &[BigInt]) -> usize {
+    println!("Checking amounts in DataCapCbor.sol");
+
+    let mut conformance = Conformance::new(DATACAP_CODEC_ARTIFACT);
+    let operator = Address::new_id(1000);
+
+    for value in edges {
+        let atto = TokenAmount::from_atto(value.clone());
+
+        if let Some(token) = int256(value) {
+            conformance.serialize(
+                "serialize_increase_allowance",
+                vec![Token::Tuple(vec![address(&operator), token.clone()])],
+                &IncreaseAllowanceParams {
+                    operator,
+                    increase: atto.clone(),
+                },
+            );
+
+            conformance.serialize(
+                "serialize_decrease_allowance",
+                vec![Token::Tuple(vec![address(&operator), token])],
+                &DecreaseAllowanceParams {
+                    operator,
+                    decrease: atto.clone(),
+                },
+            );
+        }
+
+        // Allowance queries return a bare token amount
+        deserialize(
+            &mut conformance,
+            "deserialize_int256",
+            &atto,
+            int256(value).map(|token| vec![token]),
+        );
+
+        deserialize(
+            &mut conformance,
+            "deserialize_transfer_from",
+            &TransferFromReturn {
+                from_balance: TokenAmount::default(),
+                to_balance: TokenAmount::default(),
+                allowance: atto,
+                recipient_data: RawBytes::default(),
+            },
+            int256(value).map(|token| {
+                vec![Token::Tuple(vec![
+                    int(0),
+                    int(0),
+                    token,
+                    Token::Bytes(vec![]),
+                ])]
+            }),
+        );
+    }
+
+    // Only 0 and 1 are sign bytes, and a magnitude past 32 bytes fits no solidity integer
+    conformance.rejects("deserialize_int256", &BytesSer(&[2, 1]));
+    conformance.rejects("deserialize_int256", &BytesSer(&[0; 34]));
+
+    conformance.report()
+}
+
+pub fn run() -> usize {
+    let edges = edges();
+
+    market(&edges) + miner(&edges) + datacap(&edges)
+}
@@ -0,0 +1,175 @@
+use std::collections::BTreeMap;
+
+use ethabi::Token;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::ActorID;
+use harness::contract::{return_bytes, revert_reason};
+use harness::{Contract, Harness};
+use schema::{Schema, Type};
+use serde::Serialize;
+
+use crate::layouts::schema_struct;
+
+/// Runs the codecs exposed by one of the `*CodecTest` contracts and compares them with
+/// the CBOR produced by the matching actor types. That CBOR is also validated against the
+/// schema of the actor type, when it has one.
+pub struct Conformance {
+    harness: Harness,
+    sender: Address,
+    contract: Contract,
+    schema: Schema,
+    checks: usize,
+    /// First mismatch and number of mismatches, per contract function.
+    mismatches: BTreeMap<String, (String, usize)>,
+}
+
+impl Conformance {
+    pub fn new(artifact: &str) -> Self {
+        let mut harness = Harness::new();
+
+        let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+        // Instantiate machine
+        harness.instantiate();
+
+        let contract = harness.deploy(sender[0].1, artifact);
+
+        Conformance {
+            harness,
+            sender: sender[0].1,
+            contract,
+            schema: Schema::new(),
+            checks: 0,
+            mismatches: BTreeMap::new(),
+        }
+    }
+
+    /// Solidity to CBOR: `function` has to produce exactly the bytes `native` serializes to.
+    pub fn serialize<T: Serialize>(&mut self, function: &str, args: Vec<Token>, native: &T) {
+        let expected = RawBytes::serialize(native).unwrap().to_vec();
+        self.validate::<T>(&expected);
+
+        let outcome = self.invoke(function, &args).and_then(|output| {
+            let actual = ethabi::decode(&[ethabi::ParamType::Bytes], &output)
+                .map_err(|e| format!("invalid output: {}", e))?
+                .remove(0)
+                .into_bytes()
+                .unwrap();
+
+            if actual == expected {
+                Ok(())
+            } else {
+                Err(format!(
+                    "expected {}, got {}",
+                    hex::encode(&expected),
+                    hex::encode(&actual)
+                ))
+            }
+        });
+
+        self.record(function, outcome);
+    }
+
+    /// CBOR to solidity: `function` has to decode what `native` serializes to into exactly
+    /// the ABI encoding of `expected`.
+    pub fn deserialize<T: Serialize>(&mut self, function: &str, native: &T, expected: Vec<Token>) {
+        let cbor = RawBytes::serialize(native).unwrap().to_vec();
+        self.validate::<T>(&cbor);
+        let expected_output = ethabi::encode(&expected);
+
+        let outcome = self
+            .invoke(function, &[Token::Bytes(cbor.clone())])
+            .and_then(|output| {
+                if output == expected_output {
+                    Ok(())
+                } else {
+                    Err(format!(
+                        "decoding {}: expected {:?}, got {:?}",
+                        hex::encode(&cbor),
+                        expected,
+                        self.contract
+                            .function(function, &[Token::Bytes(cbor.clone())])
+                            .decode_output(&output)
+                    ))
+                }
+            });
+
+        self.record(function, outcome);
+    }
+
+    /// CBOR to solidity for values the solidity type can't hold: `function` has to revert
+    /// instead of returning something else.
+    pub fn rejects<T: Serialize>(&mut self, function: &str, native: &T) {
+        let cbor = RawBytes::serialize(native).unwrap().to_vec();
+        self.validate::<T>(&cbor);
+
+        let outcome = match self.invoke(function, &[Token::Bytes(cbor.clone())]) {
+            Ok(output) => Err(format!(
+                "decoding {}: expected a revert, got {:?}",
+                hex::encode(&cbor),
+                self.contract
+                    .function(function, &[Token::Bytes(cbor.clone())])
+                    .decode_output(&output)
+            )),
+            Err(_) => Ok(()),
+        };
+
+        self.record(function, outcome);
+    }
+
+    /// Checks the actor's own encoding of `T` against the schema.
+    fn validate<T>(&mut self, cbor: &[u8]) {
+        if let Some(ty) = schema_struct::<T>(&self.schema).map(|s| Type::Struct(s.name)) {
+            let outcome = self
+                .schema
+                .validate(&ty, cbor)
+                .map_err(|invalid| format!("{}: {}", hex::encode(cbor), invalid));
+            self.record(&format!("schema {}", std::any::type_name::<T>()), outcome);
+        }
+    }
+
+    fn invoke(&mut self, function: &str, args: &[Token]) -> Result<Vec<u8>, String> {
+        let res = self.harness.call(
+            self.sender,
+            &self.contract,
+            function,
+            args,
+            TokenAmount::default(),
+        );
+
+        if res.msg_receipt.exit_code.value() != 0 {
+            return Err(format!(
+                "reverted with exit code {} ({:?})",
+                res.msg_receipt.exit_code.value(),
+                revert_reason(&res)
+            ));
+        }
+
+        Ok(return_bytes(&res.msg_receipt.return_data))
+    }
+
+    fn record(&mut self, function: &str, outcome: Result<(), String>) {
+        self.checks += 1;
+
+        if let Err(mismatch) = outcome {
+            self.mismatches
+                .entry(function.to_string())
+                .or_insert((mismatch, 0))
+                .1 += 1;
+        }
+    }
+
+    /// Prints a summary and returns the number of functions with at least one mismatch.
+    pub fn report(&self) -> usize {
+        let failed: usize = self.mismatches.values().map(|(_, count)| count).sum();
+        println!("{} checks, {} mismatches", self.checks, failed);
+
+        for (function, (first, count)) in &self.mismatches {
+            println!("  {} ({} times): {}", function, count, first);
+        }
+
+        self.mismatches.len()
+    }
+}
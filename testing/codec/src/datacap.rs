@@ -0,0 +1,196 @@
+use ethabi::Token;
+use frc46_token::token::types::{
+    BurnFromParams, BurnFromReturn, BurnParams, BurnReturn, DecreaseAllowanceParams,
+    GetAllowanceParams, IncreaseAllowanceParams, RevokeAllowanceParams, TransferFromParams,
+    TransferFromReturn, TransferParams, TransferReturn,
+};
+use fvm_ipld_encoding::RawBytes;
+use harness::tokens::{address, amount};
+use rand::rngs::StdRng;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+pub const DATACAP_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/DataCapCodecTest";
+
+fn raw_bytes(rng: &mut StdRng) -> RawBytes {
+    RawBytes::new(random::bytes(rng, 64))
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking DataCapCbor.sol");
+
+    let mut conformance = Conformance::new(DATACAP_CODEC_ARTIFACT);
+
+    conformance.serialize("serialize_null", vec![], &());
+
+    for _ in 0..rounds {
+        let addr = random::address(rng);
+        conformance.serialize("serialize_address", vec![address(&addr)], &addr);
+        conformance.deserialize("deserialize_address", &addr, vec![address(&addr)]);
+
+        let name = random::string(rng, 40);
+        conformance.deserialize("deserialize_string", &name, vec![Token::String(name.clone())]);
+
+        let balance = random::signed_amount(rng);
+        conformance.deserialize("deserialize_int256", &balance, vec![amount(&balance)]);
+
+        let params = GetAllowanceParams {
+            owner: random::address(rng),
+            operator: random::address(rng),
+        };
+        conformance.serialize(
+            "serialize_get_allowance",
+            vec![Token::Tuple(vec![
+                address(&params.owner),
+                address(&params.operator),
+            ])],
+            &params,
+        );
+
+        let params = TransferParams {
+            to: random::address(rng),
+            amount: random::signed_amount(rng),
+            operator_data: raw_bytes(rng),
+        };
+        conformance.serialize(
+            "serialize_transfer",
+            vec![Token::Tuple(vec![
+                address(&params.to),
+                amount(&params.amount),
+                Token::Bytes(params.operator_data.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = TransferReturn {
+            from_balance: random::signed_amount(rng),
+            to_balance: random::signed_amount(rng),
+            recipient_data: raw_bytes(rng),
+        };
+        conformance.deserialize(
+            "deserialize_transfer",
+            &ret,
+            vec![Token::Tuple(vec![
+                amount(&ret.from_balance),
+                amount(&ret.to_balance),
+                Token::Bytes(ret.recipient_data.to_vec()),
+            ])],
+        );
+
+        let params = TransferFromParams {
+            from: random::address(rng),
+            to: random::address(rng),
+            amount: random::signed_amount(rng),
+            operator_data: raw_bytes(rng),
+        };
+        conformance.serialize(
+            "serialize_transfer_from",
+            vec![Token::Tuple(vec![
+                address(&params.from),
+                address(&params.to),
+                amount(&params.amount),
+                Token::Bytes(params.operator_data.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = TransferFromReturn {
+            from_balance: random::signed_amount(rng),
+            to_balance: random::signed_amount(rng),
+            allowance: random::signed_amount(rng),
+            recipient_data: raw_bytes(rng),
+        };
+        conformance.deserialize(
+            "deserialize_transfer_from",
+            &ret,
+            vec![Token::Tuple(vec![
+                amount(&ret.from_balance),
+                amount(&ret.to_balance),
+                amount(&ret.allowance),
+                Token::Bytes(ret.recipient_data.to_vec()),
+            ])],
+        );
+
+        let params = IncreaseAllowanceParams {
+            operator: random::address(rng),
+            increase: random::signed_amount(rng),
+        };
+        conformance.serialize(
+            "serialize_increase_allowance",
+            vec![Token::Tuple(vec![
+                address(&params.operator),
+                amount(&params.increase),
+            ])],
+            &params,
+        );
+
+        let params = DecreaseAllowanceParams {
+            operator: random::address(rng),
+            decrease: random::signed_amount(rng),
+        };
+        conformance.serialize(
+            "serialize_decrease_allowance",
+            vec![Token::Tuple(vec![
+                address(&params.operator),
+                amount(&params.decrease),
+            ])],
+            &params,
+        );
+
+        let params = RevokeAllowanceParams {
+            operator: random::address(rng),
+        };
+        conformance.serialize(
+            "serialize_revoke_allowance",
+            vec![Token::Tuple(vec![address(&params.operator)])],
+            &params,
+        );
+
+        let params = BurnParams {
+            amount: random::signed_amount(rng),
+        };
+        conformance.serialize(
+            "serialize_burn",
+            vec![Token::Tuple(vec![amount(&params.amount)])],
+            &params,
+        );
+
+        let ret = BurnReturn {
+            balance: random::signed_amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_burn",
+            &ret,
+            vec![Token::Tuple(vec![amount(&ret.balance)])],
+        );
+
+        let params = BurnFromParams {
+            owner: random::address(rng),
+            amount: random::signed_amount(rng),
+        };
+        conformance.serialize(
+            "serialize_burn_from",
+            vec![Token::Tuple(vec![
+                address(&params.owner),
+                amount(&params.amount),
+            ])],
+            &params,
+        );
+
+        let ret = BurnFromReturn {
+            balance: random::signed_amount(rng),
+            allowance: random::signed_amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_burn_from",
+            &ret,
+            vec![Token::Tuple(vec![
+                amount(&ret.balance),
+                amount(&ret.allowance),
+            ])],
+        );
+    }
+
+    conformance.report()
+}
@@ -0,0 +1,264 @@
+use std::collections::BTreeMap;
+
+use ethabi::Token;
+use fvm_ipld_encoding::{to_vec, BytesSer};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::ActorID;
+use harness::contract::{panic_code, return_bytes, revert_reason};
+use harness::tokens::uint;
+use harness::{Contract, Harness};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::random;
+use crate::reference::{self, Decoded};
+
+const CBOR_DECODE_ARTIFACT: &str = "../../build/v0.8/tests/CborDecodeTest";
+
+/// Panic code of a failed `assert`, which is how the readers reject their input.
+const ASSERT_PANIC: u64 = 0x01;
+
+type Reader = fn(&[u8], usize) -> Decoded;
+
+/// Every reader exposed by `CborDecodeTest`, with its reference decoding.
+const READERS: [(&str, Reader); 16] = [
+    ("is_null_next", reference::is_null_next),
+    ("read_bool", reference::read_bool),
+    ("read_fixed_array", reference::read_fixed_array),
+    ("read_string", reference::read_string),
+    ("read_bytes", reference::read_bytes),
+    ("read_uint256", reference::read_uint256),
+    ("read_int256", reference::read_int256),
+    ("read_uint64", reference::read_uint64),
+    ("read_uint32", reference::read_uint32),
+    ("read_uint16", reference::read_uint16),
+    ("read_uint8", reference::read_uint8),
+    ("read_int64", reference::read_int64),
+    ("read_int32", reference::read_int32),
+    ("read_int16", reference::read_int16),
+    ("read_int8", reference::read_int8),
+    ("parse_cbor_header", reference::parse_cbor_header),
+];
+
+enum Outcome {
+    Returned(Vec<u8>),
+    Reverted(Option<String>),
+    Panicked(u64),
+}
+
+/// Header with the argument in `width` extra bytes, or inline if `width` is 0.
+fn encode_header(major: u8, arg: u64, width: usize) -> Vec<u8> {
+    let low = match width {
+        0 => return vec![major << 5 | arg as u8],
+        1 => 24,
+        2 => 25,
+        4 => 26,
+        _ => 27,
+    };
+
+    let mut header = vec![major << 5 | low];
+    header.extend_from_slice(&arg.to_be_bytes()[8 - width..]);
+    header
+}
+
+/// A well formed item, mostly produced by the same encoder the actors use.
+fn item(rng: &mut StdRng) -> Vec<u8> {
+    match rng.gen_range(0..8) {
+        0 => to_vec(&random::u64(rng)),
+        1 => to_vec(&random::i64(rng)),
+        2 => to_vec(&random::bool(rng)),
+        3 => to_vec(&()),
+        4 => to_vec(&BytesSer(&random::bytes(rng, 40))),
+        5 => to_vec(&random::string(rng, 40)),
+        6 => to_vec(&random::vec(rng, 4, random::u64)),
+        // The IPLD encoder doesn't produce bignums
+        _ => {
+            let tag = if random::bool(rng) { 0xc2 } else { 0xc3 };
+            let mut item = vec![tag];
+            item.extend(to_vec(&BytesSer(&random::bytes(rng, 40))).unwrap());
+            Ok(item)
+        }
+    }
+    .unwrap()
+}
+
+/// A possibly malformed item: truncated, non-canonical, reserved, corrupted or garbage.
+fn mutated_item(rng: &mut StdRng) -> Vec<u8> {
+    let mut item = item(rng);
+
+    match rng.gen_range(0..8) {
+        0 => item.truncate(rng.gen_range(0..item.len())),
+        1 => {
+            let header = reference::header(&item, 0).unwrap();
+            let minimal = match header.arg {
+                0..=23 => 0,
+                24..=0xff => 1,
+                0x100..=0xffff => 2,
+                0x1_0000..=0xffff_ffff => 4,
+                _ => 8,
+            };
+            if let Some(width) = [1, 2, 4, 8].into_iter().find(|width| *width > minimal) {
+                let mut wide = encode_header(header.major, header.arg, width);
+                wide.extend_from_slice(&item[header.next..]);
+                item = wide;
+            }
+        }
+        2 => item[0] = item[0] & 0xe0 | rng.gen_range(28..32),
+        3 => {
+            let idx = rng.gen_range(0..item.len());
+            item[idx] = rng.gen();
+        }
+        4 => {
+            let content = [0xff, 0xfe, 0xc0, 0x80];
+            item = encode_header(3, content.len() as u64, 0);
+            item.extend_from_slice(&content);
+        }
+        5 => item = random::bytes(rng, 16),
+        _ => {}
+    }
+
+    item
+}
+
+/// Input and index for one round: the item under test, maybe behind a few valid items.
+fn input(rng: &mut StdRng) -> (Vec<u8>, usize) {
+    let mut input: Vec<u8> = (0..rng.gen_range(0..3)).flat_map(|_| item(rng)).collect();
+    let idx = input.len();
+    input.extend(mutated_item(rng));
+
+    if rng.gen_bool(0.05) {
+        let past_end = input.len() + rng.gen_range(0..3);
+        return (input, past_end);
+    }
+
+    (input, idx)
+}
+
+/// Feeds the same inputs to the solidity readers and the reference decoding.
+struct Fuzzer {
+    harness: Harness,
+    sender: Address,
+    contract: Contract,
+    runs: usize,
+    /// First finding and number of findings, per reader and kind of finding.
+    findings: BTreeMap<(&'static str, &'static str), (String, usize)>,
+}
+
+impl Fuzzer {
+    fn new() -> Self {
+        let mut harness = Harness::new();
+
+        let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+        // Instantiate machine
+        harness.instantiate();
+
+        let contract = harness.deploy(sender[0].1, CBOR_DECODE_ARTIFACT);
+
+        Fuzzer {
+            harness,
+            sender: sender[0].1,
+            contract,
+            runs: 0,
+            findings: BTreeMap::new(),
+        }
+    }
+
+    fn invoke(&mut self, function: &str, input: &[u8], idx: usize) -> Outcome {
+        let res = self.harness.call(
+            self.sender,
+            &self.contract,
+            function,
+            &[Token::Bytes(input.to_vec()), uint(idx as u64)],
+            TokenAmount::default(),
+        );
+
+        if res.msg_receipt.exit_code.value() == 0 {
+            return Outcome::Returned(return_bytes(&res.msg_receipt.return_data));
+        }
+
+        match panic_code(&res) {
+            Some(code) => Outcome::Panicked(code),
+            None => Outcome::Reverted(revert_reason(&res)),
+        }
+    }
+
+    fn check(&mut self, function: &'static str, reader: Reader, input: &[u8], idx: usize) {
+        self.runs += 1;
+
+        let expected = reader(input, idx);
+        let outcome = self.invoke(function, input, idx);
+
+        let finding = match (&expected, &outcome) {
+            (Ok(tokens), Outcome::Returned(output)) if *output == ethabi::encode(tokens) => None,
+            (Ok(tokens), Outcome::Returned(output)) => Some((
+                "wrong value",
+                format!("expected {:?}, got {:?}", tokens, self.decode(function, output)),
+            )),
+            (Err(reason), Outcome::Returned(output)) => Some((
+                "accepted malformed input",
+                format!("{}, got {:?}", reason, self.decode(function, output)),
+            )),
+            (_, Outcome::Panicked(code)) if *code != ASSERT_PANIC => {
+                Some(("panic", format!("panic 0x{:02x}", code)))
+            }
+            (Ok(tokens), Outcome::Panicked(_)) => Some((
+                "rejected valid input",
+                format!("expected {:?}, got a failed assert", tokens),
+            )),
+            (Ok(tokens), Outcome::Reverted(reason)) => Some((
+                "rejected valid input",
+                format!("expected {:?}, got revert {:?}", tokens, reason),
+            )),
+            (Err(_), _) => None,
+        };
+
+        if let Some((kind, details)) = finding {
+            self.findings
+                .entry((function, kind))
+                .or_insert((
+                    format!("{} at {}: {}", hex::encode(input), idx, details),
+                    0,
+                ))
+                .1 += 1;
+        }
+    }
+
+    fn decode(&self, function: &str, output: &[u8]) -> Result<Vec<Token>, ethabi::Error> {
+        self.contract
+            .abi
+            .function(function)
+            .and_then(|function| function.decode_output(output))
+    }
+
+    /// Prints a summary and returns the number of readers with at least one finding.
+    fn report(&self) -> usize {
+        let found: usize = self.findings.values().map(|(_, count)| count).sum();
+        println!("{} runs, {} findings", self.runs, found);
+
+        for ((function, kind), (first, count)) in &self.findings {
+            println!("  {} {} ({} times): {}", function, kind, count, first);
+        }
+
+        let mut readers: Vec<_> = self.findings.keys().map(|(function, _)| function).collect();
+        readers.dedup();
+        readers.len()
+    }
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Fuzzing CborDecode.sol");
+
+    let mut fuzzer = Fuzzer::new();
+
+    for _ in 0..rounds {
+        let (input, idx) = input(rng);
+
+        for (function, reader) in READERS {
+            fuzzer.check(function, reader, &input, idx);
+        }
+    }
+
+    fuzzer.report()
+}
@@ -0,0 +1,79 @@
+use ethabi::Token;
+use fil_actor_init::{Exec4Params, Exec4Return, ExecParams, ExecReturn};
+use fvm_ipld_encoding::RawBytes;
+use harness::tokens::{address, cid};
+use rand::rngs::StdRng;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+const INIT_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/InitCodecTest";
+
+fn raw_bytes(rng: &mut StdRng) -> RawBytes {
+    RawBytes::new(random::bytes(rng, 64))
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking InitCbor.sol");
+
+    let mut conformance = Conformance::new(INIT_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        // The solidity side writes the code CID as plain bytes instead of a tagged CID
+        let params = ExecParams {
+            code_cid: random::cid(rng),
+            constructor_params: raw_bytes(rng),
+        };
+        conformance.serialize(
+            "serialize_exec",
+            vec![Token::Tuple(vec![
+                cid(&params.code_cid),
+                Token::Bytes(params.constructor_params.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = ExecReturn {
+            id_address: random::address(rng),
+            robust_address: random::address(rng),
+        };
+        conformance.deserialize(
+            "deserialize_exec",
+            &ret,
+            vec![Token::Tuple(vec![
+                address(&ret.id_address),
+                address(&ret.robust_address),
+            ])],
+        );
+
+        let params = Exec4Params {
+            code_cid: random::cid(rng),
+            constructor_params: raw_bytes(rng),
+            subaddress: raw_bytes(rng),
+        };
+        conformance.serialize(
+            "serialize_exec4",
+            vec![Token::Tuple(vec![
+                cid(&params.code_cid),
+                Token::Bytes(params.constructor_params.to_vec()),
+                Token::Bytes(params.subaddress.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = Exec4Return {
+            id_address: random::address(rng),
+            robust_address: random::address(rng),
+        };
+        conformance.deserialize(
+            "deserialize_exec4",
+            &ret,
+            vec![Token::Tuple(vec![
+                address(&ret.id_address),
+                address(&ret.robust_address),
+            ])],
+        );
+    }
+
+    conformance.report()
+}
@@ -0,0 +1,605 @@
+//! The schema structs checked against the Rust types they describe.
+//!
+//! A value of every actor type the schema has a struct for is encoded the way the actor
+//! encodes it and validated against the struct of the same crate and name, so a field
+//! added, dropped, reordered or retyped on either side shows up here rather than in the
+//! generated codecs. Schema structs no actor type reaches, directly or through the fields
+//! of another, are reported too.
+
+use std::any::type_name;
+use std::collections::{BTreeMap, BTreeSet};
+
+use fil_actor_account::types::AuthenticateMessageParams;
+use fil_actor_datacap::{DestroyParams, MintParams};
+use fil_actor_init::{Exec4Params, Exec4Return, ExecParams, ExecReturn};
+use fil_actor_market::{
+    ClientDealProposal, DealProposal, DealQueryParams, GetBalanceReturn, GetDealActivationReturn,
+    GetDealClientCollateralReturn, GetDealClientReturn, GetDealDataCommitmentReturn,
+    GetDealLabelReturn, GetDealProviderCollateralReturn, GetDealProviderReturn, GetDealTermReturn,
+    GetDealTotalPriceReturn, GetDealVerifiedReturn, Label, PublishStorageDealsParams,
+    PublishStorageDealsReturn, WithdrawBalanceParams, WithdrawBalanceReturn,
+};
+use fil_actor_miner::{
+    ActiveBeneficiary, BeneficiaryTerm, ChangeBeneficiaryParams, ChangeMultiaddrsParams,
+    ChangePeerIDParams, ChangeWorkerAddressParams, GetAvailableBalanceReturn, GetBeneficiaryReturn,
+    GetMultiaddrsReturn, GetOwnerReturn, GetPeerIDReturn, GetSectorSizeReturn,
+    GetVestingFundsReturn, IsControllingAddressParam, IsControllingAddressReturn,
+    PendingBeneficiaryChange,
+};
+use fil_actor_multisig::{
+    AddSignerParams, ApproveReturn, ChangeNumApprovalsThresholdParams, LockBalanceParams,
+    ProposeParams, ProposeReturn, RemoveSignerParams, SwapSignerParams, TxnID, TxnIDParams,
+};
+use fil_actor_power::{
+    CreateMinerParams, CreateMinerReturn, MinerConsensusCountReturn, MinerCountReturn,
+    MinerRawPowerParams, MinerRawPowerReturn, NetworkRawPowerReturn,
+};
+use fil_actor_verifreg::{
+    AllocationsResponse, Claim, ClaimTerm, ExtendClaimTermsParams, GetClaimsParams,
+    GetClaimsReturn, RemoveExpiredAllocationsParams, RemoveExpiredAllocationsReturn,
+    RemoveExpiredClaimsParams, RemoveExpiredClaimsReturn, VerifierParams,
+};
+use fil_actors_runtime::{BatchReturn, FailCode};
+use frc46_token::token::types::{
+    BurnFromParams, BurnFromReturn, BurnParams, BurnReturn, DecreaseAllowanceParams,
+    GetAllowanceParams, IncreaseAllowanceParams, MintReturn, RevokeAllowanceParams,
+    TransferFromParams, TransferFromReturn, TransferParams, TransferReturn,
+};
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use harness::enums::{POST_PROOFS, SECTOR_SIZES};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+use schema::{Schema, Struct, Type};
+use serde::Serialize;
+
+use crate::random;
+
+/// The schema struct of the same crate and name as `T`, e.g.
+/// `fil_actor_market::WithdrawBalanceParams` for `fil_actor_market::types::...`.
+pub fn schema_struct<T>(schema: &Schema) -> Option<&Struct> {
+    let path = type_name::<T>();
+    if path.contains('<') {
+        return None;
+    }
+    let krate = path.split("::").next();
+    let name = path.rsplit("::").next()?;
+
+    schema
+        .structs
+        .iter()
+        .find(|s| s.name == name && s.rust.split("::").next() == krate)
+}
+
+/// Adds the structs `ty` refers to, and those their fields refer to, to `reached`.
+fn reach(schema: &Schema, ty: &Type, reached: &mut BTreeSet<&'static str>) {
+    match ty {
+        Type::Array(item) | Type::Optional(item) => reach(schema, item, reached),
+        Type::Struct(name) => {
+            if !reached.insert(*name) {
+                return;
+            }
+            if let Some(s) = schema.find_struct(name) {
+                for field in &s.fields {
+                    reach(schema, &field.ty, reached);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+struct Layouts {
+    schema: Schema,
+    /// Structs an actor type was validated against, and those they refer to.
+    reached: BTreeSet<&'static str>,
+    /// First problem per actor type.
+    problems: BTreeMap<&'static str, String>,
+}
+
+impl Layouts {
+    fn check<T: Serialize>(&mut self, native: &T) {
+        let path = type_name::<T>();
+        let s = match schema_struct::<T>(&self.schema) {
+            Some(s) => s,
+            None => {
+                self.problems
+                    .entry(path)
+                    .or_insert_with(|| "no struct of that name in the schema".to_string());
+                return;
+            }
+        };
+
+        let ty = Type::Struct(s.name);
+        let cbor = RawBytes::serialize(native).unwrap().to_vec();
+        if let Err(invalid) = self.schema.validate(&ty, &cbor) {
+            self.problems
+                .entry(path)
+                .or_insert_with(|| format!("{}: {}", hex::encode(&cbor), invalid));
+        }
+
+        reach(&self.schema, &ty, &mut self.reached);
+    }
+}
+
+fn raw_bytes(rng: &mut StdRng) -> RawBytes {
+    RawBytes::new(random::bytes(rng, 64))
+}
+
+fn ids(rng: &mut StdRng) -> Vec<u64> {
+    random::vec(rng, 5, random::u64)
+}
+
+fn multiaddrs(rng: &mut StdRng) -> Vec<BytesDe> {
+    random::vec(rng, 3, |rng| BytesDe(random::bytes(rng, 20)))
+}
+
+fn batch(rng: &mut StdRng) -> BatchReturn {
+    BatchReturn {
+        success_count: rng.gen(),
+        fail_codes: random::vec(rng, 3, |rng| FailCode {
+            idx: rng.gen(),
+            code: ExitCode::new(rng.gen()),
+        }),
+    }
+}
+
+fn deal(rng: &mut StdRng) -> DealProposal {
+    let label = if random::bool(rng) {
+        Label::String(random::string(rng, 40))
+    } else {
+        Label::Bytes(random::bytes(rng, 40))
+    };
+
+    DealProposal {
+        piece_cid: random::cid(rng),
+        piece_size: PaddedPieceSize(random::u64(rng)),
+        verified_deal: random::bool(rng),
+        client: random::address(rng),
+        provider: random::address(rng),
+        label,
+        start_epoch: random::i64(rng),
+        end_epoch: random::i64(rng),
+        storage_price_per_epoch: random::amount(rng),
+        provider_collateral: random::amount(rng),
+        client_collateral: random::amount(rng),
+    }
+}
+
+fn client_deal(rng: &mut StdRng) -> ClientDealProposal {
+    ClientDealProposal {
+        proposal: deal(rng),
+        client_signature: Signature::new_secp256k1(random::bytes(rng, 65)),
+    }
+}
+
+fn claim(rng: &mut StdRng) -> Claim {
+    Claim {
+        provider: random::u64(rng),
+        client: random::u64(rng),
+        data: random::cid(rng),
+        size: PaddedPieceSize(random::u64(rng)),
+        term_min: random::i64(rng),
+        term_max: random::i64(rng),
+        term_start: random::i64(rng),
+        sector: random::u64(rng),
+    }
+}
+
+fn claim_term(rng: &mut StdRng) -> ClaimTerm {
+    ClaimTerm {
+        provider: random::u64(rng),
+        claim_id: random::u64(rng),
+        term_max: random::i64(rng),
+    }
+}
+
+fn beneficiary_term(rng: &mut StdRng) -> BeneficiaryTerm {
+    BeneficiaryTerm {
+        quota: random::amount(rng),
+        used_quota: random::amount(rng),
+        expiration: random::i64(rng),
+    }
+}
+
+fn pending_beneficiary_change(rng: &mut StdRng) -> PendingBeneficiaryChange {
+    PendingBeneficiaryChange {
+        new_beneficiary: random::address(rng),
+        new_quota: random::amount(rng),
+        new_expiration: random::i64(rng),
+        approved_by_beneficiary: random::bool(rng),
+        approved_by_nominee: random::bool(rng),
+    }
+}
+
+fn common(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&deal(rng));
+    layouts.check(&client_deal(rng));
+    layouts.check(&pending_beneficiary_change(rng));
+    layouts.check(&beneficiary_term(rng));
+    layouts.check(&ActiveBeneficiary {
+        beneficiary: random::address(rng),
+        term: beneficiary_term(rng),
+    });
+    layouts.check(&FailCode {
+        idx: rng.gen(),
+        code: ExitCode::new(rng.gen()),
+    });
+    layouts.check(&batch(rng));
+    layouts.check(&claim(rng));
+    layouts.check(&claim_term(rng));
+}
+
+fn market(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&WithdrawBalanceParams {
+        provider_or_client: random::address(rng),
+        amount: random::amount(rng),
+    });
+    layouts.check(&WithdrawBalanceReturn {
+        amount_withdrawn: random::amount(rng),
+    });
+    layouts.check(&GetBalanceReturn {
+        balance: random::amount(rng),
+        locked: random::amount(rng),
+    });
+    layouts.check(&DealQueryParams {
+        id: random::u64(rng),
+    });
+    layouts.check(&GetDealDataCommitmentReturn {
+        data: random::cid(rng),
+        size: PaddedPieceSize(random::u64(rng)),
+    });
+    layouts.check(&GetDealClientReturn {
+        client: random::u64(rng),
+    });
+    layouts.check(&GetDealProviderReturn {
+        provider: random::u64(rng),
+    });
+    layouts.check(&GetDealLabelReturn {
+        label: deal(rng).label,
+    });
+    layouts.check(&GetDealTermReturn {
+        start: random::i64(rng),
+        duration: random::i64(rng),
+    });
+    layouts.check(&GetDealTotalPriceReturn {
+        total_price: random::amount(rng),
+    });
+    layouts.check(&GetDealClientCollateralReturn {
+        collateral: random::amount(rng),
+    });
+    layouts.check(&GetDealProviderCollateralReturn {
+        collateral: random::amount(rng),
+    });
+    layouts.check(&GetDealVerifiedReturn {
+        verified: random::bool(rng),
+    });
+    layouts.check(&GetDealActivationReturn {
+        activated: random::i64(rng),
+        terminated: random::i64(rng),
+    });
+    layouts.check(&PublishStorageDealsParams {
+        deals: (0..3).map(|_| client_deal(rng)).collect(),
+    });
+    let ids = ids(rng);
+    layouts.check(&PublishStorageDealsReturn {
+        valid_deals: BitField::try_from_bits(0..ids.len() as u64).unwrap(),
+        ids,
+    });
+}
+
+fn miner(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&GetOwnerReturn {
+        owner: random::address(rng),
+        proposed: random::bool(rng).then(|| random::address(rng)),
+    });
+    layouts.check(&IsControllingAddressParam {
+        address: random::address(rng),
+    });
+    layouts.check(&IsControllingAddressReturn {
+        is_controlling: random::bool(rng),
+    });
+    layouts.check(&GetSectorSizeReturn {
+        sector_size: SECTOR_SIZES.choose(rng).unwrap().0,
+    });
+    layouts.check(&GetAvailableBalanceReturn {
+        available_balance: random::signed_amount(rng),
+    });
+    layouts.check(&GetVestingFundsReturn {
+        vesting_funds: random::vec(rng, 4, |rng| (random::i64(rng), random::amount(rng))),
+    });
+    layouts.check(&ChangeBeneficiaryParams {
+        new_beneficiary: random::address(rng),
+        new_quota: random::amount(rng),
+        new_expiration: random::i64(rng),
+    });
+    layouts.check(&GetBeneficiaryReturn {
+        active: ActiveBeneficiary {
+            beneficiary: random::address(rng),
+            term: beneficiary_term(rng),
+        },
+        proposed: random::bool(rng).then(|| pending_beneficiary_change(rng)),
+    });
+    layouts.check(&ChangeWorkerAddressParams {
+        new_worker: random::address(rng),
+        new_control_addresses: random::vec(rng, 3, random::address),
+    });
+    layouts.check(&ChangePeerIDParams {
+        new_id: random::bytes(rng, 40),
+    });
+    layouts.check(&ChangeMultiaddrsParams {
+        new_multi_addrs: multiaddrs(rng),
+    });
+    layouts.check(&GetPeerIDReturn {
+        peer_id: random::bytes(rng, 40),
+    });
+    layouts.check(&GetMultiaddrsReturn {
+        multi_addrs: multiaddrs(rng),
+    });
+}
+
+fn power(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&CreateMinerParams {
+        owner: random::address(rng),
+        worker: random::address(rng),
+        window_post_proof_type: POST_PROOFS.choose(rng).unwrap().0,
+        peer: random::bytes(rng, 40),
+        multiaddrs: multiaddrs(rng),
+    });
+    layouts.check(&CreateMinerReturn {
+        id_address: random::address(rng),
+        robust_address: random::address(rng),
+    });
+    layouts.check(&MinerCountReturn {
+        miner_count: random::i64(rng),
+    });
+    layouts.check(&MinerConsensusCountReturn {
+        miner_consensus_count: random::i64(rng),
+    });
+    layouts.check(&NetworkRawPowerReturn {
+        raw_byte_power: random::amount(rng).atto().clone(),
+    });
+    layouts.check(&MinerRawPowerParams {
+        miner: random::u64(rng),
+    });
+    layouts.check(&MinerRawPowerReturn {
+        raw_byte_power: random::amount(rng).atto().clone(),
+        meets_consensus_minimum: random::bool(rng),
+    });
+}
+
+fn verifreg(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&GetClaimsParams {
+        provider: random::u64(rng),
+        claim_ids: ids(rng),
+    });
+    layouts.check(&GetClaimsReturn {
+        batch_info: batch(rng),
+        claims: random::vec(rng, 3, claim),
+    });
+    layouts.check(&VerifierParams {
+        address: random::address(rng),
+        allowance: random::amount(rng),
+    });
+    layouts.check(&RemoveExpiredAllocationsParams {
+        client: random::u64(rng),
+        allocation_ids: ids(rng),
+    });
+    layouts.check(&RemoveExpiredAllocationsReturn {
+        considered: ids(rng),
+        results: batch(rng),
+        datacap_recovered: random::amount(rng),
+    });
+    layouts.check(&RemoveExpiredClaimsParams {
+        provider: random::u64(rng),
+        claim_ids: ids(rng),
+    });
+    layouts.check(&RemoveExpiredClaimsReturn {
+        considered: ids(rng),
+        results: batch(rng),
+    });
+    layouts.check(&ExtendClaimTermsParams {
+        terms: random::vec(rng, 3, claim_term),
+    });
+    layouts.check(&UniversalReceiverParams {
+        type_: rng.gen(),
+        payload: raw_bytes(rng),
+    });
+    layouts.check(&AllocationsResponse {
+        allocation_results: batch(rng),
+        extension_results: batch(rng),
+        new_allocations: ids(rng),
+    });
+}
+
+fn datacap(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&MintParams {
+        to: random::address(rng),
+        amount: random::signed_amount(rng),
+        operators: random::vec(rng, 3, random::address),
+    });
+    layouts.check(&MintReturn {
+        balance: random::signed_amount(rng),
+        supply: random::signed_amount(rng),
+        recipient_data: raw_bytes(rng),
+    });
+    layouts.check(&DestroyParams {
+        owner: random::address(rng),
+        amount: random::signed_amount(rng),
+    });
+    layouts.check(&GetAllowanceParams {
+        owner: random::address(rng),
+        operator: random::address(rng),
+    });
+    layouts.check(&TransferParams {
+        to: random::address(rng),
+        amount: random::signed_amount(rng),
+        operator_data: raw_bytes(rng),
+    });
+    layouts.check(&TransferReturn {
+        from_balance: random::signed_amount(rng),
+        to_balance: random::signed_amount(rng),
+        recipient_data: raw_bytes(rng),
+    });
+    layouts.check(&TransferFromParams {
+        from: random::address(rng),
+        to: random::address(rng),
+        amount: random::signed_amount(rng),
+        operator_data: raw_bytes(rng),
+    });
+    layouts.check(&TransferFromReturn {
+        from_balance: random::signed_amount(rng),
+        to_balance: random::signed_amount(rng),
+        allowance: random::signed_amount(rng),
+        recipient_data: raw_bytes(rng),
+    });
+    layouts.check(&IncreaseAllowanceParams {
+        operator: random::address(rng),
+        increase: random::signed_amount(rng),
+    });
+    layouts.check(&DecreaseAllowanceParams {
+        operator: random::address(rng),
+        decrease: random::signed_amount(rng),
+    });
+    layouts.check(&RevokeAllowanceParams {
+        operator: random::address(rng),
+    });
+    layouts.check(&BurnParams {
+        amount: random::signed_amount(rng),
+    });
+    layouts.check(&BurnReturn {
+        balance: random::signed_amount(rng),
+    });
+    layouts.check(&BurnFromParams {
+        owner: random::address(rng),
+        amount: random::signed_amount(rng),
+    });
+    layouts.check(&BurnFromReturn {
+        balance: random::signed_amount(rng),
+        allowance: random::signed_amount(rng),
+    });
+}
+
+fn init(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&ExecParams {
+        code_cid: random::cid(rng),
+        constructor_params: raw_bytes(rng),
+    });
+    layouts.check(&ExecReturn {
+        id_address: random::address(rng),
+        robust_address: random::address(rng),
+    });
+    layouts.check(&Exec4Params {
+        code_cid: random::cid(rng),
+        constructor_params: raw_bytes(rng),
+        subaddress: raw_bytes(rng),
+    });
+    layouts.check(&Exec4Return {
+        id_address: random::address(rng),
+        robust_address: random::address(rng),
+    });
+}
+
+fn account(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&AuthenticateMessageParams {
+        signature: random::bytes(rng, 96),
+        message: random::bytes(rng, 64),
+    });
+}
+
+fn multisig(layouts: &mut Layouts, rng: &mut StdRng) {
+    layouts.check(&ProposeParams {
+        to: random::address(rng),
+        value: random::amount(rng),
+        method: random::u64(rng),
+        params: raw_bytes(rng),
+    });
+    layouts.check(&ProposeReturn {
+        txn_id: TxnID(random::i64(rng)),
+        applied: random::bool(rng),
+        code: ExitCode::new(rng.gen()),
+        ret: raw_bytes(rng),
+    });
+    layouts.check(&TxnIDParams {
+        id: TxnID(random::i64(rng)),
+        proposal_hash: random::bytes(rng, 32),
+    });
+    layouts.check(&ApproveReturn {
+        applied: random::bool(rng),
+        code: ExitCode::new(rng.gen()),
+        ret: raw_bytes(rng),
+    });
+    layouts.check(&AddSignerParams {
+        signer: random::address(rng),
+        increase: random::bool(rng),
+    });
+    layouts.check(&RemoveSignerParams {
+        signer: random::address(rng),
+        decrease: random::bool(rng),
+    });
+    layouts.check(&SwapSignerParams {
+        from: random::address(rng),
+        to: random::address(rng),
+    });
+    layouts.check(&ChangeNumApprovalsThresholdParams {
+        new_threshold: random::u64(rng),
+    });
+    layouts.check(&LockBalanceParams {
+        start_epoch: random::i64(rng),
+        unlock_duration: random::i64(rng),
+        amount: random::amount(rng),
+    });
+}
+
+/// Checks the schema structs against random values of the actor types, `rounds` times.
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking the schema structs against the actor types");
+
+    let mut layouts = Layouts {
+        schema: Schema::new(),
+        reached: BTreeSet::new(),
+        problems: BTreeMap::new(),
+    };
+
+    for _ in 0..rounds {
+        common(&mut layouts, rng);
+        market(&mut layouts, rng);
+        miner(&mut layouts, rng);
+        power(&mut layouts, rng);
+        verifreg(&mut layouts, rng);
+        datacap(&mut layouts, rng);
+        init(&mut layouts, rng);
+        account(&mut layouts, rng);
+        multisig(&mut layouts, rng);
+    }
+
+    let mut problems: Vec<String> = layouts
+        .problems
+        .iter()
+        .map(|(path, problem)| format!("{}: {}", path, problem))
+        .collect();
+    for s in &layouts.schema.structs {
+        if !layouts.reached.contains(s.name) {
+            problems.push(format!(
+                "{} ({}) isn't checked against an actor type",
+                s.name, s.rust
+            ));
+        }
+    }
+
+    for problem in &problems {
+        println!("  {}", problem);
+    }
+    println!(
+        "{} structs checked, {} mismatches",
+        layouts.reached.len(),
+        problems.len()
+    );
+
+    problems.len()
+}
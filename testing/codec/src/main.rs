@@ -0,0 +1,66 @@
+//! Round-trip conformance between the solidity CBOR codecs and the actor types.
+//!
+//! Each `*CodecTest` contract exposes the serializers and deserializers of one actor. Random
+//! values of the native types are serialized with the actor's own encoding and checked
+//! against the solidity side in both directions. The `CborDecode.sol` readers are also
+//! fuzzed with malformed input against a reference decoding, and token amounts are checked
+//! at the edges of the BigInt encoding, and the enum codes and struct layouts of
+//! `testing/schema` against the actor types. Set `SEED` to replay a run.
+
+mod amounts;
+mod conformance;
+mod datacap;
+mod decoder;
+mod init;
+mod layouts;
+mod market;
+mod miner;
+mod multisig;
+mod power;
+mod random;
+mod reference;
+mod schema;
+mod verifreg;
+
+use std::env;
+use std::process::exit;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+/// Random values checked per function.
+const ROUNDS: usize = 20;
+
+/// Inputs fed to every `CborDecode.sol` reader.
+const DECODER_ROUNDS: usize = 100;
+
+fn main() {
+    let seed = match env::var("SEED") {
+        Ok(seed) => seed.parse().expect("SEED must be a u64"),
+        Err(_) => rand::random(),
+    };
+    println!("Checking solidity CBOR codecs against the actor types (SEED={})", seed);
+
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mismatches = [
+        market::run(&mut rng, ROUNDS),
+        miner::run(&mut rng, ROUNDS),
+        power::run(&mut rng, ROUNDS),
+        verifreg::run(&mut rng, ROUNDS),
+        datacap::run(&mut rng, ROUNDS),
+        multisig::run(&mut rng, ROUNDS),
+        init::run(&mut rng, ROUNDS),
+        decoder::run(&mut rng, DECODER_ROUNDS),
+        amounts::run(),
+        schema::run(),
+        layouts::run(&mut rng, ROUNDS),
+    ]
+    .iter()
+    .sum::<usize>();
+
+    if mismatches > 0 {
+        println!("{} functions failed (SEED={})", mismatches, seed);
+        exit(1);
+    }
+}
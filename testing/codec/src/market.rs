@@ -0,0 +1,262 @@
+use ethabi::Token;
+use fil_actor_market::{
+    ClientDealProposal, DealProposal, DealQueryParams, GetBalanceReturn,
+    GetDealActivationReturn, GetDealClientCollateralReturn, GetDealClientReturn,
+    GetDealDataCommitmentReturn, GetDealLabelReturn, GetDealProviderCollateralReturn,
+    GetDealProviderReturn, GetDealTermReturn, GetDealTotalPriceReturn, GetDealVerifiedReturn,
+    Label, PublishStorageDealsParams, PublishStorageDealsReturn, WithdrawBalanceParams,
+    WithdrawBalanceReturn,
+};
+use fvm_ipld_bitfield::BitField;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::crypto::signature::Signature;
+use fvm_shared::piece::PaddedPieceSize;
+use harness::tokens::{address, amount, cid, int, uint, unsigned_amount};
+use rand::rngs::StdRng;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+pub const MARKET_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/MarketCodecTest";
+
+/// Deal getters all take a deal ID.
+const DEAL_QUERIES: [&str; 10] = [
+    "serialize_get_deal_data_commitment",
+    "serialize_get_deal_client",
+    "serialize_get_deal_provider",
+    "serialize_get_deal_label",
+    "serialize_get_deal_term",
+    "serialize_get_deal_epoch_price",
+    "serialize_get_deal_client_collateral",
+    "serialize_get_deal_provider_collateral",
+    "serialize_get_deal_verified",
+    "serialize_get_deal_activation",
+];
+
+/// Signatures are passed as their serialized form: type byte followed by the signature.
+fn signature(sig: &Signature) -> Token {
+    let BytesDe(bytes) = RawBytes::deserialize(&RawBytes::serialize(sig).unwrap()).unwrap();
+    Token::Bytes(bytes)
+}
+
+/// Bitfields are passed in their RLE+ serialized form.
+fn bitfield(bitfield: &BitField) -> Token {
+    let BytesDe(bytes) = RawBytes::deserialize(&RawBytes::serialize(bitfield).unwrap()).unwrap();
+    Token::Bytes(bytes)
+}
+
+fn deal(rng: &mut StdRng) -> (ClientDealProposal, Token) {
+    // Labels can also be bytes natively, but the solidity side only has strings
+    let label = random::string(rng, 40);
+
+    let proposal = DealProposal {
+        piece_cid: random::cid(rng),
+        piece_size: PaddedPieceSize(random::u64(rng)),
+        verified_deal: random::bool(rng),
+        client: random::address(rng),
+        provider: random::address(rng),
+        label: Label::String(label.clone()),
+        start_epoch: random::i64(rng),
+        end_epoch: random::i64(rng),
+        storage_price_per_epoch: random::amount(rng),
+        provider_collateral: random::amount(rng),
+        client_collateral: random::amount(rng),
+    };
+
+    let client_signature = Signature::new_secp256k1(random::bytes(rng, 65));
+
+    let token = Token::Tuple(vec![
+        Token::Tuple(vec![
+            cid(&proposal.piece_cid),
+            uint(proposal.piece_size.0),
+            Token::Bool(proposal.verified_deal),
+            address(&proposal.client),
+            address(&proposal.provider),
+            Token::String(label),
+            int(proposal.start_epoch),
+            int(proposal.end_epoch),
+            amount(&proposal.storage_price_per_epoch),
+            amount(&proposal.provider_collateral),
+            amount(&proposal.client_collateral),
+        ]),
+        signature(&client_signature),
+    ]);
+
+    (
+        ClientDealProposal {
+            proposal,
+            client_signature,
+        },
+        token,
+    )
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking MarketCbor.sol");
+
+    let mut conformance = Conformance::new(MARKET_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        let addr = random::address(rng);
+        conformance.serialize("serialize_address", vec![address(&addr)], &addr);
+        conformance.deserialize("deserialize_address", &addr, vec![address(&addr)]);
+
+        let params = WithdrawBalanceParams {
+            provider_or_client: random::address(rng),
+            amount: random::amount(rng),
+        };
+        conformance.serialize(
+            "serialize_withdraw_balance",
+            vec![Token::Tuple(vec![
+                address(&params.provider_or_client),
+                amount(&params.amount),
+            ])],
+            &params,
+        );
+
+        let ret = WithdrawBalanceReturn {
+            amount_withdrawn: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_withdraw_balance",
+            &ret,
+            vec![Token::Tuple(vec![unsigned_amount(&ret.amount_withdrawn)])],
+        );
+
+        let ret = GetBalanceReturn {
+            balance: random::amount(rng),
+            locked: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_balance",
+            &ret,
+            vec![Token::Tuple(vec![amount(&ret.balance), amount(&ret.locked)])],
+        );
+
+        let params = DealQueryParams {
+            id: random::u64(rng),
+        };
+        for function in DEAL_QUERIES {
+            conformance.serialize(function, vec![Token::Tuple(vec![uint(params.id)])], &params);
+        }
+
+        let ret = GetDealDataCommitmentReturn {
+            data: random::cid(rng),
+            size: PaddedPieceSize(random::u64(rng)),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_data_commitment",
+            &ret,
+            vec![Token::Tuple(vec![cid(&ret.data), uint(ret.size.0)])],
+        );
+
+        let ret = GetDealClientReturn {
+            client: random::u64(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_client",
+            &ret,
+            vec![Token::Tuple(vec![address(&Address::new_id(ret.client))])],
+        );
+
+        let ret = GetDealProviderReturn {
+            provider: random::u64(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_provider",
+            &ret,
+            vec![Token::Tuple(vec![address(&Address::new_id(ret.provider))])],
+        );
+
+        let label = random::string(rng, 40);
+        let ret = GetDealLabelReturn {
+            label: Label::String(label.clone()),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_label",
+            &ret,
+            vec![Token::Tuple(vec![Token::String(label)])],
+        );
+
+        // The solidity return calls the second field `end`, natively it is the duration
+        let ret = GetDealTermReturn {
+            start: random::i64(rng),
+            duration: random::i64(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_term",
+            &ret,
+            vec![Token::Tuple(vec![int(ret.start), int(ret.duration)])],
+        );
+
+        let ret = GetDealTotalPriceReturn {
+            total_price: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_epoch_price",
+            &ret,
+            vec![Token::Tuple(vec![unsigned_amount(&ret.total_price)])],
+        );
+
+        let ret = GetDealClientCollateralReturn {
+            collateral: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_client_collateral",
+            &ret,
+            vec![Token::Tuple(vec![unsigned_amount(&ret.collateral)])],
+        );
+
+        let ret = GetDealProviderCollateralReturn {
+            collateral: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_provider_collateral",
+            &ret,
+            vec![Token::Tuple(vec![unsigned_amount(&ret.collateral)])],
+        );
+
+        let ret = GetDealVerifiedReturn {
+            verified: random::bool(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_verified",
+            &ret,
+            vec![Token::Tuple(vec![Token::Bool(ret.verified)])],
+        );
+
+        let ret = GetDealActivationReturn {
+            activated: random::i64(rng),
+            terminated: random::i64(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_deal_activation",
+            &ret,
+            vec![Token::Tuple(vec![int(ret.activated), int(ret.terminated)])],
+        );
+
+        let (deals, tokens): (Vec<_>, Vec<_>) = (0..3).map(|_| deal(rng)).unzip();
+        conformance.serialize(
+            "serialize_publish_storage_deals",
+            vec![Token::Tuple(vec![Token::Array(tokens)])],
+            &PublishStorageDealsParams { deals },
+        );
+
+        let ids = random::vec(rng, 5, random::u64);
+        let ret = PublishStorageDealsReturn {
+            valid_deals: BitField::try_from_bits(0..ids.len() as u64).unwrap(),
+            ids: ids.clone(),
+        };
+        conformance.deserialize(
+            "deserialize_publish_storage_deals",
+            &ret,
+            vec![Token::Tuple(vec![
+                Token::Array(ids.into_iter().map(uint).collect()),
+                bitfield(&ret.valid_deals),
+            ])],
+        );
+    }
+
+    conformance.report()
+}
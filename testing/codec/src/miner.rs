@@ -0,0 +1,218 @@
+use ethabi::Token;
+use fil_actor_miner::{
+    ActiveBeneficiary, BeneficiaryTerm, ChangeBeneficiaryParams, ChangeMultiaddrsParams,
+    ChangePeerIDParams, ChangeWorkerAddressParams, GetAvailableBalanceReturn,
+    GetBeneficiaryReturn, GetMultiaddrsReturn, GetOwnerReturn, GetPeerIDReturn,
+    GetSectorSizeReturn, GetVestingFundsReturn, IsControllingAddressReturn,
+    PendingBeneficiaryChange,
+};
+use fvm_ipld_encoding::BytesDe;
+use fvm_shared::sector::SectorSize;
+use harness::tokens::{address, amount, int, uint};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+pub const MINER_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/MinerCodecTest";
+
+const SECTOR_SIZES: [SectorSize; 5] = [
+    SectorSize::_2KiB,
+    SectorSize::_8MiB,
+    SectorSize::_512MiB,
+    SectorSize::_32GiB,
+    SectorSize::_64GiB,
+];
+
+fn multiaddrs(rng: &mut StdRng) -> Vec<BytesDe> {
+    random::vec(rng, 3, |rng| BytesDe(random::bytes(rng, 20)))
+}
+
+fn multiaddrs_token(multiaddrs: &[BytesDe]) -> Token {
+    Token::Array(
+        multiaddrs
+            .iter()
+            .map(|BytesDe(bytes)| Token::Bytes(bytes.clone()))
+            .collect(),
+    )
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking MinerCbor.sol");
+
+    let mut conformance = Conformance::new(MINER_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        let addr = random::address(rng);
+        conformance.serialize("serialize_address", vec![address(&addr)], &addr);
+        conformance.deserialize("deserialize_address", &addr, vec![address(&addr)]);
+
+        let params = ChangeBeneficiaryParams {
+            new_beneficiary: random::address(rng),
+            new_quota: random::amount(rng),
+            new_expiration: random::epoch(rng),
+        };
+        conformance.serialize(
+            "serialize_change_beneficiary",
+            vec![Token::Tuple(vec![
+                address(&params.new_beneficiary),
+                amount(&params.new_quota),
+                uint(params.new_expiration as u64),
+            ])],
+            &params,
+        );
+
+        // The solidity return only has the owner, the proposed owner is dropped
+        let ret = GetOwnerReturn {
+            owner: random::address(rng),
+            proposed: Some(random::address(rng)),
+        };
+        conformance.deserialize(
+            "deserialize_get_owner",
+            &ret,
+            vec![Token::Tuple(vec![address(&ret.owner)])],
+        );
+
+        let ret = IsControllingAddressReturn {
+            is_controlling: random::bool(rng),
+        };
+        conformance.deserialize(
+            "deserialize_is_controlling_address",
+            &ret,
+            vec![Token::Tuple(vec![Token::Bool(ret.is_controlling)])],
+        );
+
+        let ret = GetSectorSizeReturn {
+            sector_size: *SECTOR_SIZES.choose(rng).unwrap(),
+        };
+        conformance.deserialize(
+            "deserialize_get_sector_size",
+            &ret,
+            vec![Token::Tuple(vec![uint(ret.sector_size as u64)])],
+        );
+
+        let ret = GetAvailableBalanceReturn {
+            available_balance: random::signed_amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_available_balance",
+            &ret,
+            vec![Token::Tuple(vec![amount(&ret.available_balance)])],
+        );
+
+        let active = ActiveBeneficiary {
+            beneficiary: random::address(rng),
+            term: BeneficiaryTerm {
+                quota: random::amount(rng),
+                used_quota: random::amount(rng),
+                expiration: random::epoch(rng),
+            },
+        };
+        let proposed = random::bool(rng).then(|| PendingBeneficiaryChange {
+            new_beneficiary: random::address(rng),
+            new_quota: random::amount(rng),
+            new_expiration: random::epoch(rng),
+            approved_by_beneficiary: random::bool(rng),
+            approved_by_nominee: random::bool(rng),
+        });
+        // A missing proposal decodes to the zero value of the struct
+        let proposed_token = match &proposed {
+            Some(change) => Token::Tuple(vec![
+                address(&change.new_beneficiary),
+                amount(&change.new_quota),
+                uint(change.new_expiration as u64),
+                Token::Bool(change.approved_by_beneficiary),
+                Token::Bool(change.approved_by_nominee),
+            ]),
+            None => Token::Tuple(vec![
+                Token::Bytes(vec![]),
+                int(0),
+                uint(0),
+                Token::Bool(false),
+                Token::Bool(false),
+            ]),
+        };
+        let expected = vec![Token::Tuple(vec![
+            Token::Tuple(vec![
+                address(&active.beneficiary),
+                Token::Tuple(vec![
+                    amount(&active.term.quota),
+                    amount(&active.term.used_quota),
+                    uint(active.term.expiration as u64),
+                ]),
+            ]),
+            proposed_token,
+        ])];
+        conformance.deserialize(
+            "deserialize_get_beneficiary",
+            &GetBeneficiaryReturn { active, proposed },
+            expected,
+        );
+
+        let ret = GetVestingFundsReturn {
+            vesting_funds: random::vec(rng, 4, |rng| (random::i64(rng), random::amount(rng))),
+        };
+        conformance.deserialize(
+            "deserialize_get_vesting_funds",
+            &ret,
+            vec![Token::Tuple(vec![Token::Array(
+                ret.vesting_funds
+                    .iter()
+                    .map(|(epoch, funds)| Token::Tuple(vec![int(*epoch), amount(funds)]))
+                    .collect(),
+            )])],
+        );
+
+        let params = ChangeWorkerAddressParams {
+            new_worker: random::address(rng),
+            new_control_addresses: random::vec(rng, 3, random::address),
+        };
+        conformance.serialize(
+            "serialize_change_worker_address",
+            vec![Token::Tuple(vec![
+                address(&params.new_worker),
+                Token::Array(params.new_control_addresses.iter().map(address).collect()),
+            ])],
+            &params,
+        );
+
+        let params = ChangePeerIDParams {
+            new_id: random::bytes(rng, 40),
+        };
+        conformance.serialize(
+            "serialize_change_peer_id",
+            vec![Token::Tuple(vec![Token::Bytes(params.new_id.clone())])],
+            &params,
+        );
+
+        let params = ChangeMultiaddrsParams {
+            new_multi_addrs: multiaddrs(rng),
+        };
+        conformance.serialize(
+            "serialize_change_multiaddrs",
+            vec![Token::Tuple(vec![multiaddrs_token(&params.new_multi_addrs)])],
+            &params,
+        );
+
+        let ret = GetPeerIDReturn {
+            peer_id: random::bytes(rng, 40),
+        };
+        conformance.deserialize(
+            "deserialize_get_peer_id",
+            &ret,
+            vec![Token::Tuple(vec![Token::Bytes(ret.peer_id.clone())])],
+        );
+
+        let ret = GetMultiaddrsReturn {
+            multi_addrs: multiaddrs(rng),
+        };
+        conformance.deserialize(
+            "deserialize_get_multiaddrs",
+            &ret,
+            vec![Token::Tuple(vec![multiaddrs_token(&ret.multi_addrs)])],
+        );
+    }
+
+    conformance.report()
+}
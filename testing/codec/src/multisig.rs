@@ -0,0 +1,158 @@
+use ethabi::Token;
+use fil_actor_multisig::{
+    AddSignerParams, ApproveReturn, ChangeNumApprovalsThresholdParams, LockBalanceParams,
+    ProposeParams, ProposeReturn, RemoveSignerParams, SwapSignerParams, TxnID, TxnIDParams,
+};
+use fvm_ipld_encoding::{BytesSer, RawBytes};
+use fvm_shared::error::ExitCode;
+use harness::tokens::{address, amount, int, uint};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+const MULTISIG_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/MultisigCodecTest";
+
+fn raw_bytes(rng: &mut StdRng) -> RawBytes {
+    RawBytes::new(random::bytes(rng, 64))
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking MultisigCbor.sol");
+
+    let mut conformance = Conformance::new(MULTISIG_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        let data = random::bytes(rng, 64);
+        conformance.serialize(
+            "serialize_bytes",
+            vec![Token::Bytes(data.clone())],
+            &BytesSer(&data),
+        );
+
+        let params = ProposeParams {
+            to: random::address(rng),
+            value: random::amount(rng),
+            method: random::u64(rng),
+            params: raw_bytes(rng),
+        };
+        conformance.serialize(
+            "serialize_propose",
+            vec![Token::Tuple(vec![
+                address(&params.to),
+                amount(&params.value),
+                uint(params.method),
+                Token::Bytes(params.params.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = ProposeReturn {
+            txn_id: TxnID(random::i64(rng)),
+            applied: random::bool(rng),
+            code: ExitCode::new(rng.gen()),
+            ret: raw_bytes(rng),
+        };
+        conformance.deserialize(
+            "deserialize_propose",
+            &ret,
+            vec![Token::Tuple(vec![
+                int(ret.txn_id.0),
+                Token::Bool(ret.applied),
+                uint(ret.code.value().into()),
+                Token::Bytes(ret.ret.to_vec()),
+            ])],
+        );
+
+        let params = TxnIDParams {
+            id: TxnID(random::i64(rng)),
+            proposal_hash: random::bytes(rng, 32),
+        };
+        conformance.serialize(
+            "serialize_txn_id",
+            vec![Token::Tuple(vec![
+                int(params.id.0),
+                Token::Bytes(params.proposal_hash.clone()),
+            ])],
+            &params,
+        );
+
+        let ret = ApproveReturn {
+            applied: random::bool(rng),
+            code: ExitCode::new(rng.gen()),
+            ret: raw_bytes(rng),
+        };
+        conformance.deserialize(
+            "deserialize_approve",
+            &ret,
+            vec![Token::Tuple(vec![
+                Token::Bool(ret.applied),
+                uint(ret.code.value().into()),
+                Token::Bytes(ret.ret.to_vec()),
+            ])],
+        );
+
+        let params = AddSignerParams {
+            signer: random::address(rng),
+            increase: random::bool(rng),
+        };
+        conformance.serialize(
+            "serialize_add_signer",
+            vec![Token::Tuple(vec![
+                address(&params.signer),
+                Token::Bool(params.increase),
+            ])],
+            &params,
+        );
+
+        let params = RemoveSignerParams {
+            signer: random::address(rng),
+            decrease: random::bool(rng),
+        };
+        conformance.serialize(
+            "serialize_remove_signer",
+            vec![Token::Tuple(vec![
+                address(&params.signer),
+                Token::Bool(params.decrease),
+            ])],
+            &params,
+        );
+
+        let params = SwapSignerParams {
+            from: random::address(rng),
+            to: random::address(rng),
+        };
+        conformance.serialize(
+            "serialize_swap_signer",
+            vec![Token::Tuple(vec![address(&params.from), address(&params.to)])],
+            &params,
+        );
+
+        let params = ChangeNumApprovalsThresholdParams {
+            new_threshold: random::u64(rng),
+        };
+        conformance.serialize(
+            "serialize_change_num_approvals_threshold",
+            vec![Token::Tuple(vec![uint(params.new_threshold)])],
+            &params,
+        );
+
+        let params = LockBalanceParams {
+            start_epoch: random::i64(rng),
+            unlock_duration: random::i64(rng),
+            amount: random::amount(rng),
+        };
+        conformance.serialize(
+            "serialize_lock_balance",
+            vec![Token::Tuple(vec![
+                int(params.start_epoch),
+                int(params.unlock_duration),
+                amount(&params.amount),
+            ])],
+            &params,
+        );
+    }
+
+    conformance.report()
+}
@@ -0,0 +1,118 @@
+use ethabi::Token;
+use fil_actor_power::{
+    CreateMinerParams, CreateMinerReturn, MinerConsensusCountReturn, MinerCountReturn,
+    MinerRawPowerParams, MinerRawPowerReturn, NetworkRawPowerReturn,
+};
+use fvm_ipld_encoding::BytesDe;
+use fvm_shared::sector::StoragePower;
+use harness::enums::POST_PROOFS;
+use harness::tokens::{address, big_int, int, uint};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+const POWER_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/PowerCodecTest";
+
+fn power(rng: &mut StdRng) -> StoragePower {
+    random::amount(rng).atto().clone()
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking PowerCbor.sol");
+
+    let mut conformance = Conformance::new(POWER_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        let (proof, ordinal) = *POST_PROOFS.choose(rng).unwrap();
+        let params = CreateMinerParams {
+            owner: random::address(rng),
+            worker: random::address(rng),
+            window_post_proof_type: proof,
+            peer: random::bytes(rng, 40),
+            multiaddrs: random::vec(rng, 3, |rng| BytesDe(random::bytes(rng, 20))),
+        };
+        conformance.serialize(
+            "serialize_create_miner",
+            vec![Token::Tuple(vec![
+                address(&params.owner),
+                address(&params.worker),
+                Token::Uint(ordinal.into()),
+                Token::Bytes(params.peer.clone()),
+                Token::Array(
+                    params
+                        .multiaddrs
+                        .iter()
+                        .map(|BytesDe(bytes)| Token::Bytes(bytes.clone()))
+                        .collect(),
+                ),
+            ])],
+            &params,
+        );
+
+        let ret = CreateMinerReturn {
+            id_address: random::address(rng),
+            robust_address: random::address(rng),
+        };
+        conformance.deserialize(
+            "deserialize_create_miner",
+            &ret,
+            vec![Token::Tuple(vec![
+                address(&ret.id_address),
+                address(&ret.robust_address),
+            ])],
+        );
+
+        let ret = MinerCountReturn {
+            miner_count: random::epoch(rng),
+        };
+        conformance.deserialize(
+            "deserialize_miner_count",
+            &ret,
+            vec![Token::Tuple(vec![uint(ret.miner_count as u64)])],
+        );
+
+        let ret = MinerConsensusCountReturn {
+            miner_consensus_count: random::i64(rng),
+        };
+        conformance.deserialize(
+            "deserialize_miner_consensus_count",
+            &ret,
+            vec![Token::Tuple(vec![int(ret.miner_consensus_count)])],
+        );
+
+        let ret = NetworkRawPowerReturn {
+            raw_byte_power: power(rng),
+        };
+        conformance.deserialize(
+            "deserialize_network_raw_power",
+            &ret,
+            vec![Token::Tuple(vec![Token::Int(big_int(&ret.raw_byte_power))])],
+        );
+
+        let params = MinerRawPowerParams {
+            miner: random::u64(rng),
+        };
+        conformance.serialize(
+            "serialize_miner_raw_power",
+            vec![Token::Tuple(vec![uint(params.miner)])],
+            &params,
+        );
+
+        let ret = MinerRawPowerReturn {
+            raw_byte_power: power(rng),
+            meets_consensus_minimum: random::bool(rng),
+        };
+        conformance.deserialize(
+            "deserialize_miner_raw_power",
+            &ret,
+            vec![Token::Tuple(vec![
+                Token::Int(big_int(&ret.raw_byte_power)),
+                Token::Bool(ret.meets_consensus_minimum),
+            ])],
+        );
+    }
+
+    conformance.report()
+}
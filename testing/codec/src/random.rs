@@ -0,0 +1,108 @@
+//! Random values for the actor types, biased towards the edges of the CBOR encoding.
+
+use cid::multihash::Multihash;
+use cid::Cid;
+use fvm_shared::address::{Address, BLS_PUB_LEN, PAYLOAD_HASH_LEN};
+use fvm_shared::bigint::{BigInt, Sign};
+use fvm_shared::econ::TokenAmount;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Values around the boundaries between CBOR header lengths.
+const U64_EDGES: [u64; 11] = [
+    0,
+    23,
+    24,
+    255,
+    256,
+    65535,
+    65536,
+    u32::MAX as u64,
+    u32::MAX as u64 + 1,
+    i64::MAX as u64,
+    u64::MAX,
+];
+
+const DAG_CBOR: u64 = 0x71;
+const SHA2_256: u64 = 0x12;
+
+pub fn u64(rng: &mut StdRng) -> u64 {
+    if rng.gen_bool(0.3) {
+        *U64_EDGES.choose(rng).unwrap()
+    } else {
+        rng.gen()
+    }
+}
+
+pub fn i64(rng: &mut StdRng) -> i64 {
+    match rng.gen_range(0..4) {
+        0 => *[0, -1, -24, -25, -256, -257, i64::MIN, i64::MAX]
+            .choose(rng)
+            .unwrap(),
+        _ => rng.gen(),
+    }
+}
+
+/// A non-negative i64, for epochs the solidity side keeps as `uint64`.
+pub fn epoch(rng: &mut StdRng) -> i64 {
+    (u64(rng) >> 1) as i64
+}
+
+pub fn bool(rng: &mut StdRng) -> bool {
+    rng.gen()
+}
+
+pub fn bytes(rng: &mut StdRng, max_len: usize) -> Vec<u8> {
+    let len = rng.gen_range(0..=max_len);
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+pub fn string(rng: &mut StdRng, max_len: usize) -> String {
+    let len = rng.gen_range(0..=max_len);
+    (0..len).map(|_| rng.gen_range('a'..='z')).collect()
+}
+
+pub fn vec<T>(rng: &mut StdRng, max_len: usize, mut item: impl FnMut(&mut StdRng) -> T) -> Vec<T> {
+    let len = rng.gen_range(0..=max_len);
+    (0..len).map(|_| item(rng)).collect()
+}
+
+/// Any of the address protocols.
+pub fn address(rng: &mut StdRng) -> Address {
+    match rng.gen_range(0..5) {
+        0 => Address::new_id(u64(rng)),
+        1 => Address::new_secp256k1(&bytes_exact(rng, 65)).unwrap(),
+        2 => Address::new_actor(&bytes(rng, 32)),
+        3 => Address::new_bls(&bytes_exact(rng, BLS_PUB_LEN)).unwrap(),
+        _ => Address::new_delegated(10, &bytes_exact(rng, PAYLOAD_HASH_LEN)).unwrap(),
+    }
+}
+
+/// A non-negative amount that fits in 255 bits, so it is representable on both sides.
+pub fn amount(rng: &mut StdRng) -> TokenAmount {
+    TokenAmount::from_atto(BigInt::from_bytes_be(Sign::Plus, &magnitude(rng)))
+}
+
+/// Like [`amount`], but possibly negative.
+pub fn signed_amount(rng: &mut StdRng) -> TokenAmount {
+    let sign = if rng.gen_bool(0.5) { Sign::Minus } else { Sign::Plus };
+    TokenAmount::from_atto(BigInt::from_bytes_be(sign, &magnitude(rng)))
+}
+
+pub fn cid(rng: &mut StdRng) -> Cid {
+    let digest = bytes_exact(rng, 32);
+    Cid::new_v1(DAG_CBOR, Multihash::wrap(SHA2_256, &digest).unwrap())
+}
+
+fn bytes_exact(rng: &mut StdRng, len: usize) -> Vec<u8> {
+    (0..len).map(|_| rng.gen()).collect()
+}
+
+fn magnitude(rng: &mut StdRng) -> Vec<u8> {
+    let mut magnitude = bytes(rng, 32);
+    if magnitude.len() == 32 {
+        magnitude[0] &= 0x7f;
+    }
+    magnitude
+}
@@ -0,0 +1,255 @@
+//! Reference decoding for the `CborDecode.sol` readers.
+//!
+//! Follows RFC 8949 with the DAG-CBOR restrictions the actors rely on: minimal headers,
+//! definite lengths only and valid UTF-8 in text strings. Each reader returns the ABI
+//! tokens the matching `CborDecodeTest` function should return, or why it should revert.
+
+use ethabi::Token;
+use fvm_shared::bigint::{BigInt, Sign};
+use harness::tokens::{big_int, int, uint};
+
+pub type Decoded = Result<Vec<Token>, String>;
+
+const MAJ_UNSIGNED_INT: u8 = 0;
+const MAJ_SIGNED_INT: u8 = 1;
+const MAJ_BYTE_STRING: u8 = 2;
+const MAJ_TEXT_STRING: u8 = 3;
+const MAJ_ARRAY: u8 = 4;
+const MAJ_TAG: u8 = 6;
+const MAJ_OTHER: u8 = 7;
+
+const TAG_BIG_NUM: u64 = 2;
+const TAG_NEGATIVE_BIG_NUM: u64 = 3;
+
+const FALSE: u64 = 20;
+const TRUE: u64 = 21;
+const NULL: u8 = 0xf6;
+
+pub struct Header {
+    pub major: u8,
+    pub arg: u64,
+    /// Index of the first byte after the header.
+    pub next: usize,
+}
+
+pub fn header(input: &[u8], idx: usize) -> Result<Header, String> {
+    let first = *input.get(idx).ok_or("index out of range")?;
+    let (major, low) = (first >> 5, first & 0x1f);
+
+    let width = match low {
+        0..=23 => {
+            return Ok(Header {
+                major,
+                arg: low.into(),
+                next: idx + 1,
+            })
+        }
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return Err(format!("additional info {} is reserved or indefinite", low)),
+    };
+
+    let bytes = input
+        .get(idx + 1..idx + 1 + width)
+        .ok_or("truncated header")?;
+    let arg = bytes.iter().fold(0u64, |acc, byte| acc << 8 | u64::from(*byte));
+
+    // Simple values below 32 only have the one byte form, and wider major 7 values are floats
+    let minimal = match (major, width) {
+        (MAJ_OTHER, 1) => 32,
+        (MAJ_OTHER, _) => 0,
+        (_, 1) => 24,
+        (_, 2) => 1 << 8,
+        (_, 4) => 1 << 16,
+        _ => 1 << 32,
+    };
+    if arg < minimal {
+        return Err(format!("{} doesn't need a {} byte header", arg, width));
+    }
+
+    Ok(Header {
+        major,
+        arg,
+        next: idx + 1 + width,
+    })
+}
+
+fn expect_major(header: &Header, major: u8) -> Result<(), String> {
+    if header.major == major {
+        Ok(())
+    } else {
+        Err(format!("expected major type {}, found {}", major, header.major))
+    }
+}
+
+fn index(next: usize) -> Token {
+    uint(next as u64)
+}
+
+/// Content of a byte or text string starting at `idx`.
+fn string(input: &[u8], idx: usize, major: u8) -> Result<(&[u8], usize), String> {
+    let header = header(input, idx)?;
+    expect_major(&header, major)?;
+
+    let end = usize::try_from(header.arg)
+        .ok()
+        .and_then(|len| header.next.checked_add(len))
+        .filter(|end| *end <= input.len())
+        .ok_or("string runs past the end of the input")?;
+
+    Ok((&input[header.next..end], end))
+}
+
+fn unsigned(input: &[u8], idx: usize, bits: u32) -> Decoded {
+    let header = header(input, idx)?;
+    expect_major(&header, MAJ_UNSIGNED_INT)?;
+
+    if bits < 64 && header.arg >> bits != 0 {
+        return Err(format!("{} doesn't fit in uint{}", header.arg, bits));
+    }
+
+    Ok(vec![uint(header.arg), index(header.next)])
+}
+
+fn signed(input: &[u8], idx: usize, bits: u32) -> Decoded {
+    let header = header(input, idx)?;
+
+    let value = match header.major {
+        MAJ_UNSIGNED_INT => i128::from(header.arg),
+        MAJ_SIGNED_INT => -1 - i128::from(header.arg),
+        major => return Err(format!("expected an integer, found major type {}", major)),
+    };
+
+    let bound = 1i128 << (bits - 1);
+    if value < -bound || value >= bound {
+        return Err(format!("{} doesn't fit in int{}", value, bits));
+    }
+
+    Ok(vec![int(value as i64), index(header.next)])
+}
+
+/// Any integer, including tagged bignums, as a `BigInt`.
+fn big(input: &[u8], idx: usize) -> Result<(BigInt, usize), String> {
+    let header = header(input, idx)?;
+
+    match header.major {
+        MAJ_UNSIGNED_INT => Ok((header.arg.into(), header.next)),
+        MAJ_SIGNED_INT => Ok((-1 - BigInt::from(header.arg), header.next)),
+        MAJ_TAG if header.arg == TAG_BIG_NUM || header.arg == TAG_NEGATIVE_BIG_NUM => {
+            let (magnitude, next) = string(input, header.next, MAJ_BYTE_STRING)?;
+            let magnitude = BigInt::from_bytes_be(Sign::Plus, magnitude);
+
+            if header.arg == TAG_BIG_NUM {
+                Ok((magnitude, next))
+            } else {
+                Ok((-1 - magnitude, next))
+            }
+        }
+        MAJ_TAG => Err(format!("unexpected tag {}", header.arg)),
+        major => Err(format!("expected an integer, found major type {}", major)),
+    }
+}
+
+pub fn is_null_next(input: &[u8], idx: usize) -> Decoded {
+    let byte = input.get(idx).ok_or("index out of range")?;
+    Ok(vec![Token::Bool(*byte == NULL)])
+}
+
+pub fn read_bool(input: &[u8], idx: usize) -> Decoded {
+    let header = header(input, idx)?;
+    expect_major(&header, MAJ_OTHER)?;
+
+    match header.arg {
+        FALSE | TRUE => Ok(vec![Token::Bool(header.arg == TRUE), index(header.next)]),
+        value => Err(format!("simple value {} isn't a bool", value)),
+    }
+}
+
+pub fn read_fixed_array(input: &[u8], idx: usize) -> Decoded {
+    let header = header(input, idx)?;
+    expect_major(&header, MAJ_ARRAY)?;
+
+    Ok(vec![uint(header.arg), index(header.next)])
+}
+
+pub fn read_string(input: &[u8], idx: usize) -> Decoded {
+    let (content, next) = string(input, idx, MAJ_TEXT_STRING)?;
+    let content = std::str::from_utf8(content).map_err(|e| e.to_string())?;
+
+    Ok(vec![Token::String(content.to_string()), index(next)])
+}
+
+pub fn read_bytes(input: &[u8], idx: usize) -> Decoded {
+    let (content, next) = string(input, idx, MAJ_BYTE_STRING)?;
+
+    Ok(vec![Token::Bytes(content.to_vec()), index(next)])
+}
+
+pub fn read_uint256(input: &[u8], idx: usize) -> Decoded {
+    let (value, next) = big(input, idx)?;
+
+    if value.sign() == Sign::Minus || value.bits() > 256 {
+        return Err(format!("{} doesn't fit in uint256", value));
+    }
+
+    Ok(vec![Token::Uint(big_int(&value)), index(next)])
+}
+
+pub fn read_int256(input: &[u8], idx: usize) -> Decoded {
+    let (value, next) = big(input, idx)?;
+
+    // Two's complement: the magnitude of the most negative value has 256 bits
+    let fits = match value.sign() {
+        Sign::Minus => (-&value - 1u8).bits() < 256,
+        _ => value.bits() < 256,
+    };
+    if !fits {
+        return Err(format!("{} doesn't fit in int256", value));
+    }
+
+    Ok(vec![Token::Int(big_int(&value)), index(next)])
+}
+
+pub fn read_uint64(input: &[u8], idx: usize) -> Decoded {
+    unsigned(input, idx, 64)
+}
+
+pub fn read_uint32(input: &[u8], idx: usize) -> Decoded {
+    unsigned(input, idx, 32)
+}
+
+pub fn read_uint16(input: &[u8], idx: usize) -> Decoded {
+    unsigned(input, idx, 16)
+}
+
+pub fn read_uint8(input: &[u8], idx: usize) -> Decoded {
+    unsigned(input, idx, 8)
+}
+
+pub fn read_int64(input: &[u8], idx: usize) -> Decoded {
+    signed(input, idx, 64)
+}
+
+pub fn read_int32(input: &[u8], idx: usize) -> Decoded {
+    signed(input, idx, 32)
+}
+
+pub fn read_int16(input: &[u8], idx: usize) -> Decoded {
+    signed(input, idx, 16)
+}
+
+pub fn read_int8(input: &[u8], idx: usize) -> Decoded {
+    signed(input, idx, 8)
+}
+
+pub fn parse_cbor_header(input: &[u8], idx: usize) -> Decoded {
+    let header = header(input, idx)?;
+
+    Ok(vec![
+        uint(header.major.into()),
+        uint(header.arg),
+        index(header.next),
+    ])
+}
@@ -0,0 +1,83 @@
+use std::fmt::Debug;
+
+use harness::enums::{POST_PROOFS, SEAL_PROOFS, SECTOR_SIZES, UPDATE_PROOFS};
+use schema::Schema;
+
+/// `native` holds the Debug name and code of every variant of the actor type.
+fn compare(schema: &Schema, name: &str, native: &[(String, u64)]) -> Vec<String> {
+    let schema_enum = schema
+        .find_enum(name)
+        .expect("enum missing from the schema");
+    let mut problems = vec![];
+
+    for (variant, code) in native {
+        match schema_enum.variants.iter().find(|v| v.name == variant) {
+            Some(v) if v.code == *code => {}
+            Some(v) => problems.push(format!(
+                "{}::{} is {} in the schema, {} in the actor types",
+                name, variant, v.code, code
+            )),
+            None => problems.push(format!("{}::{} is missing from the schema", name, variant)),
+        }
+    }
+    for variant in &schema_enum.variants {
+        if !native
+            .iter()
+            .any(|(native_name, _)| native_name == variant.name)
+        {
+            problems.push(format!("{}::{} isn't an actor type", name, variant.name));
+        }
+    }
+
+    problems
+}
+
+/// Debug names and codes of the variants of a [`harness::enums`] table.
+fn codes<T: Copy + Debug>(table: &[(T, u64)], code: impl Fn(T) -> u64) -> Vec<(String, u64)> {
+    table
+        .iter()
+        .map(|(variant, _)| (format!("{:?}", variant), code(*variant)))
+        .collect()
+}
+
+/// Checks the enum codes of the schema `script drift` compares the Solidity types with,
+/// so a drift report is about the actor types themselves.
+pub fn run() -> usize {
+    println!("Checking the schema enums against the actor types");
+
+    let schema = Schema::new();
+    let enums = [
+        compare(
+            &schema,
+            "RegisteredSealProof",
+            &codes(&SEAL_PROOFS, |proof| i64::from(proof) as u64),
+        ),
+        compare(
+            &schema,
+            "RegisteredPoStProof",
+            &codes(&POST_PROOFS, |proof| i64::from(proof) as u64),
+        ),
+        compare(
+            &schema,
+            "RegisteredUpdateProof",
+            &codes(&UPDATE_PROOFS, |proof| i64::from(proof) as u64),
+        ),
+        compare(
+            &schema,
+            "SectorSize",
+            &codes(&SECTOR_SIZES, |size| size as u64),
+        ),
+    ];
+    let problems = enums.concat();
+
+    for problem in &problems {
+        println!("  {}", problem);
+    }
+    println!(
+        "{} enums checked, {} mismatches",
+        enums.len(),
+        problems.len()
+    );
+
+    problems.len()
+}
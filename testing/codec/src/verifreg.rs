@@ -0,0 +1,233 @@
+use ethabi::Token;
+use fil_actor_verifreg::{
+    AllocationsResponse, Claim, ClaimTerm, ExtendClaimTermsParams, GetClaimsParams,
+    GetClaimsReturn, RemoveExpiredAllocationsParams, RemoveExpiredAllocationsReturn,
+    RemoveExpiredClaimsParams, RemoveExpiredClaimsReturn, VerifierParams,
+};
+use fil_actors_runtime::{BatchReturn, FailCode};
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::error::ExitCode;
+use fvm_shared::piece::PaddedPieceSize;
+use harness::tokens::{address, amount, amount_bytes, cid, int, uint};
+use rand::rngs::StdRng;
+use rand::Rng;
+
+use crate::conformance::Conformance;
+use crate::random;
+
+const VERIFREG_CODEC_ARTIFACT: &str = "../../build/v0.8/tests/VerifRegCodecTest";
+
+fn ids(rng: &mut StdRng) -> Vec<u64> {
+    random::vec(rng, 5, random::u64)
+}
+
+fn ids_token(ids: &[u64]) -> Token {
+    Token::Array(ids.iter().copied().map(uint).collect())
+}
+
+fn batch(rng: &mut StdRng) -> BatchReturn {
+    BatchReturn {
+        success_count: rng.gen(),
+        fail_codes: random::vec(rng, 3, |rng| FailCode {
+            idx: rng.gen(),
+            code: ExitCode::new(rng.gen()),
+        }),
+    }
+}
+
+fn batch_token(batch: &BatchReturn) -> Token {
+    Token::Tuple(vec![
+        uint(batch.success_count.into()),
+        Token::Array(
+            batch
+                .fail_codes
+                .iter()
+                .map(|fail| {
+                    Token::Tuple(vec![uint(fail.idx.into()), uint(fail.code.value().into())])
+                })
+                .collect(),
+        ),
+    ])
+}
+
+fn claim(rng: &mut StdRng) -> (Claim, Token) {
+    let claim = Claim {
+        provider: random::u64(rng),
+        client: random::u64(rng),
+        data: random::cid(rng),
+        size: PaddedPieceSize(random::u64(rng)),
+        term_min: random::i64(rng),
+        term_max: random::i64(rng),
+        term_start: random::i64(rng),
+        sector: random::u64(rng),
+    };
+
+    let token = Token::Tuple(vec![
+        uint(claim.provider),
+        uint(claim.client),
+        cid(&claim.data),
+        uint(claim.size.0),
+        int(claim.term_min),
+        int(claim.term_max),
+        int(claim.term_start),
+        uint(claim.sector),
+    ]);
+
+    (claim, token)
+}
+
+pub fn run(rng: &mut StdRng, rounds: usize) -> usize {
+    println!("Checking VerifRegCbor.sol");
+
+    let mut conformance = Conformance::new(VERIFREG_CODEC_ARTIFACT);
+
+    for _ in 0..rounds {
+        let params = GetClaimsParams {
+            provider: random::u64(rng),
+            claim_ids: ids(rng),
+        };
+        conformance.serialize(
+            "serialize_get_claims",
+            vec![Token::Tuple(vec![
+                uint(params.provider),
+                ids_token(&params.claim_ids),
+            ])],
+            &params,
+        );
+
+        let batch_info = batch(rng);
+        let (claims, tokens): (Vec<_>, Vec<_>) = (0..3).map(|_| claim(rng)).unzip();
+        let expected = vec![Token::Tuple(vec![batch_token(&batch_info), Token::Array(tokens)])];
+        conformance.deserialize(
+            "deserialize_get_claims",
+            &GetClaimsReturn { batch_info, claims },
+            expected,
+        );
+
+        let params = VerifierParams {
+            address: random::address(rng),
+            allowance: random::amount(rng),
+        };
+        conformance.serialize(
+            "serialize_add_verified_client",
+            vec![Token::Tuple(vec![
+                address(&params.address),
+                amount_bytes(&params.allowance),
+            ])],
+            &params,
+        );
+
+        let params = RemoveExpiredAllocationsParams {
+            client: random::u64(rng),
+            allocation_ids: ids(rng),
+        };
+        conformance.serialize(
+            "serialize_remove_expired_allocations",
+            vec![Token::Tuple(vec![
+                uint(params.client),
+                ids_token(&params.allocation_ids),
+            ])],
+            &params,
+        );
+
+        let ret = RemoveExpiredAllocationsReturn {
+            considered: ids(rng),
+            results: batch(rng),
+            datacap_recovered: random::amount(rng),
+        };
+        conformance.deserialize(
+            "deserialize_remove_expired_allocations",
+            &ret,
+            vec![Token::Tuple(vec![
+                ids_token(&ret.considered),
+                batch_token(&ret.results),
+                amount(&ret.datacap_recovered),
+            ])],
+        );
+
+        let params = ExtendClaimTermsParams {
+            terms: random::vec(rng, 3, |rng| ClaimTerm {
+                provider: random::u64(rng),
+                claim_id: random::u64(rng),
+                term_max: random::i64(rng),
+            }),
+        };
+        conformance.serialize(
+            "serialize_extend_claim_terms",
+            vec![Token::Tuple(vec![Token::Array(
+                params
+                    .terms
+                    .iter()
+                    .map(|term| {
+                        Token::Tuple(vec![
+                            uint(term.provider),
+                            uint(term.claim_id),
+                            int(term.term_max),
+                        ])
+                    })
+                    .collect(),
+            )])],
+            &params,
+        );
+
+        let ret = batch(rng);
+        conformance.deserialize("deserialize_extend_claim_terms", &ret, vec![batch_token(&ret)]);
+
+        let params = RemoveExpiredClaimsParams {
+            provider: random::u64(rng),
+            claim_ids: ids(rng),
+        };
+        conformance.serialize(
+            "serialize_remove_expired_claims",
+            vec![Token::Tuple(vec![
+                uint(params.provider),
+                ids_token(&params.claim_ids),
+            ])],
+            &params,
+        );
+
+        let ret = RemoveExpiredClaimsReturn {
+            considered: ids(rng),
+            results: batch(rng),
+        };
+        conformance.deserialize(
+            "deserialize_remove_expired_claims",
+            &ret,
+            vec![Token::Tuple(vec![
+                ids_token(&ret.considered),
+                batch_token(&ret.results),
+            ])],
+        );
+
+        let params = UniversalReceiverParams {
+            type_: rng.gen(),
+            payload: RawBytes::new(random::bytes(rng, 64)),
+        };
+        conformance.serialize(
+            "serialize_universal_receiver_hook",
+            vec![Token::Tuple(vec![
+                uint(params.type_.into()),
+                Token::Bytes(params.payload.to_vec()),
+            ])],
+            &params,
+        );
+
+        let ret = AllocationsResponse {
+            allocation_results: batch(rng),
+            extension_results: batch(rng),
+            new_allocations: ids(rng),
+        };
+        conformance.deserialize(
+            "deserialize_universal_receiver_hook",
+            &ret,
+            vec![Token::Tuple(vec![
+                batch_token(&ret.allocation_results),
+                batch_token(&ret.extension_results),
+                ids_token(&ret.new_allocations),
+            ])],
+        );
+    }
+
+    conformance.report()
+}
@@ -0,0 +1,65 @@
+use ethabi::Token;
+use fil_actor_account::types::AuthenticateMessageParams;
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::address;
+
+use crate::deploy;
+
+const ACCOUNT_API_ARTIFACT: &str = "../../build/v0.8/AccountAPI";
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing AccountAPI");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy(ACCOUNT_API_ARTIFACT);
+        (harness, contract, ())
+    });
+
+    // The wrappers only accept ID addresses as target
+    let target = Address::new_id(differential.api.accounts[0].0);
+
+    // A bogus signature, expected to be rejected on both sides
+    let signature = vec![1; 65];
+    let message = vec![2, 3, 4];
+    differential.run(
+        Case::new(
+            "authenticate_message",
+            vec![
+                address(&target),
+                Token::Tuple(vec![
+                    Token::Bytes(signature.clone()),
+                    Token::Bytes(message.clone()),
+                ]),
+            ],
+        )
+        .native(
+            target,
+            method_nums::account::AUTHENTICATE_MESSAGE,
+            RawBytes::serialize(AuthenticateMessageParams { signature, message }).unwrap(),
+        ),
+    );
+
+    // The wrapper forwards already serialized params
+    let params = RawBytes::serialize(UniversalReceiverParams {
+        type_: 0,
+        payload: RawBytes::default(),
+    })
+    .unwrap();
+    differential.run(
+        Case::new(
+            "universal_receiver_hook",
+            vec![address(&target), Token::Bytes(params.to_vec())],
+        )
+        .native(target, method_nums::account::RECEIVE, params),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
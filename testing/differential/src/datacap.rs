@@ -0,0 +1,208 @@
+use ethabi::Token;
+use fil_actor_datacap::MintParams;
+use fil_actors_runtime::{DATACAP_TOKEN_ACTOR_ADDR, VERIFIED_REGISTRY_ACTOR_ADDR};
+use frc46_token::token::types::{
+    BurnFromParams, BurnParams, DecreaseAllowanceParams, GetAllowanceParams,
+    IncreaseAllowanceParams, RevokeAllowanceParams, TransferFromParams, TransferParams,
+};
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::econ::TokenAmount;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::{address, amount};
+
+use crate::deploy;
+
+const DATACAP_API_ARTIFACT: &str = "../../build/v0.8/DataCapAPI";
+
+const MINT_METHOD_NUM: u64 = 2;
+
+/// Wrappers without parameters.
+const GETTERS: [(&str, u64); 3] = [
+    ("name", method_nums::datacap::NAME),
+    ("symbol", method_nums::datacap::SYMBOL),
+    ("total_supply", method_nums::datacap::TOTAL_SUPPLY),
+];
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing DataCapAPI");
+
+    // The contract holds some datacap so that transfers and burns can succeed
+    let (mut differential, ()) = Differential::new(|| {
+        let (mut harness, contract) = deploy(DATACAP_API_ARTIFACT);
+
+        let params = MintParams {
+            to: contract.address,
+            amount: TokenAmount::from_whole(10),
+            operators: vec![],
+        };
+
+        let res = harness.send_implicit(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            DATACAP_TOKEN_ACTOR_ADDR,
+            MINT_METHOD_NUM,
+            RawBytes::serialize(params).unwrap(),
+            TokenAmount::default(),
+        );
+        assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+        (harness, contract, ())
+    });
+
+    let sender = differential.sender();
+    let contract = differential.contract.address;
+
+    for (function, method) in GETTERS {
+        differential.run(Case::new(function, vec![]).native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method,
+            RawBytes::default(),
+        ));
+    }
+
+    differential.run(Case::new("balance", vec![address(&contract)]).native(
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::BALANCE,
+        RawBytes::serialize(contract).unwrap(),
+    ));
+
+    let one = TokenAmount::from_whole(1);
+
+    differential.run(
+        Case::new(
+            "increase_allowance",
+            vec![Token::Tuple(vec![address(&sender), amount(&one)])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::INCREASE_ALLOWANCE,
+            RawBytes::serialize(IncreaseAllowanceParams {
+                operator: sender,
+                increase: one.clone(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "allowance",
+            vec![Token::Tuple(vec![address(&contract), address(&sender)])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::ALLOWANCE,
+            RawBytes::serialize(GetAllowanceParams {
+                owner: contract,
+                operator: sender,
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "decrease_allowance",
+            vec![Token::Tuple(vec![address(&sender), amount(&one)])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::DECREASE_ALLOWANCE,
+            RawBytes::serialize(DecreaseAllowanceParams {
+                operator: sender,
+                decrease: one.clone(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "revoke_allowance",
+            vec![Token::Tuple(vec![address(&sender)])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::REVOKE_ALLOWANCE,
+            RawBytes::serialize(RevokeAllowanceParams { operator: sender }).unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "transfer",
+            vec![Token::Tuple(vec![
+                address(&sender),
+                amount(&one),
+                Token::Bytes(vec![]),
+            ])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::TRANSFER,
+            RawBytes::serialize(TransferParams {
+                to: sender,
+                amount: one.clone(),
+                operator_data: RawBytes::default(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    // The contract has no allowance from the sender, so both sides are expected to fail
+    differential.run(
+        Case::new(
+            "transfer_from",
+            vec![Token::Tuple(vec![
+                address(&sender),
+                address(&contract),
+                amount(&one),
+                Token::Bytes(vec![]),
+            ])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::TRANSFER_FROM,
+            RawBytes::serialize(TransferFromParams {
+                from: sender,
+                to: contract,
+                amount: one.clone(),
+                operator_data: RawBytes::default(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new("burn", vec![Token::Tuple(vec![amount(&one)])]).native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::BURN,
+            RawBytes::serialize(BurnParams {
+                amount: one.clone(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "burn_from",
+            vec![Token::Tuple(vec![address(&sender), amount(&one)])],
+        )
+        .native(
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::BURN_FROM,
+            RawBytes::serialize(BurnFromParams {
+                owner: sender,
+                amount: one,
+            })
+            .unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
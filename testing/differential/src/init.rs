@@ -0,0 +1,73 @@
+use ethabi::Token;
+use fil_actor_init::{Exec4Params, ExecParams};
+use fil_actors_runtime::INIT_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+
+use crate::{deploy, multisig::constructor_params};
+
+const INIT_API_ARTIFACT: &str = "../../build/v0.8/InitAPI";
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing InitAPI");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy(INIT_API_ARTIFACT);
+        (harness, contract, ())
+    });
+
+    let sender = differential.sender();
+    let code_cid = differential.api.code_cid("multisig");
+    let constructor = RawBytes::serialize(constructor_params(vec![sender])).unwrap();
+
+    // The robust address depends on the origin of the message, which differs
+    differential.run(
+        Case::new(
+            "exec",
+            vec![Token::Tuple(vec![
+                Token::Bytes(code_cid.to_bytes()),
+                Token::Bytes(constructor.to_vec()),
+            ])],
+        )
+        .native(
+            INIT_ACTOR_ADDR,
+            method_nums::init::EXEC,
+            RawBytes::serialize(ExecParams {
+                code_cid,
+                constructor_params: constructor.clone(),
+            })
+            .unwrap(),
+        )
+        .ignore(1),
+    );
+
+    // Exec4 is restricted to the EAM, so both sides are expected to be rejected
+    let subaddress = vec![1, 2, 3];
+    differential.run(
+        Case::new(
+            "exec4",
+            vec![Token::Tuple(vec![
+                Token::Bytes(code_cid.to_bytes()),
+                Token::Bytes(constructor.to_vec()),
+                Token::Bytes(subaddress.clone()),
+            ])],
+        )
+        .native(
+            INIT_ACTOR_ADDR,
+            method_nums::init::EXEC4,
+            RawBytes::serialize(Exec4Params {
+                code_cid,
+                constructor_params: constructor,
+                subaddress: RawBytes::new(subaddress),
+            })
+            .unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
@@ -0,0 +1,151 @@
+//! Differential runner for the solidity API contracts.
+//!
+//! Every wrapper is called through its API contract and, on an identical copy of the
+//! state, directly on the native actor with the exported method number (computed as in
+//! `script/src/main.rs`). Both results are decoded and any difference is reported.
+//!
+//! The gas used by every API call is checked against `gas-snapshot.csv`, see
+//! [`harness::gas`]. A method whose gas changed by more than `GAS_THRESHOLD` percent fails
+//! the run, and so does a missing snapshot; a snapshot with no rows yet only lists the
+//! methods. `UPDATE_GAS_SNAPSHOT=1` writes the new figures instead.
+//!
+//! Every comparison ends up in a JUnit XML and a JSON report under `reports/`, see
+//! [`harness::report`].
+
+mod account;
+mod datacap;
+mod init;
+mod market;
+mod miner;
+mod multisig;
+mod power;
+mod verifreg;
+
+use std::env;
+use std::path::Path;
+use std::process::exit;
+
+use fvm_shared::address::Address;
+use fvm_shared::ActorID;
+use harness::gas::{GasReport, Snapshot};
+use harness::report::{report_dir, Report};
+use harness::{Contract, Harness};
+
+const GAS_SNAPSHOT: &str = "gas-snapshot.csv";
+
+/// Percentage by which a method's gas may change, unless `GAS_THRESHOLD` says otherwise.
+const DEFAULT_GAS_THRESHOLD: f64 = 2.0;
+
+/// Deploys the API contract at `artifact` in a fresh world with a single account.
+fn deploy(artifact: &str) -> (Harness, Contract) {
+    deploy_with(artifact, |_| ())
+}
+
+/// Like [`deploy`], with `genesis` changing the state before the machine is instantiated.
+fn deploy_with<F: Fn(&mut Harness)>(artifact: &str, genesis: F) -> (Harness, Contract) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    genesis(&mut harness);
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, artifact);
+
+    (harness, contract)
+}
+
+/// Prints the gas table and returns the number of methods whose gas changed past the
+/// threshold, or 1 without a snapshot to compare with. A snapshot with no rows only reports
+/// the methods as new. When asked to, the current figures are written instead.
+fn check_gas(report: &GasReport) -> usize {
+    println!("Gas used by the API contracts");
+    print!("{}", report.table());
+
+    let path = Path::new(GAS_SNAPSHOT);
+    let current = report.snapshot();
+    let threshold: f64 = env::var("GAS_THRESHOLD")
+        .map(|threshold| {
+            threshold
+                .parse()
+                .expect("GAS_THRESHOLD must be a percentage")
+        })
+        .unwrap_or(DEFAULT_GAS_THRESHOLD);
+
+    if env::var("UPDATE_GAS_SNAPSHOT").is_ok() {
+        current.save(path);
+        println!("Wrote {}", GAS_SNAPSHOT);
+        return 0;
+    }
+
+    match Snapshot::load(path) {
+        // A snapshot without figures yet, e.g. before anyone ran the suite against the
+        // bundle: every method is new, so report them without failing
+        Some(snapshot) if snapshot.0.is_empty() => {
+            for change in snapshot.changes(&current, threshold) {
+                println!("  GAS NEW {}", change);
+            }
+            println!(
+                "{} has no figures yet, UPDATE_GAS_SNAPSHOT=1 records them",
+                GAS_SNAPSHOT
+            );
+            0
+        }
+        Some(snapshot) => {
+            let changes = snapshot.changes(&current, threshold);
+            for change in &changes {
+                println!("  GAS CHANGE {}", change);
+            }
+            if !changes.is_empty() {
+                println!(
+                    "{} methods changed by more than {}%, UPDATE_GAS_SNAPSHOT=1 accepts them",
+                    changes.len(),
+                    threshold
+                );
+            }
+            changes.len()
+        }
+        None => {
+            println!(
+                "No {} to compare with, UPDATE_GAS_SNAPSHOT=1 writes one",
+                GAS_SNAPSHOT
+            );
+            1
+        }
+    }
+}
+
+fn main() {
+    println!("Comparing solidity API calls against native actor calls");
+
+    let mut gas = GasReport::default();
+    let mut report = Report::new("differential");
+
+    let differences = [
+        market::run(&mut gas, &mut report),
+        miner::run(&mut gas, &mut report),
+        power::run(&mut gas, &mut report),
+        verifreg::run(&mut gas, &mut report),
+        datacap::run(&mut gas, &mut report),
+        init::run(&mut gas, &mut report),
+        account::run(&mut gas, &mut report),
+        multisig::run(&mut gas, &mut report),
+    ]
+    .iter()
+    .sum::<usize>();
+
+    let gas_changes = check_gas(&gas);
+
+    let dir = report_dir();
+    report.write(&dir);
+    println!("Wrote reports to {}", dir.display());
+
+    if differences > 0 {
+        println!("{} differences found", differences);
+    }
+    if differences > 0 || gas_changes > 0 {
+        exit(1);
+    }
+}
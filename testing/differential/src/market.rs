@@ -0,0 +1,123 @@
+use ethabi::Token;
+use fil_actor_market::{DealQueryParams, PublishStorageDealsParams, WithdrawBalanceParams};
+use fil_actors_runtime::STORAGE_MARKET_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::econ::TokenAmount;
+use harness::deals::{deal_proposal, insert_deals};
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::{address, amount, uint};
+
+use crate::deploy_with;
+
+const MARKET_API_ARTIFACT: &str = "../../build/v0.8/MarketAPI";
+
+/// Deal getters all take a deal ID and differ only in what they return.
+const DEAL_GETTERS: [(&str, u64); 10] = [
+    (
+        "get_deal_data_commitment",
+        method_nums::market::GET_DEAL_DATA_COMMITMENT,
+    ),
+    ("get_deal_client", method_nums::market::GET_DEAL_CLIENT),
+    ("get_deal_provider", method_nums::market::GET_DEAL_PROVIDER),
+    ("get_deal_label", method_nums::market::GET_DEAL_LABEL),
+    ("get_deal_term", method_nums::market::GET_DEAL_TERM),
+    (
+        "get_deal_total_price",
+        method_nums::market::GET_DEAL_TOTAL_PRICE,
+    ),
+    (
+        "get_deal_client_collateral",
+        method_nums::market::GET_DEAL_CLIENT_COLLATERAL,
+    ),
+    (
+        "get_deal_provider_collateral",
+        method_nums::market::GET_DEAL_PROVIDER_COLLATERAL,
+    ),
+    ("get_deal_verified", method_nums::market::GET_DEAL_VERIFIED),
+    (
+        "get_deal_activation",
+        method_nums::market::GET_DEAL_ACTIVATION,
+    ),
+];
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing MarketAPI");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy_with(MARKET_API_ARTIFACT, |harness| {
+            let client = harness.accounts[0].1;
+            insert_deals(harness, vec![deal_proposal(client, "deal".to_string())]);
+        });
+        (harness, contract, ())
+    });
+
+    let contract = differential.contract.address;
+
+    differential.run(
+        Case::new("add_balance", vec![address(&contract)])
+            .native(
+                STORAGE_MARKET_ACTOR_ADDR,
+                method_nums::market::ADD_BALANCE,
+                RawBytes::serialize(contract).unwrap(),
+            )
+            .value(TokenAmount::from_atto(1000)),
+    );
+
+    let withdrawn = TokenAmount::from_atto(100);
+    differential.run(
+        Case::new(
+            "withdraw_balance",
+            vec![Token::Tuple(vec![address(&contract), amount(&withdrawn)])],
+        )
+        .native(
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::WITHDRAW_BALANCE,
+            RawBytes::serialize(WithdrawBalanceParams {
+                provider_or_client: contract,
+                amount: withdrawn,
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new("get_balance", vec![address(&contract)]).native(
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::GET_BALANCE,
+            RawBytes::serialize(contract).unwrap(),
+        ),
+    );
+
+    // Deal 0 was inserted at genesis; deal 1 doesn't exist, so it is expected to fail the
+    // same way on both sides
+    for id in [0, 1] {
+        for (function, method) in DEAL_GETTERS {
+            differential.run(
+                Case::new(function, vec![Token::Tuple(vec![uint(id)])]).native(
+                    STORAGE_MARKET_ACTOR_ADDR,
+                    method,
+                    RawBytes::serialize(DealQueryParams { id }).unwrap(),
+                ),
+            );
+        }
+    }
+
+    differential.run(
+        Case::new(
+            "publish_storage_deals",
+            vec![Token::Tuple(vec![Token::Array(vec![])])],
+        )
+        .native(
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::PUBLISH_STORAGE_DEALS,
+            RawBytes::serialize(PublishStorageDealsParams { deals: vec![] }).unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
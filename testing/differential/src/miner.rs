@@ -0,0 +1,171 @@
+use ethabi::Token;
+use fil_actor_miner::{
+    ChangeBeneficiaryParams, ChangeMultiaddrsParams, ChangePeerIDParams,
+    ChangeWorkerAddressParams,
+};
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::econ::TokenAmount;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::{address, amount, uint};
+
+use crate::deploy;
+
+const MINER_API_ARTIFACT: &str = "../../build/v0.8/MinerAPI";
+
+/// Wrappers that only take the miner address.
+const GETTERS: [(&str, u64); 7] = [
+    ("get_owner", method_nums::miner::GET_OWNER),
+    ("get_sector_size", method_nums::miner::GET_SECTOR_SIZE),
+    (
+        "get_available_balance",
+        method_nums::miner::GET_AVAILABLE_BALANCE,
+    ),
+    ("get_vesting_funds", method_nums::miner::GET_VESTING_FUNDS),
+    ("get_beneficiary", method_nums::miner::GET_BENEFICIARY),
+    ("get_peer_id", method_nums::miner::GET_PEER_ID),
+    ("get_multiaddresses", method_nums::miner::GET_MULTIADDRS),
+];
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing MinerAPI");
+
+    // The contract owns the miner so that it is allowed to call the mutating methods
+    let (mut differential, (miner, worker)) = Differential::new(|| {
+        let (mut harness, contract) = deploy(MINER_API_ARTIFACT);
+        let sender = harness.accounts[0].1;
+
+        let (miner, worker) = harness.create_miner(sender, contract.address);
+
+        (harness, contract, (miner, worker))
+    });
+
+    let sender = differential.sender();
+
+    for (function, method) in GETTERS {
+        differential.run(Case::new(function, vec![address(&miner)]).native(
+            miner,
+            method,
+            RawBytes::default(),
+        ));
+    }
+
+    differential.run(
+        Case::new(
+            "is_controlling_address",
+            vec![address(&miner), Token::Tuple(vec![address(&worker)])],
+        )
+        .native(
+            miner,
+            method_nums::miner::IS_CONTROLLING_ADDRESS,
+            RawBytes::serialize(worker).unwrap(),
+        ),
+    );
+
+    let new_id = vec![4, 5, 6];
+    differential.run(
+        Case::new(
+            "change_peer_id",
+            vec![address(&miner), Token::Tuple(vec![Token::Bytes(new_id.clone())])],
+        )
+        .native(
+            miner,
+            method_nums::miner::CHANGE_PEER_ID,
+            RawBytes::serialize(ChangePeerIDParams { new_id }).unwrap(),
+        ),
+    );
+
+    let new_multiaddr = vec![4, 5, 6];
+    differential.run(
+        Case::new(
+            "change_multiaddresses",
+            vec![
+                address(&miner),
+                Token::Tuple(vec![Token::Array(vec![Token::Bytes(
+                    new_multiaddr.clone(),
+                )])]),
+            ],
+        )
+        .native(
+            miner,
+            method_nums::miner::CHANGE_MULTIADDRS,
+            RawBytes::serialize(ChangeMultiaddrsParams {
+                new_multi_addrs: vec![BytesDe(new_multiaddr)],
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "change_worker_address",
+            vec![
+                address(&miner),
+                Token::Tuple(vec![address(&worker), Token::Array(vec![])]),
+            ],
+        )
+        .native(
+            miner,
+            method_nums::miner::CHANGE_WORKER_ADDRESS,
+            RawBytes::serialize(ChangeWorkerAddressParams {
+                new_worker: worker,
+                new_control_addresses: vec![],
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new("confirm_change_worker_address", vec![address(&miner)]).native(
+            miner,
+            method_nums::miner::CONFIRM_CHANGE_WORKER_ADDRESS,
+            RawBytes::default(),
+        ),
+    );
+
+    differential.run(Case::new("repay_debt", vec![address(&miner)]).native(
+        miner,
+        method_nums::miner::REPAY_DEBT,
+        RawBytes::default(),
+    ));
+
+    let quota = TokenAmount::from_atto(100);
+    differential.run(
+        Case::new(
+            "change_beneficiary",
+            vec![
+                address(&miner),
+                Token::Tuple(vec![address(&sender), amount(&quota), uint(1000)]),
+            ],
+        )
+        .native(
+            miner,
+            method_nums::miner::CHANGE_BENEFICIARY,
+            RawBytes::serialize(ChangeBeneficiaryParams {
+                new_beneficiary: sender,
+                new_quota: quota,
+                new_expiration: 1000,
+            })
+            .unwrap(),
+        ),
+    );
+
+    // Last, as it hands the miner over to the sender
+    differential.run(
+        Case::new(
+            "change_owner_address",
+            vec![address(&miner), address(&sender)],
+        )
+        .native(
+            miner,
+            method_nums::miner::CHANGE_OWNER_ADDRESS,
+            RawBytes::serialize(sender).unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
@@ -0,0 +1,227 @@
+use ethabi::Token;
+use fil_actor_init::{ExecParams, ExecReturn};
+use fil_actor_multisig::{
+    AddSignerParams, ChangeNumApprovalsThresholdParams, ConstructorParams, LockBalanceParams,
+    ProposeParams, RemoveSignerParams, SwapSignerParams, TxnID, TxnIDParams,
+};
+use fil_actors_runtime::INIT_ACTOR_ADDR;
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::{address, amount, int, uint};
+
+use crate::deploy;
+
+const MULTISIG_API_ARTIFACT: &str = "../../build/v0.8/MultisigAPI";
+
+/// A multisig where any of `signers` can apply a proposal on its own.
+pub fn constructor_params(signers: Vec<Address>) -> ConstructorParams {
+    ConstructorParams {
+        signers,
+        num_approvals_threshold: 1,
+        unlock_duration: 0,
+        start_epoch: 0,
+    }
+}
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing MultisigAPI");
+
+    let (mut differential, msig) = Differential::new(|| {
+        let (mut harness, contract) = deploy(MULTISIG_API_ARTIFACT);
+        let sender = harness.accounts[0].1;
+
+        let params = ExecParams {
+            code_cid: harness.code_cid("multisig"),
+            constructor_params: RawBytes::serialize(constructor_params(vec![
+                sender,
+                contract.address,
+            ]))
+            .unwrap(),
+        };
+
+        let res = harness.send(
+            sender,
+            INIT_ACTOR_ADDR,
+            method_nums::init::EXEC,
+            RawBytes::serialize(params).unwrap(),
+            TokenAmount::default(),
+        );
+        assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+        let ret: ExecReturn = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+
+        (harness, contract, ret.id_address)
+    });
+
+    let sender = differential.sender();
+
+    // Applied right away, as the threshold is one
+    differential.run(
+        Case::new(
+            "propose",
+            vec![
+                address(&msig),
+                Token::Tuple(vec![
+                    address(&sender),
+                    amount(&TokenAmount::default()),
+                    uint(0),
+                    Token::Bytes(vec![]),
+                ]),
+            ],
+        )
+        .native(
+            msig,
+            method_nums::multisig::PROPOSE,
+            RawBytes::serialize(ProposeParams {
+                to: sender,
+                value: TokenAmount::default(),
+                method: 0,
+                params: RawBytes::default(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    // The transaction was already applied, so both sides are expected to fail
+    for (function, method) in [
+        ("approve", method_nums::multisig::APPROVE),
+        ("cancel", method_nums::multisig::CANCEL),
+    ] {
+        differential.run(
+            Case::new(
+                function,
+                vec![
+                    address(&msig),
+                    Token::Tuple(vec![int(0), Token::Bytes(vec![])]),
+                ],
+            )
+            .native(
+                msig,
+                method,
+                RawBytes::serialize(TxnIDParams {
+                    id: TxnID(0),
+                    proposal_hash: vec![],
+                })
+                .unwrap(),
+            ),
+        );
+    }
+
+    // The following methods can only be called by the multisig itself (i.e. through a
+    // proposal), so both sides are expected to be rejected
+    differential.run(
+        Case::new(
+            "add_signer",
+            vec![
+                address(&msig),
+                Token::Tuple(vec![address(&sender), Token::Bool(false)]),
+            ],
+        )
+        .native(
+            msig,
+            method_nums::multisig::ADD_SIGNER,
+            RawBytes::serialize(AddSignerParams {
+                signer: sender,
+                increase: false,
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "remove_signer",
+            vec![
+                address(&msig),
+                Token::Tuple(vec![address(&sender), Token::Bool(false)]),
+            ],
+        )
+        .native(
+            msig,
+            method_nums::multisig::REMOVE_SIGNER,
+            RawBytes::serialize(RemoveSignerParams {
+                signer: sender,
+                decrease: false,
+            })
+            .unwrap(),
+        ),
+    );
+
+    let contract = differential.contract.address;
+    differential.run(
+        Case::new(
+            "swap_signer",
+            vec![
+                address(&msig),
+                Token::Tuple(vec![address(&sender), address(&contract)]),
+            ],
+        )
+        .native(
+            msig,
+            method_nums::multisig::SWAP_SIGNER,
+            RawBytes::serialize(SwapSignerParams {
+                from: sender,
+                to: contract,
+            })
+            .unwrap(),
+        ),
+    );
+
+    // The second `swap_signer` overload actually changes the approvals threshold
+    differential.run(
+        Case::new(
+            "swap_signer",
+            vec![address(&msig), Token::Tuple(vec![uint(1)])],
+        )
+        .native(
+            msig,
+            method_nums::multisig::CHANGE_NUM_APPROVALS_THRESHOLD,
+            RawBytes::serialize(ChangeNumApprovalsThresholdParams { new_threshold: 1 }).unwrap(),
+        ),
+    );
+
+    let locked = TokenAmount::from_atto(100);
+    differential.run(
+        Case::new(
+            "lock_balance",
+            vec![
+                address(&msig),
+                Token::Tuple(vec![int(0), int(100), amount(&locked)]),
+            ],
+        )
+        .native(
+            msig,
+            method_nums::multisig::LOCK_BALANCE,
+            RawBytes::serialize(LockBalanceParams {
+                start_epoch: 0,
+                unlock_duration: 100,
+                amount: locked,
+            })
+            .unwrap(),
+        ),
+    );
+
+    // The wrapper forwards already serialized params
+    let params = RawBytes::serialize(UniversalReceiverParams {
+        type_: 0,
+        payload: RawBytes::default(),
+    })
+    .unwrap();
+    differential.run(
+        Case::new(
+            "universal_receiver_hook",
+            vec![address(&msig), Token::Bytes(params.to_vec())],
+        )
+        .native(msig, method_nums::multisig::RECEIVE, params),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
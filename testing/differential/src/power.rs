@@ -0,0 +1,87 @@
+use ethabi::Token;
+use fil_actor_power::MinerRawPowerParams;
+use fil_actors_runtime::STORAGE_POWER_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use harness::differential::{Case, Differential};
+use harness::enums::post_proof_ordinal;
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::setup::create_miner_params;
+use harness::tokens::{address, uint};
+
+use crate::deploy;
+
+const POWER_API_ARTIFACT: &str = "../../build/v0.8/PowerAPI";
+
+/// Wrappers without parameters.
+const GETTERS: [(&str, u64); 3] = [
+    ("miner_count", method_nums::power::MINER_COUNT),
+    (
+        "miner_consensus_count",
+        method_nums::power::MINER_CONSENSUS_COUNT,
+    ),
+    ("network_raw_power", method_nums::power::NETWORK_RAW_POWER),
+];
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing PowerAPI");
+
+    let (mut differential, (miner, worker)) = Differential::new(|| {
+        let (mut harness, contract) = deploy(POWER_API_ARTIFACT);
+        let sender = harness.accounts[0].1;
+
+        let (miner, worker) = harness.create_miner(sender, sender);
+
+        (harness, contract, (miner, worker))
+    });
+
+    let params = create_miner_params(differential.contract.address, worker);
+
+    // The robust address depends on the origin of the message, which differs
+    differential.run(
+        Case::new(
+            "create_miner",
+            vec![Token::Tuple(vec![
+                address(&params.owner),
+                address(&params.worker),
+                uint(post_proof_ordinal(params.window_post_proof_type).unwrap()),
+                Token::Bytes(params.peer.clone()),
+                Token::Array(
+                    params
+                        .multiaddrs
+                        .iter()
+                        .map(|multiaddr| Token::Bytes(multiaddr.0.clone()))
+                        .collect(),
+                ),
+            ])],
+        )
+        .native(
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::CREATE_MINER,
+            RawBytes::serialize(&params).unwrap(),
+        )
+        .ignore(1),
+    );
+
+    for (function, method) in GETTERS {
+        differential.run(Case::new(function, vec![]).native(
+            STORAGE_POWER_ACTOR_ADDR,
+            method,
+            RawBytes::default(),
+        ));
+    }
+
+    let miner_id = miner.id().unwrap();
+    differential.run(
+        Case::new("miner_raw_power", vec![Token::Tuple(vec![uint(miner_id)])]).native(
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::MINER_RAW_POWER,
+            RawBytes::serialize(MinerRawPowerParams { miner: miner_id }).unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
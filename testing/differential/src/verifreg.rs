@@ -0,0 +1,134 @@
+use ethabi::Token;
+use fil_actor_verifreg::{
+    AddVerifiedClientParams, ExtendClaimTermsParams, GetClaimsParams,
+    RemoveExpiredAllocationsParams, RemoveExpiredClaimsParams,
+};
+use fil_actors_runtime::VERIFIED_REGISTRY_ACTOR_ADDR;
+use fvm_actor_utils::receiver::UniversalReceiverParams;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::econ::TokenAmount;
+use harness::differential::{Case, Differential};
+use harness::gas::GasReport;
+use harness::method_nums;
+use harness::report::Report;
+use harness::tokens::{address, amount_bytes, uint};
+
+use crate::deploy;
+
+const VERIFREG_API_ARTIFACT: &str = "../../build/v0.8/VerifRegAPI";
+
+const FRC46_TOKEN_TYPE: u32 = frc42_dispatch::method_hash!("FRC46") as u32;
+
+pub fn run(gas: &mut GasReport, report: &mut Report) -> usize {
+    println!("Comparing VerifRegAPI");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy(VERIFREG_API_ARTIFACT);
+        (harness, contract, ())
+    });
+
+    let sender = differential.sender();
+    let sender_id = differential.api.accounts[0].0;
+
+    differential.run(
+        Case::new(
+            "get_claims",
+            vec![Token::Tuple(vec![uint(sender_id), Token::Array(vec![])])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::GET_CLAIMS,
+            RawBytes::serialize(GetClaimsParams {
+                provider: sender_id,
+                claim_ids: vec![],
+            })
+            .unwrap(),
+        ),
+    );
+
+    // The contract is not a verifier, so both sides are expected to be rejected
+    let allowance = TokenAmount::from_whole(1);
+    differential.run(
+        Case::new(
+            "add_verified_client",
+            vec![Token::Tuple(vec![address(&sender), amount_bytes(&allowance)])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::ADD_VERIFIED_CLIENT,
+            RawBytes::serialize(AddVerifiedClientParams {
+                address: sender,
+                allowance,
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "remove_expired_allocations",
+            vec![Token::Tuple(vec![uint(sender_id), Token::Array(vec![])])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::REMOVE_EXPIRED_ALLOCATIONS,
+            RawBytes::serialize(RemoveExpiredAllocationsParams {
+                client: sender_id,
+                allocation_ids: vec![],
+            })
+            .unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "extend_claim_terms",
+            vec![Token::Tuple(vec![Token::Array(vec![])])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::EXTEND_CLAIM_TERMS,
+            RawBytes::serialize(ExtendClaimTermsParams { terms: vec![] }).unwrap(),
+        ),
+    );
+
+    differential.run(
+        Case::new(
+            "remove_expired_claims",
+            vec![Token::Tuple(vec![uint(sender_id), Token::Array(vec![])])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::REMOVE_EXPIRED_CLAIMS,
+            RawBytes::serialize(RemoveExpiredClaimsParams {
+                provider: sender_id,
+                claim_ids: vec![],
+            })
+            .unwrap(),
+        ),
+    );
+
+    // Only the datacap actor may call the hook, so both sides are expected to be rejected
+    differential.run(
+        Case::new(
+            "universal_receiver_hook",
+            vec![Token::Tuple(vec![
+                uint(FRC46_TOKEN_TYPE as u64),
+                Token::Bytes(vec![]),
+            ])],
+        )
+        .native(
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::RECEIVE,
+            RawBytes::serialize(UniversalReceiverParams {
+                type_: FRC46_TOKEN_TYPE,
+                payload: RawBytes::default(),
+            })
+            .unwrap(),
+        ),
+    );
+
+    gas.merge(differential.api.gas_report());
+    report.extend(differential.cases());
+    differential.report()
+}
@@ -0,0 +1,213 @@
+//! Dispatch suite.
+//!
+//! The method numbers the APIs call come from the list in `script/src/actors.rs`, through
+//! the generated [`harness::method_nums`]. A name typed wrong or a method builtin-actors
+//! stopped exporting would only show up as an exit code 22 once a contract calls it. Here
+//! every listed exported method is sent, without params, to an actor of its kind. The
+//! actor rejecting the params proves it dispatched the method; only "unhandled message"
+//! means the number isn't exported.
+//!
+//! The methods the bundle handled, along with those that aren't exported and can't be
+//! probed, are written to `exported-methods.json` in the report directory. The script's
+//! method number manifests are built from it, see `script/src/manifest.rs`.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::process::exit;
+
+use fil_actor_init::{ExecParams, ExecReturn};
+use fil_actor_multisig::ConstructorParams;
+use fil_actors_runtime::{
+    DATACAP_TOKEN_ACTOR_ADDR, INIT_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR,
+    VERIFIED_REGISTRY_ACTOR_ADDR,
+};
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::{ActorID, MethodNum};
+use harness::checks::{check, report};
+use harness::method_nums::{self, ACTORS};
+use harness::methods::FIRST_EXPORTED_METHOD;
+use harness::report::report_dir;
+use harness::Harness;
+
+/// Written to the report directory, for `script manifest`.
+const EXPORTED_METHODS: &str = "exported-methods.json";
+
+/// How an actor handled a method sent without params.
+#[derive(Debug)]
+enum Dispatch {
+    /// The method ran, it takes no params.
+    Succeeded,
+    /// The method is there, but wants params.
+    BadParams(ExitCode),
+    /// The method is there, but refused the call for another reason, e.g. the caller.
+    Rejected(ExitCode),
+    NotFound(ExitCode),
+}
+
+impl Dispatch {
+    fn new(exit_code: ExitCode) -> Self {
+        if exit_code.is_success() {
+            Dispatch::Succeeded
+        } else if [ExitCode::USR_SERIALIZATION, ExitCode::USR_ILLEGAL_ARGUMENT].contains(&exit_code)
+        {
+            Dispatch::BadParams(exit_code)
+        } else if exit_code == ExitCode::USR_UNHANDLED_MESSAGE {
+            Dispatch::NotFound(exit_code)
+        } else {
+            Dispatch::Rejected(exit_code)
+        }
+    }
+}
+
+/// An actor of every kind listed in [`ACTORS`].
+struct World {
+    sender: Address,
+    miner: Address,
+    account: Address,
+    multisig: Address,
+}
+
+fn setup() -> (Harness, World) {
+    let mut harness = Harness::new();
+
+    let accounts: [(ActorID, Address); 2] = harness.create_accounts();
+    let sender = accounts[0].1;
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let (miner, _) = harness.create_miner(sender, sender);
+
+    let res = harness.send(
+        sender,
+        INIT_ACTOR_ADDR,
+        method_nums::init::EXEC,
+        RawBytes::serialize(ExecParams {
+            code_cid: harness.code_cid("multisig"),
+            constructor_params: RawBytes::serialize(ConstructorParams {
+                signers: vec![sender],
+                num_approvals_threshold: 1,
+                unlock_duration: 0,
+                start_epoch: 0,
+            })
+            .unwrap(),
+        })
+        .unwrap(),
+        TokenAmount::default(),
+    );
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    let multisig: ExecReturn = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+
+    let world = World {
+        sender,
+        miner,
+        account: accounts[1].1,
+        multisig: multisig.id_address,
+    };
+
+    (harness, world)
+}
+
+/// The actor methods of `actor` are sent to.
+fn target(actor: &str, world: &World) -> Address {
+    match actor {
+        "Market" => STORAGE_MARKET_ACTOR_ADDR,
+        "Miner" => world.miner,
+        "Power" => STORAGE_POWER_ACTOR_ADDR,
+        "VerifReg" => VERIFIED_REGISTRY_ACTOR_ADDR,
+        "DataCap" => DATACAP_TOKEN_ACTOR_ADDR,
+        "Init" => INIT_ACTOR_ADDR,
+        "Account" => world.account,
+        "Multisig" => world.multisig,
+        actor => panic!("no {} actor to send methods to", actor),
+    }
+}
+
+/// Sends `method` without params on a copy of the state, so that methods that succeed
+/// don't change what the next ones see.
+fn dispatch(harness: &mut Harness, from: Address, to: Address, method: MethodNum) -> Dispatch {
+    let message = harness.send_message(
+        from,
+        to,
+        method,
+        RawBytes::default(),
+        TokenAmount::default(),
+    );
+    let res = harness.apply_reverted(message);
+
+    Dispatch::new(res.msg_receipt.exit_code)
+}
+
+fn main() {
+    println!("Sending every listed exported method to its actor");
+
+    let (mut harness, world) = setup();
+
+    let mut results = vec![];
+    let mut handled: BTreeMap<&str, BTreeMap<&str, MethodNum>> = BTreeMap::new();
+
+    for (actor, methods) in ACTORS {
+        let to = target(actor, &world);
+        println!("{} ({})", actor, to);
+
+        let actor_handled = handled.entry(*actor).or_default();
+        for (name, number) in methods.iter() {
+            if *number < FIRST_EXPORTED_METHOD {
+                println!("  {} ({}): not exported, skipped", name, number);
+                actor_handled.insert(*name, *number);
+                continue;
+            }
+
+            let outcome = match dispatch(&mut harness, world.sender, to, *number) {
+                Dispatch::Succeeded => {
+                    println!("  {} ({}): succeeded", name, number);
+                    Ok(())
+                }
+                Dispatch::BadParams(exit_code) => {
+                    println!(
+                        "  {} ({}): bad params, exit code {}",
+                        name,
+                        number,
+                        exit_code.value()
+                    );
+                    Ok(())
+                }
+                Dispatch::Rejected(exit_code) => {
+                    println!(
+                        "  {} ({}): rejected, exit code {}",
+                        name,
+                        number,
+                        exit_code.value()
+                    );
+                    Ok(())
+                }
+                Dispatch::NotFound(exit_code) => Err(format!(
+                    "method {} not found, exit code {}",
+                    number,
+                    exit_code.value()
+                )),
+            };
+            if outcome.is_ok() {
+                actor_handled.insert(*name, *number);
+            }
+            check(&mut results, format!("{}.{}", actor, name), outcome);
+        }
+    }
+
+    let failures = report("dispatch", &results);
+
+    let path = report_dir().join(EXPORTED_METHODS);
+    fs::write(
+        &path,
+        serde_json::to_string_pretty(&handled).unwrap() + "\n",
+    )
+    .expect("Unable to write exported methods");
+    println!("Wrote {}", path.display());
+
+    if failures > 0 {
+        exit(1);
+    }
+}
@@ -0,0 +1,280 @@
+//! Enum ordinal suite.
+//!
+//! The `CommonTypes.sol` proof enums go through the API contracts as their ordinals, see
+//! [`harness::enums`]. Every PoSt proof is passed to `create_miner` and to the native power
+//! actor: both have to accept or reject it alike, and a miner created through the API has
+//! to report through `get_sector_size` the size of the proof it was given. Seal and update
+//! proofs aren't taken by any wrapper, they are checked through the PoSt proof and sector
+//! size they go with.
+
+use std::collections::HashMap;
+use std::process::exit;
+
+use ethabi::Token;
+use fil_actor_power::CreateMinerParams;
+use fil_actors_runtime::STORAGE_POWER_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::sector::{RegisteredPoStProof, SectorSize};
+use fvm_shared::ActorID;
+use harness::checks::{check, failure, report, Results};
+use harness::enums::{
+    post_proof, post_proof_ordinal, seal_proof, sector_size, sector_size_from_bytes,
+    sector_size_ordinal, update_proof, update_proof_ordinal, POST_PROOFS, SEAL_PROOFS,
+    SECTOR_SIZES, UPDATE_PROOFS,
+};
+use harness::method_nums;
+use harness::setup::create_miner_params;
+use harness::tokens::{address, uint};
+use harness::{Contract, Harness};
+
+const POWER_API_ARTIFACT: &str = "../../build/v0.8/PowerAPI";
+const MINER_API_ARTIFACT: &str = "../../build/v0.8/MinerAPI";
+
+/// Deploys PowerAPI and MinerAPI next to a BLS account, the worker of every miner.
+fn setup() -> (Harness, Contract, Contract, Address) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let power_api = harness.deploy(sender[0].1, POWER_API_ARTIFACT);
+    let miner_api = harness.deploy(sender[0].1, MINER_API_ARTIFACT);
+
+    let worker = harness.bls_worker(sender[0].1);
+
+    (harness, power_api, miner_api, worker)
+}
+
+/// Every table entry has to map back to the same variant, and no two entries may share
+/// an ordinal.
+fn mappings(results: &mut Results) {
+    println!("Checking the ordinal mappings");
+
+    for (proof, ordinal) in SEAL_PROOFS {
+        check(
+            results,
+            format!("RegisteredSealProof {:?}", proof),
+            match seal_proof(ordinal) {
+                Some(mapped) if mapped == proof => Ok(()),
+                other => Err(format!("ordinal {} maps back to {:?}", ordinal, other)),
+            },
+        );
+    }
+    for (proof, ordinal) in POST_PROOFS {
+        check(
+            results,
+            format!("RegisteredPoStProof {:?}", proof),
+            match post_proof(ordinal) {
+                Some(mapped) if mapped == proof => Ok(()),
+                other => Err(format!("ordinal {} maps back to {:?}", ordinal, other)),
+            },
+        );
+    }
+    for (proof, ordinal) in UPDATE_PROOFS {
+        check(
+            results,
+            format!("RegisteredUpdateProof {:?}", proof),
+            match update_proof(ordinal) {
+                Some(mapped) if mapped == proof => Ok(()),
+                other => Err(format!("ordinal {} maps back to {:?}", ordinal, other)),
+            },
+        );
+    }
+    for (size, ordinal) in SECTOR_SIZES {
+        check(
+            results,
+            format!("SectorSize {:?}", size),
+            match (sector_size(ordinal), sector_size_from_bytes(size as u64)) {
+                (Some(mapped), Some(from_bytes)) if mapped == size && from_bytes == size => Ok(()),
+                other => Err(format!(
+                    "ordinal {} and size {} map back to {:?}",
+                    ordinal, size as u64, other
+                )),
+            },
+        );
+    }
+}
+
+/// Creates a miner through PowerAPI with the ordinal of `proof` and returns its sector size
+/// read back through MinerAPI, or `None` if the power actor refused the proof.
+fn api_sector_size(
+    harness: &mut Harness,
+    power_api: &Contract,
+    miner_api: &Contract,
+    worker: Address,
+    proof: RegisteredPoStProof,
+) -> Result<Option<u64>, String> {
+    let sender = harness.accounts[0].1;
+    let ordinal = post_proof_ordinal(proof).ok_or("no ordinal")?;
+
+    let res = harness.call(
+        sender,
+        power_api,
+        "create_miner",
+        &[Token::Tuple(vec![
+            address(&sender),
+            address(&worker),
+            uint(ordinal),
+            Token::Bytes(vec![1, 2, 3]),
+            Token::Array(vec![Token::Bytes(vec![1, 2, 3])]),
+        ])],
+        TokenAmount::default(),
+    );
+    if res.msg_receipt.exit_code.value() != 0 {
+        return Ok(None);
+    }
+
+    let miner = match &power_api.decode("create_miner", &res)[..] {
+        [Token::Tuple(ret)] => match &ret[..] {
+            [Token::Bytes(id_address), _] => Address::from_bytes(id_address)
+                .map_err(|e| format!("create_miner returned {}", e))?,
+            other => return Err(format!("create_miner returned {:?}", other)),
+        },
+        other => return Err(format!("create_miner returned {:?}", other)),
+    };
+
+    let res = harness.call(
+        sender,
+        miner_api,
+        "get_sector_size",
+        &[address(&miner)],
+        TokenAmount::default(),
+    );
+    if res.msg_receipt.exit_code.value() != 0 {
+        return Err(format!("get_sector_size failed with {}", failure(&res)));
+    }
+
+    match &miner_api.decode("get_sector_size", &res)[..] {
+        [Token::Tuple(ret)] => match &ret[..] {
+            [Token::Uint(size)] => Ok(Some(size.as_u64())),
+            other => Err(format!("get_sector_size returned {:?}", other)),
+        },
+        other => Err(format!("get_sector_size returned {:?}", other)),
+    }
+}
+
+/// Passes every PoSt proof to `create_miner` and returns the sector size of the miners the
+/// power actor accepted.
+fn post_proofs(results: &mut Results) -> HashMap<u64, SectorSize> {
+    println!("Passing every RegisteredPoStProof to PowerAPI");
+
+    let (mut harness, power_api, miner_api, worker) = setup();
+    let sender = harness.accounts[0].1;
+    let mut sizes = HashMap::new();
+
+    for (proof, ordinal) in POST_PROOFS {
+        let res = harness.send(
+            sender,
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::CREATE_MINER,
+            RawBytes::serialize(CreateMinerParams {
+                window_post_proof_type: proof,
+                ..create_miner_params(sender, worker)
+            })
+            .unwrap(),
+            TokenAmount::default(),
+        );
+        let native = res.msg_receipt.exit_code.value() == 0;
+
+        let api = api_sector_size(&mut harness, &power_api, &miner_api, worker, proof);
+
+        let outcome = match (native, api) {
+            (_, Err(problem)) => Err(problem),
+            (false, Ok(None)) => Ok(()),
+            (false, Ok(Some(_))) => Err(format!(
+                "accepted, the actor refuses it with {}",
+                failure(&res)
+            )),
+            (true, Ok(None)) => Err("refused, the actor accepts it".to_string()),
+            (true, Ok(Some(bytes))) => match (proof.sector_size(), sector_size_from_bytes(bytes)) {
+                (Ok(expected), Some(size)) if size == expected => {
+                    sizes.insert(ordinal, size);
+                    Ok(())
+                }
+                (expected, _) => Err(format!(
+                    "miner reports {} bytes, the proof is for {:?}",
+                    bytes, expected
+                )),
+            },
+        };
+
+        check(
+            results,
+            format!("RegisteredPoStProof {:?} ({})", proof, ordinal),
+            outcome,
+        );
+    }
+
+    sizes
+}
+
+/// A seal proof goes with the window PoSt proof of its size, and the ordinals of the update
+/// proofs follow the sector sizes.
+fn seal_proofs(results: &mut Results, sizes: &HashMap<u64, SectorSize>) {
+    println!("Checking RegisteredSealProof and RegisteredUpdateProof against the miners");
+
+    for (proof, ordinal) in SEAL_PROOFS {
+        let name = format!("RegisteredSealProof {:?} ({})", proof, ordinal);
+
+        let (size, post, update) = match (
+            proof.sector_size(),
+            proof.registered_window_post_proof(),
+            proof.registered_update_proof(),
+        ) {
+            (Ok(size), Ok(post), Ok(update)) => (size, post, update),
+            other => {
+                check(
+                    results,
+                    name,
+                    Err(format!("no native counterparts: {:?}", other)),
+                );
+                continue;
+            }
+        };
+
+        let post_ordinal = post_proof_ordinal(post);
+        let outcome = match post_ordinal.and_then(|ordinal| sizes.get(&ordinal)) {
+            Some(reported) if *reported == size => Ok(()),
+            Some(reported) => Err(format!(
+                "a miner with {:?} reports {:?}, the proof is for {:?}",
+                post, reported, size
+            )),
+            // Refused alike by the actor, nothing to compare with
+            None if post_ordinal.is_some() => Ok(()),
+            None => Err(format!("no ordinal for {:?}", post)),
+        };
+        check(results, format!("{} window PoSt", name), outcome);
+
+        let outcome = match update_proof_ordinal(update) {
+            Some(ordinal) if ordinal == sector_size_ordinal(size) => Ok(()),
+            other => Err(format!(
+                "{:?} is ordinal {:?}, {:?} is {}",
+                update,
+                other,
+                size,
+                sector_size_ordinal(size)
+            )),
+        };
+        check(results, format!("{} update", name), outcome);
+    }
+}
+
+fn main() {
+    println!("Testing proof and sector size enums through the API contracts");
+
+    let mut results = vec![];
+
+    mappings(&mut results);
+    let sizes = post_proofs(&mut results);
+    seal_proofs(&mut results, &sizes);
+
+    let failures = report("enums", &results);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
@@ -0,0 +1,299 @@
+//! Out of gas suite.
+//!
+//! Messages to the API contracts are sent with a blanket `GAS_LIMIT`, which never lets
+//! anything run out of gas. Here the smallest limit each wrapper succeeds with is
+//! estimated, then the wrapper is sent with less, so that the actor call behind it runs
+//! out of gas. `Actor.call` gives that call at most `Actor.GAS_LIMIT`; whatever it is
+//! given, the wrapper must not succeed unless the actor call did, or it would return
+//! whatever was left in its response buffer.
+
+use std::process::exit;
+
+use ethabi::Token;
+use fil_actors_runtime::STORAGE_MARKET_ACTOR_ADDR;
+use fvm::executor::ApplyRet;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::message::Message;
+use fvm_shared::ActorID;
+use harness::checks::{check, report, Results};
+use harness::contract::revert_reason;
+use harness::method_nums;
+use harness::precompile::ACTOR_GAS_LIMIT;
+use harness::tokens::{address, amount, uint};
+use harness::trace::{self, Call, Outcome};
+use harness::{Contract, Harness};
+
+const MARKET_API_ARTIFACT: &str = "../../build/v0.8/MarketAPI";
+const MINER_API_ARTIFACT: &str = "../../build/v0.8/MinerAPI";
+const POWER_API_ARTIFACT: &str = "../../build/v0.8/PowerAPI";
+const DATACAP_API_ARTIFACT: &str = "../../build/v0.8/DataCapAPI";
+const VERIFREG_API_ARTIFACT: &str = "../../build/v0.8/VerifRegAPI";
+
+/// Below the estimate, the wrapper is sent with this many fractions less of the gas its
+/// actor call used.
+const STEPS: i64 = 4;
+
+/// What the wrapper arguments refer to.
+struct World {
+    contract: Address,
+    miner: Address,
+    worker: Address,
+}
+
+struct Wrapper {
+    artifact: &'static str,
+    function: &'static str,
+    args: fn(&World) -> Vec<Token>,
+}
+
+fn wrapper(
+    artifact: &'static str,
+    function: &'static str,
+    args: fn(&World) -> Vec<Token>,
+) -> Wrapper {
+    Wrapper {
+        artifact,
+        function,
+        args,
+    }
+}
+
+fn wrappers() -> Vec<Wrapper> {
+    let mut wrappers = vec![
+        wrapper(MARKET_API_ARTIFACT, "add_balance", |world| {
+            vec![address(&world.contract)]
+        }),
+        wrapper(MARKET_API_ARTIFACT, "get_balance", |world| {
+            vec![address(&world.contract)]
+        }),
+        wrapper(MARKET_API_ARTIFACT, "withdraw_balance", |world| {
+            vec![Token::Tuple(vec![
+                address(&world.contract),
+                amount(&TokenAmount::from_atto(100)),
+            ])]
+        }),
+    ];
+
+    for getter in [
+        "get_owner",
+        "get_sector_size",
+        "get_available_balance",
+        "get_vesting_funds",
+        "get_beneficiary",
+        "get_peer_id",
+        "get_multiaddresses",
+    ] {
+        wrappers.push(wrapper(MINER_API_ARTIFACT, getter, |world| {
+            vec![address(&world.miner)]
+        }));
+    }
+    wrappers.extend([
+        wrapper(MINER_API_ARTIFACT, "is_controlling_address", |world| {
+            vec![
+                address(&world.miner),
+                Token::Tuple(vec![address(&world.worker)]),
+            ]
+        }),
+        wrapper(MINER_API_ARTIFACT, "change_peer_id", |world| {
+            vec![
+                address(&world.miner),
+                Token::Tuple(vec![Token::Bytes(vec![4, 5, 6])]),
+            ]
+        }),
+        wrapper(MINER_API_ARTIFACT, "change_multiaddresses", |world| {
+            vec![
+                address(&world.miner),
+                Token::Tuple(vec![Token::Array(vec![Token::Bytes(vec![4, 5, 6])])]),
+            ]
+        }),
+    ]);
+
+    for getter in ["miner_count", "miner_consensus_count", "network_raw_power"] {
+        wrappers.push(wrapper(POWER_API_ARTIFACT, getter, |_| vec![]));
+    }
+    wrappers.push(wrapper(POWER_API_ARTIFACT, "miner_raw_power", |world| {
+        vec![Token::Tuple(vec![uint(world.miner.id().unwrap())])]
+    }));
+
+    for getter in ["name", "symbol", "total_supply"] {
+        wrappers.push(wrapper(DATACAP_API_ARTIFACT, getter, |_| vec![]));
+    }
+    wrappers.push(wrapper(DATACAP_API_ARTIFACT, "balance", |world| {
+        vec![address(&world.contract)]
+    }));
+
+    wrappers.push(wrapper(VERIFREG_API_ARTIFACT, "get_claims", |world| {
+        vec![Token::Tuple(vec![
+            uint(world.miner.id().unwrap()),
+            Token::Array(vec![]),
+        ])]
+    }));
+
+    wrappers
+}
+
+/// Deploys `artifact` with tracing on, next to a miner it owns and some market escrow of
+/// its own, so that the state-changing wrappers succeed with enough gas.
+fn setup(artifact: &str) -> (Harness, Contract, World) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine, tracing calls to find the actor call in every message
+    harness.trace();
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, artifact);
+
+    let (miner, worker) = harness.create_miner(sender[0].1, contract.address);
+
+    let res = harness.send(
+        sender[0].1,
+        STORAGE_MARKET_ACTOR_ADDR,
+        method_nums::market::ADD_BALANCE,
+        RawBytes::serialize(contract.address).unwrap(),
+        TokenAmount::from_atto(1000),
+    );
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    let world = World {
+        contract: contract.address,
+        miner,
+        worker,
+    };
+
+    (harness, contract, world)
+}
+
+/// The call the contract made to a builtin actor, through the call actor precompile.
+fn actor_call(res: &ApplyRet, contract: &Contract) -> Option<Call> {
+    let contract_id = contract.address.id().unwrap();
+
+    trace::build(&res.exec_trace)
+        .into_iter()
+        .next()?
+        .calls
+        .into_iter()
+        .find(|call| call.from == contract_id)
+}
+
+fn describe(res: &ApplyRet, contract: &Contract) -> String {
+    let exit_code = res.msg_receipt.exit_code.value();
+    let message = match revert_reason(res) {
+        Some(reason) => format!("exit code {} ({})", exit_code, reason),
+        None => format!("exit code {}", exit_code),
+    };
+
+    let call = match actor_call(res, contract) {
+        Some(call) => match call.outcome {
+            Outcome::Returned(exit_code, _) => {
+                format!("actor call exit code {}", exit_code.value())
+            }
+            Outcome::Failed(err) => format!("actor call failed: {}", err),
+            Outcome::Unfinished => "actor call did not return".to_string(),
+        },
+        None => "actor call not reached".to_string(),
+    };
+
+    format!("{}, {}", message, call)
+}
+
+/// Whether the actor call made while applying `res` completed successfully.
+fn actor_call_succeeded(res: &ApplyRet, contract: &Contract) -> bool {
+    matches!(
+        actor_call(res, contract).map(|call| call.outcome),
+        Some(Outcome::Returned(exit_code, _)) if exit_code.is_success()
+    )
+}
+
+fn run(results: &mut Results, wrapper: &Wrapper) {
+    let (mut harness, contract, world) = setup(wrapper.artifact);
+    let sender = harness.accounts[0].1;
+    let name = wrapper.function;
+
+    let message = harness.call_message(
+        sender,
+        &contract,
+        name,
+        &(wrapper.args)(&world),
+        TokenAmount::default(),
+    );
+    let with = |gas_limit| Message {
+        gas_limit,
+        ..message.clone()
+    };
+
+    let estimate = match harness.estimate_gas(message.clone()) {
+        Some(estimate) => estimate,
+        None => {
+            let res = harness.apply_reverted(message.clone());
+            check(
+                results,
+                format!("{} estimate", name),
+                Err(format!(
+                    "fails regardless of gas, {}",
+                    describe(&res, &contract)
+                )),
+            );
+            return;
+        }
+    };
+
+    let res = harness.apply_reverted(with(estimate));
+    let used = match actor_call(&res, &contract) {
+        Some(call) => call.gas.round_up(),
+        None => {
+            print!("{}", harness.call_tree(&res));
+            check(results, name.to_string(), Err("no actor call".to_string()));
+            return;
+        }
+    };
+    println!(
+        "  {}: estimated {} gas, the actor call used {} of Actor.GAS_LIMIT {}",
+        name, estimate, used, ACTOR_GAS_LIMIT
+    );
+
+    let res = harness.apply_reverted(with(estimate - 1));
+    let outcome = if res.msg_receipt.exit_code.is_success() {
+        Err(format!("also succeeds with {} gas", estimate - 1))
+    } else {
+        Ok(())
+    };
+    check(results, format!("{} estimate", name), outcome);
+
+    for step in 1..=STEPS {
+        let gas_limit = estimate - used * step / STEPS;
+        let res = harness.apply_reverted(with(gas_limit));
+        println!("    {} gas: {}", gas_limit, describe(&res, &contract));
+
+        let outcome =
+            if res.msg_receipt.exit_code.is_success() && !actor_call_succeeded(&res, &contract) {
+                print!("{}", harness.call_tree(&res));
+                Err(format!(
+                    "succeeded with {} gas although its actor call did not",
+                    gas_limit
+                ))
+            } else {
+                Ok(())
+            };
+        check(results, format!("{} with {} gas", name, gas_limit), outcome);
+    }
+}
+
+fn main() {
+    println!("Running the API wrappers out of gas");
+
+    let mut results = vec![];
+
+    for wrapper in wrappers() {
+        run(&mut results, &wrapper);
+    }
+
+    let failures = report("gas", &results);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
@@ -0,0 +1,73 @@
+//! Conversions between the textual (`f0101`), binary (`0065`) and Ethereum forms of
+//! filecoin addresses.
+//!
+//! The API contracts take addresses as their binary form, and `Actor.call` only accepts
+//! ID addresses as target. Ethereum addresses are either a masked ID address
+//! (`0xff` followed by zeros and the big-endian ID) or the subaddress of an `f410`
+//! delegated address.
+
+use std::str::FromStr;
+
+use fvm_shared::address::{Address, Payload};
+use fvm_shared::ActorID;
+
+/// Namespace of the EAM, under which contracts get their `f410` addresses.
+pub const EAM_NAMESPACE: ActorID = 10;
+
+pub const ETH_ADDRESS_LEN: usize = 20;
+
+/// First byte of an Ethereum address masking an ID address.
+const ID_MASK_PREFIX: u8 = 0xff;
+
+/// Parses the textual form, e.g. `f0101` or `t410f...`.
+pub fn parse(text: &str) -> Result<Address, String> {
+    Address::from_str(text).map_err(|e| format!("invalid address {}: {}", text, e))
+}
+
+/// Binary form as hex, e.g. `0065` for `f0101`.
+pub fn to_hex(addr: &Address) -> String {
+    hex::encode(addr.to_bytes())
+}
+
+pub fn from_hex(encoded: &str) -> Result<Address, String> {
+    let bytes = hex::decode(encoded).map_err(|e| format!("invalid hex {}: {}", encoded, e))?;
+    Address::from_bytes(&bytes).map_err(|e| format!("invalid address {}: {}", encoded, e))
+}
+
+/// Ethereum form of ID addresses and of EAM delegated addresses. Other protocols have none.
+pub fn to_eth(addr: &Address) -> Option<[u8; ETH_ADDRESS_LEN]> {
+    match addr.payload() {
+        Payload::ID(id) => {
+            let mut eth = [0; ETH_ADDRESS_LEN];
+            eth[0] = ID_MASK_PREFIX;
+            eth[12..].copy_from_slice(&id.to_be_bytes());
+            Some(eth)
+        }
+        Payload::Delegated(delegated) if delegated.namespace() == EAM_NAMESPACE => {
+            delegated.subaddress().try_into().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Inverse of [`to_eth`]: masked IDs give an ID address, anything else an `f410` address.
+pub fn from_eth(eth: [u8; ETH_ADDRESS_LEN]) -> Address {
+    if eth[0] == ID_MASK_PREFIX && eth[1..12].iter().all(|byte| *byte == 0) {
+        let mut id = [0; 8];
+        id.copy_from_slice(&eth[12..]);
+        return Address::new_id(u64::from_be_bytes(id));
+    }
+
+    Address::new_delegated(EAM_NAMESPACE, &eth).unwrap()
+}
+
+/// Human readable protocol name, for test output.
+pub fn protocol_name(addr: &Address) -> &'static str {
+    match addr.payload() {
+        Payload::ID(_) => "id",
+        Payload::Secp256k1(_) => "secp256k1",
+        Payload::Actor(_) => "actor",
+        Payload::BLS(_) => "bls",
+        Payload::Delegated(_) => "delegated",
+    }
+}
@@ -0,0 +1,86 @@
+//! Balance bookkeeping around messages.
+//!
+//! With [`crate::Harness::check_balances`] on, every message is applied between two
+//! snapshots of all actor balances. Value has to be conserved: gas moves funds from the
+//! sender to the burnt funds and reward actors, and anything else moves between actors,
+//! so the total never changes. The per-actor changes of the last message are kept, to
+//! tell which actor ended up with the value sent along.
+
+use std::collections::BTreeMap;
+
+use fil_actors_runtime::{BURNT_FUNDS_ACTOR_ADDR, REWARD_ACTOR_ADDR};
+use fvm::state_tree::StateTree;
+use fvm_ipld_blockstore::Blockstore;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::ActorID;
+
+/// IDs below this one are reserved for the singleton actors.
+const FIRST_NON_SINGLETON_ID: ActorID = 100;
+
+/// Balance of every actor, keyed by ID.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Balances(pub BTreeMap<ActorID, TokenAmount>);
+
+/// Balance changes, keyed by ID. Only actors whose balance changed are present.
+pub type Changes = BTreeMap<ActorID, TokenAmount>;
+
+impl Balances {
+    /// The init actor hands out IDs sequentially, so the scan stops at the first unused
+    /// one after the singletons.
+    pub fn snapshot<BS: Blockstore>(state_tree: &StateTree<BS>) -> Self {
+        let mut balances = BTreeMap::new();
+
+        for id in 0.. {
+            match state_tree.get_actor(id).unwrap() {
+                Some(actor) => {
+                    balances.insert(id, actor.balance);
+                }
+                None if id >= FIRST_NON_SINGLETON_ID => break,
+                None => {}
+            }
+        }
+
+        Balances(balances)
+    }
+
+    pub fn total(&self) -> TokenAmount {
+        self.0
+            .values()
+            .fold(TokenAmount::default(), |total, balance| total + balance)
+    }
+
+    /// What every actor gained (positive) or lost (negative) between `self` and `after`.
+    pub fn changes(&self, after: &Balances) -> Changes {
+        let mut changes = Changes::new();
+
+        for (id, balance) in &after.0 {
+            let before = self.0.get(id).cloned().unwrap_or_default();
+            if *balance != before {
+                changes.insert(*id, balance - &before);
+            }
+        }
+        for (id, balance) in &self.0 {
+            if !after.0.contains_key(id) {
+                changes.insert(*id, -balance.clone());
+            }
+        }
+
+        changes
+    }
+}
+
+/// Changes left once gas is taken out: the burnt funds and reward actors, and `sender`
+/// which paid for it.
+pub fn transfers(changes: &Changes, sender: ActorID) -> Changes {
+    let gas = [
+        sender,
+        BURNT_FUNDS_ACTOR_ADDR.id().unwrap(),
+        REWARD_ACTOR_ADDR.id().unwrap(),
+    ];
+
+    changes
+        .iter()
+        .filter(|(id, _)| !gas.contains(id))
+        .map(|(id, change)| (*id, change.clone()))
+        .collect()
+}
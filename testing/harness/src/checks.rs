@@ -0,0 +1,51 @@
+//! Bookkeeping for the suites that run many independent checks in one binary.
+//!
+//! A failed check is printed as soon as it is recorded, so the output reads in the order
+//! things happened, and [`report`] sums everything up at the end, in a JUnit XML and JSON
+//! [`Report`].
+
+use fvm::executor::ApplyRet;
+
+use crate::contract::revert_reason;
+use crate::report::{report_dir, Report, Status, TestCase};
+
+/// Name and outcome of every check, in the order they ran.
+pub type Results = Vec<(String, Result<(), String>)>;
+
+pub fn check(results: &mut Results, name: String, outcome: Result<(), String>) {
+    if let Err(problem) = &outcome {
+        println!("  FAILED {}: {}", name, problem);
+    }
+    results.push((name, outcome));
+}
+
+/// Prints how many checks ran and failed, and writes them to `<suite>.xml` and
+/// `<suite>.json` in [`report_dir`]. Returns the failures.
+pub fn report(suite: &str, results: &Results) -> usize {
+    let failures = results
+        .iter()
+        .filter(|(_, outcome)| outcome.is_err())
+        .count();
+    println!("{} checks, {} failures", results.len(), failures);
+
+    let mut report = Report::new(suite);
+    for (name, outcome) in results {
+        let status = match outcome {
+            Ok(()) => Status::Pass,
+            Err(problem) => Status::Fail(problem.clone()),
+        };
+        report.push(TestCase::check(suite, name, status));
+    }
+    report.write(&report_dir());
+
+    failures
+}
+
+/// Exit code and revert reason of a message that was expected to succeed.
+pub fn failure(res: &ApplyRet) -> String {
+    format!(
+        "exit code {} ({:?})",
+        res.msg_receipt.exit_code.value(),
+        revert_reason(res)
+    )
+}
@@ -0,0 +1,106 @@
+use std::fs::File;
+
+use ethabi::{ParamType, Token};
+use fvm::executor::ApplyRet;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+
+/// Selector of solidity's `Panic(uint256)`, used by `assert`, overflows and bad indexing.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// A deployed solidity contract together with the ABI solc produced for it.
+pub struct Contract {
+    /// Name of the solc artifact, e.g. `MarketAPI`.
+    pub name: String,
+    pub address: Address,
+    /// `f2` and `f410` addresses the EAM assigned, when deployed by [`crate::Harness`].
+    pub robust_address: Option<Address>,
+    pub delegated_address: Option<Address>,
+    pub abi: ethabi::Contract,
+}
+
+impl Contract {
+    pub fn load(address: Address, artifact: &str) -> Self {
+        let abi_path = format!("{}.abi", artifact);
+        let file = File::open(&abi_path).expect("Unable to read abi file");
+        let abi = ethabi::Contract::load(file).unwrap();
+
+        let name = artifact.rsplit('/').next().unwrap().to_string();
+
+        Contract {
+            name,
+            address,
+            robust_address: None,
+            delegated_address: None,
+            abi,
+        }
+    }
+
+    /// Looks `name` up in the ABI. Overloaded functions (e.g. the two `swap_signer` in
+    /// MultisigAPI) are told apart by the arguments they are called with.
+    pub fn function(&self, name: &str, args: &[Token]) -> &ethabi::Function {
+        let functions = self
+            .abi
+            .functions_by_name(name)
+            .unwrap_or_else(|_| panic!("function `{}` not found in abi", name));
+
+        functions
+            .iter()
+            .find(|function| {
+                let types: Vec<ParamType> =
+                    function.inputs.iter().map(|p| p.kind.clone()).collect();
+                Token::types_check(args, &types)
+            })
+            .unwrap_or(&functions[0])
+    }
+
+    pub fn calldata(&self, name: &str, args: &[Token]) -> Vec<u8> {
+        self.function(name, args).encode_input(args).unwrap()
+    }
+
+    /// Decodes the return value of a successful call to `name`.
+    pub fn decode(&self, name: &str, res: &ApplyRet) -> Vec<Token> {
+        assert_eq!(
+            res.msg_receipt.exit_code.value(),
+            0,
+            "call to `{}` failed",
+            name
+        );
+
+        let output = return_bytes(&res.msg_receipt.return_data);
+        self.abi
+            .function(name)
+            .unwrap()
+            .decode_output(&output)
+            .unwrap()
+    }
+}
+
+/// The EVM actor wraps its return data as a CBOR byte string.
+pub fn return_bytes(return_data: &RawBytes) -> Vec<u8> {
+    if return_data.is_empty() {
+        return vec![];
+    }
+
+    let BytesDe(output) = RawBytes::deserialize(return_data).unwrap();
+    output
+}
+
+/// Decodes the `Error(string)` payload of a reverted call, if there is one.
+pub fn revert_reason(res: &ApplyRet) -> Option<String> {
+    xfail::report::revert_reason(&return_bytes(&res.msg_receipt.return_data))
+}
+
+/// Decodes the `Panic(uint256)` code of a reverted call, if it panicked.
+pub fn panic_code(res: &ApplyRet) -> Option<u64> {
+    let output = return_bytes(&res.msg_receipt.return_data);
+    if output.len() < 4 || output[..4] != PANIC_SELECTOR {
+        return None;
+    }
+
+    ethabi::decode(&[ParamType::Uint(256)], &output[4..])
+        .ok()
+        .and_then(|tokens| tokens.into_iter().next())
+        .and_then(|token| token.into_uint())
+        .map(|code| code.low_u64())
+}
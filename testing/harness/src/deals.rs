@@ -0,0 +1,68 @@
+//! Deals for the market getters to read.
+//!
+//! Publishing deals needs signed proposals and a miner with power, so the proposals are
+//! written straight into the market state instead, before the machine is instantiated.
+
+use fil_actor_market::{DealArray, DealProposal, Label, State};
+use fil_actors_runtime::STORAGE_MARKET_ACTOR_ADDR;
+use fvm::state_tree::ActorState;
+use fvm_ipld_encoding::CborStore;
+use fvm_shared::address::Address;
+use fvm_shared::commcid::data_commitment_v1_to_cid;
+use fvm_shared::deal::DealID;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::piece::PaddedPieceSize;
+use multihash::Code;
+
+use crate::Harness;
+
+/// A 2KiB deal from `client` with a provider that doesn't exist, free of charge.
+pub fn deal_proposal(client: Address, label: String) -> DealProposal {
+    DealProposal {
+        piece_cid: data_commitment_v1_to_cid(&[0; 32]).unwrap(),
+        piece_size: PaddedPieceSize(2048),
+        verified_deal: false,
+        client,
+        provider: Address::new_id(1000),
+        label: Label::String(label),
+        start_epoch: 100,
+        end_epoch: 1000,
+        storage_price_per_epoch: TokenAmount::default(),
+        provider_collateral: TokenAmount::default(),
+        client_collateral: TokenAmount::default(),
+    }
+}
+
+/// Stores `proposals` as deals `0..proposals.len()`. Must be called before
+/// [`Harness::instantiate`].
+pub fn insert_deals(harness: &mut Harness, proposals: Vec<DealProposal>) {
+    let market_id = STORAGE_MARKET_ACTOR_ADDR.id().unwrap();
+
+    let state_tree = harness.tester.state_tree.as_mut().unwrap();
+    let actor = state_tree.get_actor(market_id).unwrap().unwrap();
+
+    let state_cid = {
+        let store = state_tree.store();
+        let mut state: State = store.get_cbor(&actor.state).unwrap().unwrap();
+
+        let mut deals = DealArray::load(&state.proposals, store).unwrap();
+        let count = proposals.len() as DealID;
+        for (id, proposal) in (0..count).zip(proposals) {
+            deals.set(id, proposal).unwrap();
+        }
+        state.proposals = deals.flush().unwrap();
+        state.next_id = count;
+
+        store.put_cbor(&state, Code::Blake2b256).unwrap()
+    };
+
+    state_tree
+        .set_actor(
+            market_id,
+            ActorState {
+                state: state_cid,
+                ..actor
+            },
+        )
+        .unwrap();
+}
@@ -0,0 +1,191 @@
+//! CBOR diagnostic notation (RFC 8949, section 8) of actor params and returns, for test
+//! output.
+//!
+//! Payloads of the methods in the [`schema`] are shown with the names of their types,
+//! fields and enum variants, e.g. `WithdrawBalanceParams{provider_or_client: f0101,
+//! amount: 12222}`, and with addresses, BigInts and CIDs written out as usual instead of
+//! as byte strings. Anything else is shown in plain diagnostic notation, e.g.
+//! `[h'0102', "label"]`. A payload that doesn't match its type is followed by what is
+//! wrong with it.
+
+use cid::Cid;
+use fil_actors_runtime::{
+    DATACAP_TOKEN_ACTOR_ADDR, INIT_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR,
+    VERIFIED_REGISTRY_ACTOR_ADDR,
+};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::{BigInt, Sign};
+use fvm_shared::MethodNum;
+use schema::cbor::{self, Item};
+use schema::{Encoding, Method, Schema, Type};
+
+use crate::methods;
+
+/// Tag used by dag-cbor to encode CIDs.
+const CID_TAG: u64 = 42;
+
+/// Actors with a single instance. Methods of the others (miners, accounts, multisigs) are
+/// told apart by which payload they match.
+const SINGLETONS: [(&str, Address); 5] = [
+    ("Market", STORAGE_MARKET_ACTOR_ADDR),
+    ("Power", STORAGE_POWER_ACTOR_ADDR),
+    ("VerifReg", VERIFIED_REGISTRY_ACTOR_ADDR),
+    ("DataCap", DATACAP_TOKEN_ACTOR_ADDR),
+    ("Init", INIT_ACTOR_ADDR),
+];
+
+/// `item` in plain diagnostic notation.
+pub fn notation(item: &Item) -> String {
+    match item {
+        Item::Uint(value) => value.to_string(),
+        Item::Negative(value) => (-1 - i128::from(*value)).to_string(),
+        Item::Bytes(bytes) => format!("h'{}'", hex::encode(bytes)),
+        Item::Text(text) => format!("{:?}", text),
+        Item::Array(items) => format!("[{}]", join(items.iter().map(notation))),
+        Item::Map(entries) => format!(
+            "{{{}}}",
+            join(entries.iter().map(|(key, value)| format!(
+                "{}: {}",
+                notation(key),
+                notation(value)
+            )))
+        ),
+        Item::Tag(tag, item) => format!("{}({})", tag, notation(item)),
+        Item::Bool(value) => value.to_string(),
+        Item::Null => "null".to_string(),
+        Item::Undefined => "undefined".to_string(),
+        Item::Float(value) if value.is_nan() => "NaN".to_string(),
+        Item::Float(value) if value.is_infinite() && *value > 0.0 => "Infinity".to_string(),
+        Item::Float(value) if value.is_infinite() => "-Infinity".to_string(),
+        Item::Float(value) => format!("{:?}", value),
+        Item::Simple(value) => format!("simple({})", value),
+    }
+}
+
+fn join(parts: impl Iterator<Item = String>) -> String {
+    parts.collect::<Vec<_>>().join(", ")
+}
+
+/// The decimal value of a serialized BigInt, if it is one.
+fn bigint(bytes: &[u8]) -> Option<String> {
+    let sign = match bytes.first() {
+        None => return Some("0".to_string()),
+        Some(0) => Sign::Plus,
+        Some(1) => Sign::Minus,
+        Some(_) => return None,
+    };
+    Some(BigInt::from_bytes_be(sign, &bytes[1..]).to_string())
+}
+
+fn cid(item: &Item) -> Option<String> {
+    match item {
+        // Behind the multibase identity prefix
+        Item::Bytes(bytes) if bytes.first() == Some(&0) => {
+            Cid::try_from(&bytes[1..]).ok().map(|cid| cid.to_string())
+        }
+        _ => None,
+    }
+}
+
+/// `item` as a `ty`, falling back to plain notation for the parts that aren't.
+pub fn typed(schema: &Schema, ty: &Type, item: &Item) -> String {
+    let shown = match (ty, item) {
+        (Type::Optional(_), Item::Null) => None,
+        (Type::Optional(value), _) => Some(typed(schema, value, item)),
+
+        (Type::BigInt, Item::Bytes(bytes)) => bigint(bytes),
+        (Type::Address, Item::Bytes(bytes)) => Address::from_bytes(bytes)
+            .ok()
+            .map(|address| address.to_string()),
+        (Type::Cid, Item::Tag(CID_TAG, inner)) => cid(inner),
+
+        (Type::Array(item_type), Item::Array(items)) => Some(format!(
+            "[{}]",
+            join(items.iter().map(|item| typed(schema, item_type, item)))
+        )),
+
+        (Type::Enum(name), Item::Uint(code)) => schema
+            .find_enum(name)
+            .and_then(|e| e.variant(*code))
+            .map(|variant| variant.name.to_string()),
+
+        (Type::Struct(name), _) => schema.find_struct(name).and_then(|s| {
+            let items = match (s.encoding, item) {
+                (Encoding::Transparent, _) => std::slice::from_ref(item),
+                (Encoding::Tuple, Item::Array(items)) if items.len() == s.fields.len() => {
+                    items.as_slice()
+                }
+                (Encoding::Tuple, _) => return None,
+            };
+            let fields =
+                s.fields.iter().zip(items).map(|(field, item)| {
+                    format!("{}: {}", field.name, typed(schema, &field.ty, item))
+                });
+            Some(format!("{}{{{}}}", s.name, join(fields)))
+        }),
+
+        _ => None,
+    };
+
+    shown.unwrap_or_else(|| notation(item))
+}
+
+/// The schema methods `method` can be when sent to `to`.
+fn candidates<'a>(schema: &'a Schema, to: &Address, method: MethodNum) -> Vec<&'a Method> {
+    let name = methods::name(to, method);
+    let singleton = SINGLETONS
+        .iter()
+        .find(|(_, address)| address == to)
+        .map(|(actor, _)| *actor);
+
+    schema
+        .methods
+        .iter()
+        .filter(|m| m.name == name)
+        .filter(|m| match singleton {
+            Some(actor) => m.actor == actor,
+            None => SINGLETONS.iter().all(|(actor, _)| m.actor != *actor),
+        })
+        .collect()
+}
+
+/// `data` as the first of `types` it matches, or as the first one with what is wrong.
+fn render(schema: &Schema, types: &[&Type], data: &[u8]) -> String {
+    if data.is_empty() {
+        return "empty".to_string();
+    }
+
+    let item = match cbor::decode(data) {
+        Ok(item) => item,
+        Err(err) => return format!("invalid CBOR 0x{} ({})", hex::encode(data), err),
+    };
+
+    let ty = match types
+        .iter()
+        .find(|ty| schema.validate_item(ty, &item, "").is_ok())
+        .or_else(|| types.first())
+    {
+        Some(ty) => ty,
+        None => return notation(&item),
+    };
+
+    let shown = typed(schema, ty, &item);
+    match schema.validate_item(ty, &item, "") {
+        Ok(()) => shown,
+        Err(invalid) => format!("{} (invalid {})", shown, invalid),
+    }
+}
+
+/// The params `data` of `method` sent to `to`.
+pub fn params(schema: &Schema, to: &Address, method: MethodNum, data: &[u8]) -> String {
+    let methods = candidates(schema, to, method);
+    let types: Vec<&Type> = methods.iter().filter_map(|m| m.params.as_ref()).collect();
+    render(schema, &types, data)
+}
+
+/// The return `data` of `method` sent to `to`.
+pub fn ret(schema: &Schema, to: &Address, method: MethodNum, data: &[u8]) -> String {
+    let methods = candidates(schema, to, method);
+    let types: Vec<&Type> = methods.iter().filter_map(|m| m.ret.as_ref()).collect();
+    render(schema, &types, data)
+}
@@ -0,0 +1,414 @@
+//! Differential runner: every API wrapper is called through its solidity contract and,
+//! on an identical copy of the state, directly on the native actor it proxies to.
+//!
+//! The native call is sent as an implicit message from the contract's own address, so
+//! the actor sees the same caller in both worlds. The native return is then mapped onto
+//! the wrapper's ABI outputs and compared with what the contract returned.
+
+use std::fmt::Debug;
+
+use ciborium::value::Value;
+use ethabi::{ParamType, Token};
+use fvm::executor::ApplyRet;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::MethodNum;
+use schema::Schema;
+
+use crate::checks::Results;
+use crate::contract::{revert_reason, Contract};
+use crate::diagnostic;
+use crate::report::{Status, TestCase};
+use crate::setup::Harness;
+
+/// Tag used by dag-cbor to encode CIDs.
+const CID_TAG: u64 = 42;
+
+/// The same logical request, expressed once for the solidity wrapper and once for the
+/// native actor.
+pub struct Case {
+    pub function: &'static str,
+    pub args: Vec<Token>,
+    pub to: Address,
+    pub method: MethodNum,
+    pub params: RawBytes,
+    pub value: TokenAmount,
+    /// Fields of the returned struct that legitimately differ between both worlds.
+    pub ignored: Vec<usize>,
+}
+
+impl Case {
+    pub fn new(function: &'static str, args: Vec<Token>) -> Self {
+        Case {
+            function,
+            args,
+            to: Address::new_id(0),
+            method: 0,
+            params: RawBytes::default(),
+            value: TokenAmount::default(),
+            ignored: vec![],
+        }
+    }
+
+    pub fn native(mut self, to: Address, method: MethodNum, params: RawBytes) -> Self {
+        self.to = to;
+        self.method = method;
+        self.params = params;
+        self
+    }
+
+    pub fn value(mut self, value: TokenAmount) -> Self {
+        self.value = value;
+        self
+    }
+
+    pub fn ignore(mut self, field: usize) -> Self {
+        self.ignored.push(field);
+        self
+    }
+}
+
+pub struct Differential {
+    pub api: Harness,
+    pub native: Harness,
+    pub contract: Contract,
+    results: Vec<(&'static str, Result<(), String>)>,
+    cases: Vec<TestCase>,
+}
+
+impl Differential {
+    /// Runs `setup` twice to build two identical worlds. `setup` must be deterministic:
+    /// the contract and whatever context the cases refer to (e.g. a miner address) have
+    /// to be the same in both.
+    pub fn new<C, F>(setup: F) -> (Self, C)
+    where
+        C: PartialEq + Debug,
+        F: Fn() -> (Harness, Contract, C),
+    {
+        let (api, contract, context) = setup();
+        let (native, native_contract, native_context) = setup();
+
+        assert_eq!(
+            contract.address, native_contract.address,
+            "both worlds need to be identical"
+        );
+        assert_eq!(context, native_context, "both worlds need to be identical");
+
+        let differential = Differential {
+            api,
+            native,
+            contract,
+            results: vec![],
+            cases: vec![],
+        };
+
+        (differential, context)
+    }
+
+    pub fn sender(&self) -> Address {
+        self.api.accounts[0].1
+    }
+
+    pub fn run(&mut self, case: Case) {
+        println!("Comparing `{}`", case.function);
+
+        let function = case.function;
+        let (_, outcome) = self.compare(case);
+        if let Err(difference) = &outcome {
+            println!("  DIFFERENCE: {}", difference);
+        }
+
+        let status = match &outcome {
+            Ok(()) => Status::Pass,
+            Err(difference) => Status::Fail(difference.clone()),
+        };
+        let call = self.api.calls().last().unwrap().clone();
+        self.cases.push(TestCase::call(call, status));
+
+        self.results.push((function, outcome));
+    }
+
+    /// The outcome of every case run so far, as [`crate::checks`] records them.
+    pub fn results(&self) -> Results {
+        self.results
+            .iter()
+            .map(|(function, outcome)| (function.to_string(), outcome.clone()))
+            .collect()
+    }
+
+    /// Every case run so far, with the API call it made, see [`crate::report`].
+    pub fn cases(&self) -> &[TestCase] {
+        &self.cases
+    }
+
+    /// Runs `case` in both worlds without recording the outcome. The native result is
+    /// returned too, for callers that look at what the actor sent back.
+    pub fn compare(&mut self, case: Case) -> (ApplyRet, Result<(), String>) {
+        let sender = self.sender();
+        let api_res = self.api.call(
+            sender,
+            &self.contract,
+            case.function,
+            &case.args,
+            case.value.clone(),
+        );
+
+        if !case.value.is_zero() {
+            // The API call takes the value from the sender through the contract
+            self.native.send(
+                sender,
+                self.contract.address,
+                0,
+                RawBytes::default(),
+                case.value.clone(),
+            );
+        }
+
+        let native_res = self.native.send_implicit(
+            self.contract.address,
+            case.to,
+            case.method,
+            case.params.clone(),
+            case.value.clone(),
+        );
+
+        let outcome = compare(&self.contract, &case, &api_res, &native_res)
+            .map_err(|difference| with_native_payloads(difference, &case, &native_res));
+        if outcome.is_err() && !api_res.exec_trace.is_empty() {
+            print!("{}", self.api.call_tree(&api_res));
+        }
+
+        (native_res, outcome)
+    }
+
+    /// Prints a summary and returns the number of cases that differ.
+    pub fn report(&self) -> usize {
+        let differences: Vec<_> = self
+            .results
+            .iter()
+            .filter(|(_, outcome)| outcome.is_err())
+            .collect();
+
+        println!(
+            "{} calls compared, {} differences",
+            self.results.len(),
+            differences.len()
+        );
+        for (function, outcome) in &differences {
+            println!("  {}: {}", function, outcome.as_ref().unwrap_err());
+        }
+
+        differences.len()
+    }
+}
+
+fn compare(
+    contract: &Contract,
+    case: &Case,
+    api_res: &ApplyRet,
+    native_res: &ApplyRet,
+) -> Result<(), String> {
+    let api_code = api_res.msg_receipt.exit_code.value();
+    let native_code = native_res.msg_receipt.exit_code.value();
+
+    if native_code != 0 {
+        if api_code == 0 {
+            return Err(format!(
+                "native call failed with exit code {} but the API call succeeded",
+                native_code
+            ));
+        }
+
+        // Actor.readRespData reverts with the exit code of the inner call
+        let expected = format!("actor error code {}", native_code);
+        let reason = revert_reason(api_res);
+        if reason.as_deref() != Some(expected.as_str()) {
+            return Err(format!(
+                "native call failed with exit code {} but the API call reverted with {:?}",
+                native_code, reason
+            ));
+        }
+
+        return Ok(());
+    }
+
+    if api_code != 0 {
+        return Err(format!(
+            "native call succeeded but the API call failed with exit code {} ({:?})",
+            api_code,
+            revert_reason(api_res)
+        ));
+    }
+
+    let outputs: Vec<ParamType> = contract
+        .function(case.function, &case.args)
+        .outputs
+        .iter()
+        .map(|p| p.kind.clone())
+        .collect();
+
+    let mut expected = expected_tokens(&outputs, &native_res.msg_receipt.return_data)?;
+    let mut actual = contract.decode(case.function, api_res);
+
+    for tokens in [&mut expected, &mut actual] {
+        if let Some(Token::Tuple(fields)) = tokens.first_mut() {
+            for field in &case.ignored {
+                if let Some(token) = fields.get_mut(*field) {
+                    *token = Token::Bool(false);
+                }
+            }
+        }
+    }
+
+    if expected != actual {
+        return Err(format!("expected {:?}, got {:?}", expected, actual));
+    }
+
+    Ok(())
+}
+
+/// Adds what the native call sent and got back to `difference`, in diagnostic notation.
+fn with_native_payloads(difference: String, case: &Case, native_res: &ApplyRet) -> String {
+    let schema = Schema::new();
+    let params = diagnostic::params(&schema, &case.to, case.method, case.params.bytes());
+    let ret = diagnostic::ret(
+        &schema,
+        &case.to,
+        case.method,
+        native_res.msg_receipt.return_data.bytes(),
+    );
+
+    format!(
+        "{}\n    native params: {}\n    native return: {}",
+        difference, params, ret
+    )
+}
+
+/// Maps the CBOR returned by the native actor onto the ABI outputs of the wrapper.
+pub fn expected_tokens(outputs: &[ParamType], return_data: &RawBytes) -> Result<Vec<Token>, String> {
+    if outputs.is_empty() {
+        return Ok(vec![]);
+    }
+
+    if return_data.is_empty() {
+        return Err(format!("native call returned nothing, expected {:?}", outputs));
+    }
+
+    let value: Value = ciborium::de::from_reader(return_data.bytes())
+        .map_err(|e| format!("native return is not valid CBOR: {}", e))?;
+
+    match outputs {
+        [output] => Ok(vec![cbor_to_token(&value, output)?]),
+        _ => match value {
+            Value::Array(items) if items.len() == outputs.len() => items
+                .iter()
+                .zip(outputs)
+                .map(|(item, output)| cbor_to_token(item, output))
+                .collect(),
+            value => Err(format!("cannot map CBOR {:?} to {:?}", value, outputs)),
+        },
+    }
+}
+
+/// Converts a native CBOR value into the token the solidity side is expected to produce.
+pub fn cbor_to_token(value: &Value, param: &ParamType) -> Result<Token, String> {
+    match (param, value) {
+        // Optional fields are null on the native side and zeroed on the solidity side
+        (_, Value::Null) => Ok(zero_token(param)),
+
+        (ParamType::Tuple(types), Value::Array(items)) if types.len() == items.len() => items
+            .iter()
+            .zip(types)
+            .map(|(item, kind)| cbor_to_token(item, kind))
+            .collect::<Result<_, _>>()
+            .map(Token::Tuple),
+        // Single field structs wrap transparent native types
+        (ParamType::Tuple(types), value) if types.len() == 1 => {
+            Ok(Token::Tuple(vec![cbor_to_token(value, &types[0])?]))
+        }
+
+        (ParamType::Array(kind), Value::Array(items)) => items
+            .iter()
+            .map(|item| cbor_to_token(item, kind))
+            .collect::<Result<_, _>>()
+            .map(Token::Array),
+        (ParamType::FixedArray(kind, len), Value::Array(items)) if *len == items.len() => items
+            .iter()
+            .map(|item| cbor_to_token(item, kind))
+            .collect::<Result<_, _>>()
+            .map(Token::FixedArray),
+
+        (ParamType::Bytes, Value::Bytes(bytes)) => Ok(Token::Bytes(bytes.clone())),
+        (ParamType::Bytes, Value::Tag(CID_TAG, inner)) => match inner.as_ref() {
+            // Strip the multibase prefix dag-cbor puts in front of the CID
+            Value::Bytes(bytes) if !bytes.is_empty() => Ok(Token::Bytes(bytes[1..].to_vec())),
+            inner => Err(format!("invalid CID {:?}", inner)),
+        },
+        (ParamType::String, Value::Text(text)) => Ok(Token::String(text.clone())),
+        (ParamType::Bool, Value::Bool(b)) => Ok(Token::Bool(*b)),
+
+        (ParamType::Uint(_), Value::Integer(i)) => {
+            let i = i128::from(*i);
+            if i < 0 {
+                return Err(format!("negative value {} for {:?}", i, param));
+            }
+            Ok(Token::Uint((i as u128).into()))
+        }
+        (ParamType::Int(_), Value::Integer(i)) => Ok(Token::Int(int_to_u256(i128::from(*i)))),
+
+        // BigInt / TokenAmount: sign byte followed by the big endian magnitude
+        (ParamType::Uint(_), Value::Bytes(bytes)) => match bigint(bytes)? {
+            (false, magnitude) => Ok(Token::Uint(magnitude)),
+            (true, _) => Err(format!("negative big int for {:?}", param)),
+        },
+        (ParamType::Int(_), Value::Bytes(bytes)) => match bigint(bytes)? {
+            (false, magnitude) => Ok(Token::Int(magnitude)),
+            (true, magnitude) => Ok(Token::Int((!magnitude).overflowing_add(1.into()).0)),
+        },
+
+        (param, value) => Err(format!("cannot map CBOR {:?} to {:?}", value, param)),
+    }
+}
+
+fn int_to_u256(i: i128) -> ethabi::Uint {
+    if i >= 0 {
+        (i as u128).into()
+    } else {
+        let magnitude: ethabi::Uint = i.unsigned_abs().into();
+        (!magnitude).overflowing_add(1.into()).0
+    }
+}
+
+/// Splits a serialized BigInt into (is_negative, magnitude).
+pub fn bigint(bytes: &[u8]) -> Result<(bool, ethabi::Uint), String> {
+    if bytes.is_empty() {
+        return Ok((false, 0.into()));
+    }
+
+    let magnitude = &bytes[1..];
+    if magnitude.len() > 32 {
+        return Err(format!("big int of {} bytes does not fit 256 bits", magnitude.len()));
+    }
+
+    match bytes[0] {
+        0 => Ok((false, ethabi::Uint::from_big_endian(magnitude))),
+        1 => Ok((true, ethabi::Uint::from_big_endian(magnitude))),
+        sign => Err(format!("invalid big int sign byte {:#x}", sign)),
+    }
+}
+
+fn zero_token(param: &ParamType) -> Token {
+    match param {
+        ParamType::Address => Token::Address(Default::default()),
+        ParamType::Bytes => Token::Bytes(vec![]),
+        ParamType::FixedBytes(len) => Token::FixedBytes(vec![0; *len]),
+        ParamType::Int(_) => Token::Int(0.into()),
+        ParamType::Uint(_) => Token::Uint(0.into()),
+        ParamType::Bool => Token::Bool(false),
+        ParamType::String => Token::String(String::new()),
+        ParamType::Array(_) => Token::Array(vec![]),
+        ParamType::FixedArray(kind, len) => Token::FixedArray(vec![zero_token(kind); *len]),
+        ParamType::Tuple(types) => Token::Tuple(types.iter().map(zero_token).collect()),
+    }
+}
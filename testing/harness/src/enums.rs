@@ -0,0 +1,114 @@
+//! Mappings between the `fvm_shared` proof types and sector sizes and their ordinals in the
+//! `CommonTypes.sol` enums.
+//!
+//! The solidity enums are passed to the API contracts as their ordinal, which is not
+//! always the code the actors use: `RegisteredSealProof` declares 512MiB before 8MiB, and
+//! `SectorSize` is encoded as the size in bytes. The trailing `Invalid` variant of the
+//! proof enums has no counterpart here.
+
+use fvm_shared::sector::{
+    RegisteredPoStProof, RegisteredSealProof, RegisteredUpdateProof, SectorSize,
+};
+
+/// Proof types with their ordinal in `CommonTypes.RegisteredSealProof`.
+pub const SEAL_PROOFS: [(RegisteredSealProof, u64); 10] = [
+    (RegisteredSealProof::StackedDRG2KiBV1, 0),
+    (RegisteredSealProof::StackedDRG512MiBV1, 1),
+    (RegisteredSealProof::StackedDRG8MiBV1, 2),
+    (RegisteredSealProof::StackedDRG32GiBV1, 3),
+    (RegisteredSealProof::StackedDRG64GiBV1, 4),
+    (RegisteredSealProof::StackedDRG2KiBV1P1, 5),
+    (RegisteredSealProof::StackedDRG512MiBV1P1, 6),
+    (RegisteredSealProof::StackedDRG8MiBV1P1, 7),
+    (RegisteredSealProof::StackedDRG32GiBV1P1, 8),
+    (RegisteredSealProof::StackedDRG64GiBV1P1, 9),
+];
+
+/// Proof types with their ordinal in `CommonTypes.RegisteredPoStProof`.
+pub const POST_PROOFS: [(RegisteredPoStProof, u64); 10] = [
+    (RegisteredPoStProof::StackedDRGWinning2KiBV1, 0),
+    (RegisteredPoStProof::StackedDRGWinning8MiBV1, 1),
+    (RegisteredPoStProof::StackedDRGWinning512MiBV1, 2),
+    (RegisteredPoStProof::StackedDRGWinning32GiBV1, 3),
+    (RegisteredPoStProof::StackedDRGWinning64GiBV1, 4),
+    (RegisteredPoStProof::StackedDRGWindow2KiBV1, 5),
+    (RegisteredPoStProof::StackedDRGWindow8MiBV1, 6),
+    (RegisteredPoStProof::StackedDRGWindow512MiBV1, 7),
+    (RegisteredPoStProof::StackedDRGWindow32GiBV1, 8),
+    (RegisteredPoStProof::StackedDRGWindow64GiBV1, 9),
+];
+
+/// Proof types with their ordinal in `CommonTypes.RegisteredUpdateProof`.
+pub const UPDATE_PROOFS: [(RegisteredUpdateProof, u64); 5] = [
+    (RegisteredUpdateProof::StackedDRG2KiBV1, 0),
+    (RegisteredUpdateProof::StackedDRG8MiBV1, 1),
+    (RegisteredUpdateProof::StackedDRG512MiBV1, 2),
+    (RegisteredUpdateProof::StackedDRG32GiBV1, 3),
+    (RegisteredUpdateProof::StackedDRG64GiBV1, 4),
+];
+
+/// Sector sizes with their ordinal in `CommonTypes.SectorSize`.
+pub const SECTOR_SIZES: [(SectorSize, u64); 5] = [
+    (SectorSize::_2KiB, 0),
+    (SectorSize::_8MiB, 1),
+    (SectorSize::_512MiB, 2),
+    (SectorSize::_32GiB, 3),
+    (SectorSize::_64GiB, 4),
+];
+
+fn ordinal<T: PartialEq>(table: &[(T, u64)], value: &T) -> Option<u64> {
+    table
+        .iter()
+        .find(|(variant, _)| variant == value)
+        .map(|(_, ordinal)| *ordinal)
+}
+
+fn variant<T: Copy>(table: &[(T, u64)], ordinal: u64) -> Option<T> {
+    table
+        .iter()
+        .find(|(_, o)| *o == ordinal)
+        .map(|(variant, _)| *variant)
+}
+
+/// `None` for `Invalid` proofs.
+pub fn seal_proof_ordinal(proof: RegisteredSealProof) -> Option<u64> {
+    ordinal(&SEAL_PROOFS, &proof)
+}
+
+pub fn seal_proof(ordinal: u64) -> Option<RegisteredSealProof> {
+    variant(&SEAL_PROOFS, ordinal)
+}
+
+/// `None` for `Invalid` proofs.
+pub fn post_proof_ordinal(proof: RegisteredPoStProof) -> Option<u64> {
+    ordinal(&POST_PROOFS, &proof)
+}
+
+pub fn post_proof(ordinal: u64) -> Option<RegisteredPoStProof> {
+    variant(&POST_PROOFS, ordinal)
+}
+
+/// `None` for `Invalid` proofs.
+pub fn update_proof_ordinal(proof: RegisteredUpdateProof) -> Option<u64> {
+    ordinal(&UPDATE_PROOFS, &proof)
+}
+
+pub fn update_proof(ordinal: u64) -> Option<RegisteredUpdateProof> {
+    variant(&UPDATE_PROOFS, ordinal)
+}
+
+pub fn sector_size_ordinal(size: SectorSize) -> u64 {
+    ordinal(&SECTOR_SIZES, &size).unwrap()
+}
+
+pub fn sector_size(ordinal: u64) -> Option<SectorSize> {
+    variant(&SECTOR_SIZES, ordinal)
+}
+
+/// The size in bytes, as `GetSectorSizeReturn` and the actors encode it.
+pub fn sector_size_from_bytes(bytes: u64) -> Option<SectorSize> {
+    SECTOR_SIZES
+        .iter()
+        .map(|(size, _)| *size)
+        .find(|size| *size as u64 == bytes)
+}
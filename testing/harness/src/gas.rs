@@ -0,0 +1,152 @@
+//! Gas used by contract methods.
+//!
+//! [`crate::Harness::call`] records the `gas_used` of every call in a [`GasReport`], per
+//! contract and function. The most any call to a function used is compared against a
+//! committed [`Snapshot`], so that a change in the solidity libraries that makes calls
+//! more expensive shows up in the test run rather than in users' fees.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+/// First lines of a saved [`Snapshot`].
+const SNAPSHOT_HEADER: &str = "\
+# Most gas used by any call to each API method, checked by the differential suite.
+# Regenerate with `UPDATE_GAS_SNAPSHOT=1 make test_differential_integration`.
+# contract,function,gas
+";
+
+/// `(contract, function)`, e.g. `("MarketAPI", "get_balance")`.
+pub type Method = (String, String);
+
+#[derive(Debug, Clone, Default)]
+pub struct GasReport(pub BTreeMap<Method, Vec<i64>>);
+
+impl GasReport {
+    pub fn record(&mut self, contract: &str, function: &str, gas_used: i64) {
+        self.0
+            .entry((contract.to_string(), function.to_string()))
+            .or_default()
+            .push(gas_used);
+    }
+
+    pub fn merge(&mut self, other: &GasReport) {
+        for (method, calls) in &other.0 {
+            self.0.entry(method.clone()).or_default().extend(calls);
+        }
+    }
+
+    /// One table per contract, with the calls made to each function.
+    pub fn table(&self) -> String {
+        let mut out = String::new();
+        let mut contract = "";
+
+        for ((name, function), calls) in &self.0 {
+            if name != contract {
+                contract = name;
+                out.push_str(&format!(
+                    "{}\n  {:<40} {:>6} {:>12} {:>12} {:>12}\n",
+                    name, "function", "calls", "min", "max", "mean"
+                ));
+            }
+
+            let min = calls.iter().min().unwrap();
+            let max = calls.iter().max().unwrap();
+            let mean = calls.iter().sum::<i64>() / calls.len() as i64;
+            out.push_str(&format!(
+                "  {:<40} {:>6} {:>12} {:>12} {:>12}\n",
+                function,
+                calls.len(),
+                min,
+                max,
+                mean
+            ));
+        }
+
+        out
+    }
+
+    pub fn snapshot(&self) -> Snapshot {
+        Snapshot(
+            self.0
+                .iter()
+                .map(|(method, calls)| (method.clone(), *calls.iter().max().unwrap()))
+                .collect(),
+        )
+    }
+}
+
+/// Most gas any call to each method used, stored as `contract,function,gas` lines. Lines
+/// starting with `#` are comments.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snapshot(pub BTreeMap<Method, i64>);
+
+impl Snapshot {
+    /// `None` when there is no snapshot at `path` yet.
+    pub fn load(path: &Path) -> Option<Snapshot> {
+        let content = fs::read_to_string(path).ok()?;
+
+        let methods = content
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| {
+                let fields: Vec<&str> = line.split(',').collect();
+                match fields[..] {
+                    [contract, function, gas] => (
+                        (contract.to_string(), function.to_string()),
+                        gas.parse()
+                            .unwrap_or_else(|_| panic!("invalid gas in snapshot line {}", line)),
+                    ),
+                    _ => panic!("invalid snapshot line {}", line),
+                }
+            })
+            .collect();
+
+        Some(Snapshot(methods))
+    }
+
+    pub fn save(&self, path: &Path) {
+        let mut content = String::from(SNAPSHOT_HEADER);
+        content.extend(
+            self.0
+                .iter()
+                .map(|((contract, function), gas)| format!("{},{},{}\n", contract, function, gas)),
+        );
+
+        fs::write(path, content).expect("Unable to write gas snapshot");
+    }
+
+    /// Methods whose gas changed by more than `threshold` percent since `self`, in either
+    /// direction, as well as methods that appeared or disappeared.
+    pub fn changes(&self, current: &Snapshot, threshold: f64) -> Vec<String> {
+        let mut changes = vec![];
+
+        for (method, after) in &current.0 {
+            match self.0.get(method) {
+                Some(before) if before == after => {}
+                // No percentage of nothing, any gas at all is a change
+                Some(0) => changes.push(format!("{}.{}: 0 -> {}", method.0, method.1, after)),
+                Some(before) => {
+                    let change = (after - before) as f64 * 100.0 / *before as f64;
+                    if change.abs() > threshold {
+                        changes.push(format!(
+                            "{}.{}: {} -> {} ({:+.2}%)",
+                            method.0, method.1, before, after, change
+                        ));
+                    }
+                }
+                None => changes.push(format!("{}.{}: new, {}", method.0, method.1, after)),
+            }
+        }
+        for (method, before) in &self.0 {
+            if !current.0.contains_key(method) {
+                changes.push(format!(
+                    "{}.{}: no longer called, was {}",
+                    method.0, method.1, before
+                ));
+            }
+        }
+
+        changes
+    }
+}
@@ -0,0 +1,25 @@
+//! Shared setup for the integration binaries under `testing/`.
+//!
+//! Every suite loads the devnet bundle, creates a few accounts, deploys one or more
+//! solidity contracts through the EAM and then sends messages to them. [`Harness`]
+//! wraps those steps and [`Contract`] takes care of the ABI side of the calls.
+
+pub mod address;
+pub mod balances;
+pub mod checks;
+pub mod contract;
+pub mod deals;
+pub mod diagnostic;
+pub mod differential;
+pub mod enums;
+pub mod gas;
+pub mod method_nums;
+pub mod methods;
+pub mod precompile;
+pub mod report;
+pub mod setup;
+pub mod tokens;
+pub mod trace;
+
+pub use contract::Contract;
+pub use setup::Harness;
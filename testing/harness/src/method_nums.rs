@@ -0,0 +1,219 @@
+//! Method numbers of the builtin actor methods the Solidity APIs call.
+//!
+//! Generated by `script/src/generate.rs`, do not edit. Run `make generate_method_nums`.
+
+use fvm_shared::MethodNum;
+
+pub mod market {
+    use fvm_shared::MethodNum;
+
+    pub const ADD_BALANCE: MethodNum = 822473126;
+    pub const GET_BALANCE: MethodNum = 726108461;
+    pub const GET_DEAL_ACTIVATION: MethodNum = 2567238399;
+    pub const GET_DEAL_CLIENT: MethodNum = 128053329;
+    pub const GET_DEAL_CLIENT_COLLATERAL: MethodNum = 200567895;
+    pub const GET_DEAL_DATA_COMMITMENT: MethodNum = 1157985802;
+    pub const GET_DEAL_LABEL: MethodNum = 46363526;
+    pub const GET_DEAL_PROVIDER: MethodNum = 935081690;
+    pub const GET_DEAL_PROVIDER_COLLATERAL: MethodNum = 2986712137;
+    pub const GET_DEAL_TERM: MethodNum = 163777312;
+    pub const GET_DEAL_TOTAL_PRICE: MethodNum = 4287162428;
+    pub const GET_DEAL_VERIFIED: MethodNum = 2627389465;
+    pub const PUBLISH_STORAGE_DEALS: MethodNum = 2236929350;
+    pub const WITHDRAW_BALANCE: MethodNum = 2280458852;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("AddBalance", ADD_BALANCE),
+        ("GetBalance", GET_BALANCE),
+        ("GetDealActivation", GET_DEAL_ACTIVATION),
+        ("GetDealClient", GET_DEAL_CLIENT),
+        ("GetDealClientCollateral", GET_DEAL_CLIENT_COLLATERAL),
+        ("GetDealDataCommitment", GET_DEAL_DATA_COMMITMENT),
+        ("GetDealLabel", GET_DEAL_LABEL),
+        ("GetDealProvider", GET_DEAL_PROVIDER),
+        ("GetDealProviderCollateral", GET_DEAL_PROVIDER_COLLATERAL),
+        ("GetDealTerm", GET_DEAL_TERM),
+        ("GetDealTotalPrice", GET_DEAL_TOTAL_PRICE),
+        ("GetDealVerified", GET_DEAL_VERIFIED),
+        ("PublishStorageDeals", PUBLISH_STORAGE_DEALS),
+        ("WithdrawBalance", WITHDRAW_BALANCE),
+    ];
+}
+
+pub mod miner {
+    use fvm_shared::MethodNum;
+
+    pub const CHANGE_BENEFICIARY: MethodNum = 1570634796;
+    pub const CHANGE_MULTIADDRS: MethodNum = 1063480576;
+    pub const CHANGE_OWNER_ADDRESS: MethodNum = 1010589339;
+    pub const CHANGE_PEER_ID: MethodNum = 1236548004;
+    pub const CHANGE_WORKER_ADDRESS: MethodNum = 3302309124;
+    pub const CONFIRM_CHANGE_WORKER_ADDRESS: MethodNum = 2354970453;
+    pub const GET_AVAILABLE_BALANCE: MethodNum = 4026106874;
+    pub const GET_BENEFICIARY: MethodNum = 4158972569;
+    pub const GET_MULTIADDRS: MethodNum = 1332909407;
+    pub const GET_OWNER: MethodNum = 3275365574;
+    pub const GET_PEER_ID: MethodNum = 2812875329;
+    pub const GET_SECTOR_SIZE: MethodNum = 3858292296;
+    pub const GET_VESTING_FUNDS: MethodNum = 1726876304;
+    pub const IS_CONTROLLING_ADDRESS: MethodNum = 348244887;
+    pub const REPAY_DEBT: MethodNum = 3665352697;
+    pub const WITHDRAW_BALANCE: MethodNum = 2280458852;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("ChangeBeneficiary", CHANGE_BENEFICIARY),
+        ("ChangeMultiaddrs", CHANGE_MULTIADDRS),
+        ("ChangeOwnerAddress", CHANGE_OWNER_ADDRESS),
+        ("ChangePeerID", CHANGE_PEER_ID),
+        ("ChangeWorkerAddress", CHANGE_WORKER_ADDRESS),
+        ("ConfirmChangeWorkerAddress", CONFIRM_CHANGE_WORKER_ADDRESS),
+        ("GetAvailableBalance", GET_AVAILABLE_BALANCE),
+        ("GetBeneficiary", GET_BENEFICIARY),
+        ("GetMultiaddrs", GET_MULTIADDRS),
+        ("GetOwner", GET_OWNER),
+        ("GetPeerID", GET_PEER_ID),
+        ("GetSectorSize", GET_SECTOR_SIZE),
+        ("GetVestingFunds", GET_VESTING_FUNDS),
+        ("IsControllingAddress", IS_CONTROLLING_ADDRESS),
+        ("RepayDebt", REPAY_DEBT),
+        ("WithdrawBalance", WITHDRAW_BALANCE),
+    ];
+}
+
+pub mod power {
+    use fvm_shared::MethodNum;
+
+    pub const CREATE_MINER: MethodNum = 1173380165;
+    pub const MINER_CONSENSUS_COUNT: MethodNum = 196739875;
+    pub const MINER_COUNT: MethodNum = 1987646258;
+    pub const MINER_RAW_POWER: MethodNum = 3753401894;
+    pub const NETWORK_RAW_POWER: MethodNum = 931722534;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("CreateMiner", CREATE_MINER),
+        ("MinerConsensusCount", MINER_CONSENSUS_COUNT),
+        ("MinerCount", MINER_COUNT),
+        ("MinerRawPower", MINER_RAW_POWER),
+        ("NetworkRawPower", NETWORK_RAW_POWER),
+    ];
+}
+
+pub mod verifreg {
+    use fvm_shared::MethodNum;
+
+    pub const ADD_VERIFIED_CLIENT: MethodNum = 3916220144;
+    pub const EXTEND_CLAIM_TERMS: MethodNum = 1752273514;
+    pub const GET_CLAIMS: MethodNum = 2199871187;
+    pub const RECEIVE: MethodNum = 3726118371;
+    pub const REMOVE_EXPIRED_ALLOCATIONS: MethodNum = 2421068268;
+    pub const REMOVE_EXPIRED_CLAIMS: MethodNum = 2873373899;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("AddVerifiedClient", ADD_VERIFIED_CLIENT),
+        ("ExtendClaimTerms", EXTEND_CLAIM_TERMS),
+        ("GetClaims", GET_CLAIMS),
+        ("Receive", RECEIVE),
+        ("RemoveExpiredAllocations", REMOVE_EXPIRED_ALLOCATIONS),
+        ("RemoveExpiredClaims", REMOVE_EXPIRED_CLAIMS),
+    ];
+}
+
+pub mod datacap {
+    use fvm_shared::MethodNum;
+
+    pub const ALLOWANCE: MethodNum = 4205072950;
+    pub const BALANCE: MethodNum = 3261979605;
+    pub const BURN: MethodNum = 1434719642;
+    pub const BURN_FROM: MethodNum = 2979674018;
+    pub const DECREASE_ALLOWANCE: MethodNum = 1529376545;
+    pub const DESTROY: MethodNum = 2624896501;
+    pub const GRANULARITY: MethodNum = 3936767397;
+    pub const INCREASE_ALLOWANCE: MethodNum = 1777121560;
+    pub const MINT: MethodNum = 116935346;
+    pub const NAME: MethodNum = 48890204;
+    pub const REVOKE_ALLOWANCE: MethodNum = 2765635761;
+    pub const SYMBOL: MethodNum = 2061153854;
+    pub const TOTAL_SUPPLY: MethodNum = 114981429;
+    pub const TRANSFER: MethodNum = 80475954;
+    pub const TRANSFER_FROM: MethodNum = 3621052141;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("Allowance", ALLOWANCE),
+        ("Balance", BALANCE),
+        ("Burn", BURN),
+        ("BurnFrom", BURN_FROM),
+        ("DecreaseAllowance", DECREASE_ALLOWANCE),
+        ("Destroy", DESTROY),
+        ("Granularity", GRANULARITY),
+        ("IncreaseAllowance", INCREASE_ALLOWANCE),
+        ("Mint", MINT),
+        ("Name", NAME),
+        ("RevokeAllowance", REVOKE_ALLOWANCE),
+        ("Symbol", SYMBOL),
+        ("TotalSupply", TOTAL_SUPPLY),
+        ("Transfer", TRANSFER),
+        ("TransferFrom", TRANSFER_FROM),
+    ];
+}
+
+pub mod init {
+    use fvm_shared::MethodNum;
+
+    pub const EXEC: MethodNum = 81225168;
+    pub const EXEC4: MethodNum = 3;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[("Exec", EXEC), ("Exec4", EXEC4)];
+}
+
+pub mod account {
+    use fvm_shared::MethodNum;
+
+    pub const AUTHENTICATE_MESSAGE: MethodNum = 2643134072;
+    pub const RECEIVE: MethodNum = 3726118371;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("AuthenticateMessage", AUTHENTICATE_MESSAGE),
+        ("Receive", RECEIVE),
+    ];
+}
+
+pub mod multisig {
+    use fvm_shared::MethodNum;
+
+    pub const ADD_SIGNER: MethodNum = 3028530033;
+    pub const APPROVE: MethodNum = 1289044053;
+    pub const CANCEL: MethodNum = 3365893656;
+    pub const CHANGE_NUM_APPROVALS_THRESHOLD: MethodNum = 3375931653;
+    pub const LOCK_BALANCE: MethodNum = 1999470977;
+    pub const PROPOSE: MethodNum = 1696838335;
+    pub const RECEIVE: MethodNum = 3726118371;
+    pub const REMOVE_SIGNER: MethodNum = 21182899;
+    pub const SWAP_SIGNER: MethodNum = 3968117037;
+
+    pub const METHODS: &[(&str, MethodNum)] = &[
+        ("AddSigner", ADD_SIGNER),
+        ("Approve", APPROVE),
+        ("Cancel", CANCEL),
+        (
+            "ChangeNumApprovalsThreshold",
+            CHANGE_NUM_APPROVALS_THRESHOLD,
+        ),
+        ("LockBalance", LOCK_BALANCE),
+        ("Propose", PROPOSE),
+        ("Receive", RECEIVE),
+        ("RemoveSigner", REMOVE_SIGNER),
+        ("SwapSigner", SWAP_SIGNER),
+    ];
+}
+
+/// Every actor with its methods.
+pub const ACTORS: &[(&str, &[(&str, MethodNum)])] = &[
+    ("Market", market::METHODS),
+    ("Miner", miner::METHODS),
+    ("Power", power::METHODS),
+    ("VerifReg", verifreg::METHODS),
+    ("DataCap", datacap::METHODS),
+    ("Init", init::METHODS),
+    ("Account", account::METHODS),
+    ("Multisig", multisig::METHODS),
+];
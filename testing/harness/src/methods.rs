@@ -0,0 +1,48 @@
+//! Method names for test output.
+//!
+//! Exported methods are dispatched on their FRC-42 hash, listed for each actor in the
+//! generated [`crate::method_nums`]. The handful of methods below 2^24 are only
+//! meaningful together with the actor they are sent to.
+
+use fil_actors_runtime::{EAM_ACTOR_ADDR, INIT_ACTOR_ADDR};
+use fvm_shared::address::Address;
+use fvm_shared::MethodNum;
+
+use crate::method_nums::ACTORS;
+
+/// Exported methods are numbered from here on, see FRC-42.
+pub const FIRST_EXPORTED_METHOD: MethodNum = 1 << 24;
+
+/// Name of an exported builtin actor method. Names exported by several actors (e.g.
+/// `Receive`) hash to the same number.
+pub fn exported(method: MethodNum) -> Option<&'static str> {
+    if method < FIRST_EXPORTED_METHOD {
+        return None;
+    }
+
+    ACTORS
+        .iter()
+        .flat_map(|(_, methods)| methods.iter())
+        .find(|(_, number)| *number == method)
+        .map(|(name, _)| *name)
+}
+
+/// Name of `method` when sent to the builtin actor `to`, or the bare number when it isn't
+/// known. Contracts are not builtin actors, see [`crate::trace`] for those.
+pub fn name(to: &Address, method: MethodNum) -> String {
+    let known = match method {
+        0 => Some("Send"),
+        1 => Some("Constructor"),
+        2 if *to == INIT_ACTOR_ADDR => Some("Exec"),
+        3 if *to == INIT_ACTOR_ADDR => Some("Exec4"),
+        2 if *to == EAM_ACTOR_ADDR => Some("Create"),
+        3 if *to == EAM_ACTOR_ADDR => Some("Create2"),
+        4 if *to == EAM_ACTOR_ADDR => Some("CreateExternal"),
+        method => exported(method),
+    };
+
+    match known {
+        Some(name) => name.to_string(),
+        None => format!("method {}", method),
+    }
+}
@@ -0,0 +1,219 @@
+//! Layout of the input `Actor.call` builds for the call actor precompile at `0x0e`.
+//!
+//! Six words (method, value, flags, codec, address length and params length) are followed
+//! by the params and then by the address bytes, all of which `Actor.call` hands the
+//! precompile. [`verify`] checks a raw input, and [`differences`] compares the call an
+//! execution trace shows reaching the actor with the one that was meant. The output is
+//! fixed: return data beyond [`MAX_RETURN_SIZE`] does not fit the response buffer.
+
+use ethabi::{Token, Uint};
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::MethodNum;
+
+/// Exposes `Actor.call` with any flags, see [`crate::Harness::call_actor`].
+pub const ACTOR_CALL_TEST_ARTIFACT: &str = "../../build/v0.8/tests/ActorCallTest";
+
+pub const CALL_ACTOR_PRECOMPILE_ADDR: u8 = 0x0e;
+
+/// Size of the buffer `Actor.call` gives the precompile for its output.
+pub const MAX_RAW_RESPONSE_SIZE: usize = 0x300;
+
+/// Return data that fits the output buffer after the words `Actor.readRespData` skips.
+pub const MAX_RETURN_SIZE: usize = MAX_RAW_RESPONSE_SIZE - 0x80;
+
+/// Gas `Actor.call` gives the `delegatecall`, `Actor.GAS_LIMIT`.
+pub const ACTOR_GAS_LIMIT: i64 = 100000000;
+
+pub const DEFAULT_FLAG: u64 = 0;
+pub const READ_ONLY_FLAG: u64 = 1;
+
+/// The only codec the precompile accepts for params.
+pub const DAG_CBOR: u64 = 0x71;
+
+const WORD: usize = 32;
+
+/// Method, value, flags, codec, address length and params length.
+const HEADER_SIZE: usize = 6 * WORD;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallActorInput {
+    pub method: u64,
+    pub value: Uint,
+    pub flags: u64,
+    pub codec: u64,
+    pub params: Vec<u8>,
+    pub address: Vec<u8>,
+}
+
+impl CallActorInput {
+    /// Input `Actor.call` is expected to build for a call to `address`.
+    pub fn new(method: u64, value: Uint, params: Vec<u8>, address: Vec<u8>) -> Self {
+        CallActorInput {
+            method,
+            value,
+            flags: DEFAULT_FLAG,
+            codec: DAG_CBOR,
+            params,
+            address,
+        }
+    }
+
+    /// Bytes the precompile has to read to get the whole call.
+    pub fn size(&self) -> usize {
+        HEADER_SIZE + self.params.len() + self.address.len()
+    }
+}
+
+fn word(input: &[u8], index: usize) -> &[u8] {
+    &input[index * WORD..(index + 1) * WORD]
+}
+
+/// Reads a word holding an integer of at most `bytes` bytes.
+fn small_word(input: &[u8], index: usize, name: &str, bytes: usize) -> Result<u64, String> {
+    let word = word(input, index);
+    let (high, low) = word.split_at(WORD - bytes);
+
+    if high.iter().any(|byte| *byte != 0) {
+        return Err(format!(
+            "{} word 0x{} does not fit {} bytes",
+            name,
+            hex::encode(word),
+            bytes
+        ));
+    }
+
+    Ok(low.iter().fold(0, |acc, byte| (acc << 8) | *byte as u64))
+}
+
+/// Decodes the whole `input`, regardless of how much of it the precompile would get.
+pub fn decode(input: &[u8]) -> Result<CallActorInput, String> {
+    if input.len() < HEADER_SIZE {
+        return Err(format!(
+            "{} bytes input has no room for the header",
+            input.len()
+        ));
+    }
+
+    let method = small_word(input, 0, "method", 8)?;
+    let value = Uint::from_big_endian(word(input, 1));
+    let flags = small_word(input, 2, "flags", 8)?;
+    let codec = small_word(input, 3, "codec", 8)?;
+    let address_len = small_word(input, 4, "address length", 4)? as usize;
+    let params_len = small_word(input, 5, "params length", 4)? as usize;
+
+    let params_end = HEADER_SIZE + params_len;
+    let address_end = params_end + address_len;
+    if address_end > input.len() {
+        return Err(format!(
+            "{} bytes of params and {} bytes of address run past the {} bytes input",
+            params_len,
+            address_len,
+            input.len()
+        ));
+    }
+
+    Ok(CallActorInput {
+        method,
+        value,
+        flags,
+        codec,
+        params: input[HEADER_SIZE..params_end].to_vec(),
+        address: input[params_end..address_end].to_vec(),
+    })
+}
+
+/// Fields of `actual` that differ from `expected`, one line each.
+pub fn differences(actual: &CallActorInput, expected: &CallActorInput) -> Vec<String> {
+    let mut problems = vec![];
+
+    if actual.method != expected.method {
+        problems.push(format!(
+            "method {} instead of {}",
+            actual.method, expected.method
+        ));
+    }
+    if actual.value != expected.value {
+        problems.push(format!(
+            "value {} instead of {}",
+            actual.value, expected.value
+        ));
+    }
+    if actual.flags != DEFAULT_FLAG && actual.flags != READ_ONLY_FLAG {
+        problems.push(format!("unknown flags {:#x}", actual.flags));
+    } else if actual.flags != expected.flags {
+        problems.push(format!(
+            "flags {} instead of {}",
+            actual.flags, expected.flags
+        ));
+    }
+    if actual.codec != DAG_CBOR {
+        problems.push(format!(
+            "codec {:#x} instead of {:#x}",
+            actual.codec, DAG_CBOR
+        ));
+    }
+    if actual.params != expected.params {
+        problems.push(format!(
+            "params 0x{} instead of 0x{}",
+            hex::encode(&actual.params),
+            hex::encode(&expected.params)
+        ));
+    }
+    if actual.address != expected.address {
+        problems.push(format!(
+            "address 0x{} instead of 0x{}",
+            hex::encode(&actual.address),
+            hex::encode(&expected.address)
+        ));
+    }
+
+    problems
+}
+
+/// Checks `input` against the call it is meant to encode, when the precompile gets only the
+/// first `input_size` bytes of it. Returns one line per problem.
+pub fn verify(input: &[u8], input_size: usize, expected: &CallActorInput) -> Vec<String> {
+    let decoded = match decode(input) {
+        Ok(decoded) => decoded,
+        Err(e) => return vec![e],
+    };
+
+    let mut problems = differences(&decoded, expected);
+
+    let needed = decoded.size();
+    if needed > input_size {
+        let seen_params = input_size
+            .saturating_sub(HEADER_SIZE)
+            .min(decoded.params.len());
+        let seen_address = input_size
+            .saturating_sub(HEADER_SIZE + decoded.params.len())
+            .min(decoded.address.len());
+        problems.push(format!(
+            "precompile sees {} of {} bytes: {} of {} params bytes, {} of {} address bytes",
+            input_size,
+            needed,
+            seen_params,
+            decoded.params.len(),
+            seen_address,
+            decoded.address.len()
+        ));
+    }
+
+    problems
+}
+
+/// Arguments of `ActorCallTest.call_actor`.
+pub fn call_actor_args(
+    to: &Address,
+    method: MethodNum,
+    params: &RawBytes,
+    flags: u64,
+) -> Vec<Token> {
+    vec![
+        Token::Uint(method.into()),
+        Token::Bytes(to.to_bytes()),
+        Token::Bytes(params.to_vec()),
+        Token::Uint(flags.into()),
+    ]
+}
@@ -0,0 +1,9 @@
+//! Machine-readable test reports.
+//!
+//! [`crate::Harness::call`] keeps a [`CallRecord`] of every contract call: exit code, gas,
+//! decoded revert reason and how long it took to apply. Runners pair the records with
+//! the outcome of their checks into a [`Report`]. Every suite under `testing/`, with or
+//! without the harness, writes its reports with [`xfail::report`].
+
+pub use xfail::report::{report_dir, CallRecord, Report, TestCase, DEFAULT_REPORT_DIR};
+pub use xfail::Status;
@@ -0,0 +1,529 @@
+use std::collections::HashMap;
+use std::env;
+use std::time::Instant;
+
+use cid::Cid;
+use fil_actor_eam::Return;
+use fil_actor_power::{CreateMinerParams, CreateMinerReturn};
+use fil_actors_runtime::{EAM_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR};
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
+use fvm::state_tree::ActorState;
+use fvm_integration_tests::bundle;
+use fvm_integration_tests::dummy::DummyExterns;
+use fvm_integration_tests::tester::{Account, IntegrationExecutor, Tester};
+use fvm_ipld_blockstore::MemoryBlockstore;
+use fvm_ipld_encoding::{strict_bytes, tuple::*, BytesDe, BytesSer, CborStore, RawBytes};
+use fvm_shared::address::{Address, BLS_PUB_LEN};
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::message::Message;
+use fvm_shared::sector::RegisteredPoStProof;
+use fvm_shared::state::StateTreeVersion;
+use fvm_shared::version::NetworkVersion;
+use fvm_shared::{ActorID, MethodNum};
+
+use crate::address;
+use crate::balances::{Balances, Changes};
+use crate::contract::{revert_reason, Contract};
+use crate::gas::GasReport;
+use crate::method_nums;
+use crate::precompile::{call_actor_args, READ_ONLY_FLAG};
+use crate::report::CallRecord;
+use crate::trace;
+
+pub const BUNDLE_PATH: &str = "../builtin-actors/output/builtin-actors-devnet-wasm.car";
+
+pub const GAS_LIMIT: i64 = 1000000000;
+
+/// EAM `Create2` method number.
+pub const CREATE2_METHOD_NUM: MethodNum = 3;
+
+/// EVM `InvokeContract` method number.
+pub const INVOKE_CONTRACT_METHOD_NUM: MethodNum = 2;
+
+#[derive(Serialize_tuple, Deserialize_tuple)]
+pub struct Create2Params {
+    #[serde(with = "strict_bytes")]
+    pub initcode: Vec<u8>,
+    #[serde(with = "strict_bytes")]
+    pub salt: [u8; 32],
+}
+
+/// Params of the miners the suites create, owned by `owner`.
+pub fn create_miner_params(owner: Address, worker: Address) -> CreateMinerParams {
+    CreateMinerParams {
+        owner,
+        worker,
+        window_post_proof_type: RegisteredPoStProof::StackedDRGWindow2KiBV1,
+        peer: vec![1, 2, 3],
+        multiaddrs: vec![BytesDe(vec![1, 2, 3])],
+    }
+}
+
+pub type HarnessExecutor = IntegrationExecutor<MemoryBlockstore, DummyExterns>;
+
+pub struct Harness {
+    pub tester: Tester<MemoryBlockstore, DummyExterns>,
+    pub accounts: Vec<Account>,
+    codes: HashMap<String, Cid>,
+    sequences: HashMap<Address, u64>,
+    deployments: u64,
+    balance_checks: bool,
+    balance_changes: Changes,
+    tracing: bool,
+    contracts: Vec<ActorID>,
+    gas: GasReport,
+    calls: Vec<CallRecord>,
+}
+
+impl Harness {
+    /// Loads the devnet bundle into a fresh tester. Accounts must be created before
+    /// calling [`Harness::instantiate`].
+    pub fn new() -> Self {
+        let bs = MemoryBlockstore::default();
+        let actors = std::fs::read(BUNDLE_PATH).expect("Unable to read actor devnet file");
+        let bundle_root = bundle::import_bundle(&bs, &actors).unwrap();
+
+        // The bundle root holds the manifest version and the manifest, which lists the
+        // code CID of every builtin actor by name
+        let (_, manifest): (u32, Cid) = bs.get_cbor(&bundle_root).unwrap().unwrap();
+        let codes: Vec<(String, Cid)> = bs.get_cbor(&manifest).unwrap().unwrap();
+
+        let tester =
+            Tester::new(NetworkVersion::V18, StateTreeVersion::V5, bundle_root, bs).unwrap();
+
+        Harness {
+            tester,
+            accounts: vec![],
+            codes: codes.into_iter().collect(),
+            sequences: HashMap::new(),
+            deployments: 0,
+            balance_checks: false,
+            balance_changes: Changes::new(),
+            tracing: false,
+            contracts: vec![],
+            gas: GasReport::default(),
+            calls: vec![],
+        }
+    }
+
+    /// Code CID of the builtin actor called `name` in the bundle manifest, e.g. "multisig".
+    pub fn code_cid(&self, name: &str) -> Cid {
+        *self
+            .codes
+            .get(name)
+            .unwrap_or_else(|| panic!("no {} actor in the bundle", name))
+    }
+
+    pub fn create_accounts<const N: usize>(&mut self) -> [Account; N] {
+        let accounts: [Account; N] = self.tester.create_accounts().unwrap();
+        self.accounts.extend_from_slice(&accounts);
+        accounts
+    }
+
+    /// Records an execution trace for every message, to be rendered with
+    /// [`Harness::call_tree`]. Setting `TRACE` in the environment does the same for every
+    /// harness. Must be called before [`Harness::instantiate`].
+    pub fn trace(&mut self) {
+        self.tracing = true;
+    }
+
+    pub fn instantiate(&mut self) {
+        if self.tracing || env::var("TRACE").is_ok() {
+            self.tester
+                .instantiate_machine_with_config(
+                    DummyExterns,
+                    |_| (),
+                    |mc| {
+                        mc.enable_tracing();
+                    },
+                )
+                .unwrap();
+        } else {
+            self.tester.instantiate_machine(DummyExterns).unwrap();
+        }
+    }
+
+    pub fn executor(&mut self) -> &mut HarnessExecutor {
+        self.tester
+            .executor
+            .as_mut()
+            .expect("machine needs to be instantiated first")
+    }
+
+    fn next_sequence(&mut self, from: Address) -> u64 {
+        let sequence = self.sequences.entry(from).or_default();
+        let current = *sequence;
+        *sequence += 1;
+        current
+    }
+
+    pub fn apply(&mut self, message: Message, kind: ApplyKind) -> ApplyRet {
+        if !self.balance_checks {
+            return self.executor().execute_message(message, kind, 100).unwrap();
+        }
+
+        let (from, to, method_num) = (message.from, message.to, message.method_num);
+
+        let before = self.balances();
+        let res = self.executor().execute_message(message, kind, 100).unwrap();
+        let after = self.balances();
+
+        self.balance_changes = before.changes(&after);
+        assert_eq!(
+            before.total(),
+            after.total(),
+            "message from {} to {} (method {}) did not conserve value: {:?}",
+            from,
+            to,
+            method_num,
+            self.balance_changes
+        );
+
+        res
+    }
+
+    /// Applies `message` inside a state tree transaction that is then reverted, so nothing
+    /// it changed is kept, the sender sequence included.
+    pub fn apply_reverted(&mut self, message: Message) -> ApplyRet {
+        let executor = self.executor();
+
+        executor.state_tree_mut().begin_transaction();
+        let res = executor
+            .execute_message(message, ApplyKind::Explicit, 100)
+            .unwrap();
+        executor.state_tree_mut().end_transaction(true).unwrap();
+
+        res
+    }
+
+    /// Smallest gas limit with which `message` succeeds, or `None` if it fails even with
+    /// [`GAS_LIMIT`]. Every attempt goes through [`Harness::apply_reverted`], so the state
+    /// is the same for all of them.
+    pub fn estimate_gas(&mut self, message: Message) -> Option<i64> {
+        let succeeds = |harness: &mut Self, gas_limit| {
+            let message = Message {
+                gas_limit,
+                ..message.clone()
+            };
+            harness
+                .apply_reverted(message)
+                .msg_receipt
+                .exit_code
+                .is_success()
+        };
+
+        let res = self.apply_reverted(Message {
+            gas_limit: GAS_LIMIT,
+            ..message.clone()
+        });
+        if !res.msg_receipt.exit_code.is_success() {
+            return None;
+        }
+
+        // It would run out below what it used. It may need more than that though: inner
+        // calls only get part of the gas left, and have to be given enough to finish.
+        let mut failing = res.msg_receipt.gas_used - 1;
+        let mut succeeding = GAS_LIMIT;
+        if succeeds(self, failing) {
+            // Not a lower bound after all, search from no gas at all, with which nothing runs
+            succeeding = failing;
+            failing = 0;
+        }
+        while succeeding - failing > 1 {
+            let gas_limit = failing + (succeeding - failing) / 2;
+            if succeeds(self, gas_limit) {
+                succeeding = gas_limit;
+            } else {
+                failing = gas_limit;
+            }
+        }
+
+        Some(succeeding)
+    }
+
+    /// Checks that every message applied from now on conserves value, see [`crate::balances`].
+    pub fn check_balances(&mut self) {
+        self.balance_checks = true;
+    }
+
+    pub fn balances(&mut self) -> Balances {
+        Balances::snapshot(self.executor().state_tree())
+    }
+
+    /// Balance changes caused by the last message, if [`Harness::check_balances`] is on.
+    pub fn balance_changes(&self) -> &Changes {
+        &self.balance_changes
+    }
+
+    /// Call tree of the message that returned `res`, see [`crate::trace`].
+    pub fn call_tree(&self, res: &ApplyRet) -> String {
+        if res.exec_trace.is_empty() {
+            return "no execution trace, run with TRACE=1 to record one\n".to_string();
+        }
+
+        trace::render(&trace::build(&res.exec_trace), &self.contracts)
+    }
+
+    /// Sends an explicit message, keeping track of the sender sequence.
+    pub fn send(
+        &mut self,
+        from: Address,
+        to: Address,
+        method_num: MethodNum,
+        params: RawBytes,
+        value: TokenAmount,
+    ) -> ApplyRet {
+        let message = Message {
+            from,
+            to,
+            gas_limit: GAS_LIMIT,
+            method_num,
+            sequence: self.next_sequence(from),
+            value,
+            params,
+            ..Message::default()
+        };
+
+        self.apply(message, ApplyKind::Explicit)
+    }
+
+    /// Sends an implicit message. The sender doesn't need to be an account, which
+    /// allows calling methods restricted to singleton actors (e.g. datacap `Mint`).
+    pub fn send_implicit(
+        &mut self,
+        from: Address,
+        to: Address,
+        method_num: MethodNum,
+        params: RawBytes,
+        value: TokenAmount,
+    ) -> ApplyRet {
+        let message = Message {
+            from,
+            to,
+            gas_limit: GAS_LIMIT,
+            method_num,
+            value,
+            params,
+            ..Message::default()
+        };
+
+        self.apply(message, ApplyKind::Implicit)
+    }
+
+    pub fn actor_state(&mut self, id: ActorID) -> Option<ActorState> {
+        self.executor().state_tree().get_actor(id).unwrap()
+    }
+
+    /// Resolves any address protocol to the ID of the actor it refers to.
+    pub fn lookup_id(&mut self, addr: &Address) -> Option<ActorID> {
+        self.executor().state_tree().lookup_id(addr).unwrap()
+    }
+
+    /// Deploys the solc artifact at `artifact` (path without the `.bin` / `.abi` extension)
+    /// through the EAM.
+    pub fn deploy(&mut self, from: Address, artifact: &str) -> Contract {
+        let bin_path = env::current_dir()
+            .unwrap()
+            .join(format!("{}.bin", artifact))
+            .canonicalize()
+            .unwrap();
+        let evm_hex = std::fs::read(bin_path).expect("Unable to read file");
+        let evm_bin = hex::decode(evm_hex).unwrap();
+
+        // Same initcode deployed twice by the same sender would collide otherwise
+        let mut salt = [0; 32];
+        salt[24..].copy_from_slice(&self.deployments.to_be_bytes());
+        self.deployments += 1;
+
+        let constructor_params = Create2Params {
+            initcode: evm_bin,
+            salt,
+        };
+
+        let res = self.send(
+            from,
+            EAM_ACTOR_ADDR,
+            CREATE2_METHOD_NUM,
+            RawBytes::serialize(constructor_params).unwrap(),
+            TokenAmount::default(),
+        );
+
+        assert_eq!(
+            res.msg_receipt.exit_code.value(),
+            0,
+            "failed to deploy {}",
+            artifact
+        );
+
+        let exec_return: Return = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+
+        self.contracts.push(exec_return.actor_id);
+
+        let mut contract = Contract::load(Address::new_id(exec_return.actor_id), artifact);
+        contract.robust_address = exec_return.robust_address;
+        contract.delegated_address = Some(address::from_eth(exec_return.eth_address.0));
+        contract
+    }
+
+    /// Creates the BLS account the suites use as miner worker, which has to be an account
+    /// actor, by sending it funds from `from`.
+    pub fn bls_worker(&mut self, from: Address) -> Address {
+        let worker = Address::new_bls(&[1; BLS_PUB_LEN]).unwrap();
+
+        let res = self.send(
+            from,
+            worker,
+            0,
+            RawBytes::default(),
+            TokenAmount::from_atto(1000),
+        );
+        assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+        worker
+    }
+
+    /// Creates a miner owned by `owner` through the power actor, with the
+    /// [`Harness::bls_worker`] as worker. Returns the ID address of the miner and the worker.
+    pub fn create_miner(&mut self, from: Address, owner: Address) -> (Address, Address) {
+        let worker = self.bls_worker(from);
+
+        let res = self.send(
+            from,
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::CREATE_MINER,
+            RawBytes::serialize(create_miner_params(owner, worker)).unwrap(),
+            TokenAmount::default(),
+        );
+        assert_eq!(
+            res.msg_receipt.exit_code.value(),
+            0,
+            "failed to create a miner"
+        );
+
+        let ret: CreateMinerReturn = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+        (ret.id_address, worker)
+    }
+
+    /// The message [`Harness::call`] would send next, e.g. to [`Harness::estimate_gas`].
+    pub fn call_message(
+        &self,
+        from: Address,
+        contract: &Contract,
+        name: &str,
+        args: &[ethabi::Token],
+        value: TokenAmount,
+    ) -> Message {
+        let calldata = contract.calldata(name, args);
+
+        Message {
+            from,
+            to: contract.address,
+            gas_limit: GAS_LIMIT,
+            method_num: INVOKE_CONTRACT_METHOD_NUM,
+            sequence: self.sequences.get(&from).copied().unwrap_or_default(),
+            value,
+            params: RawBytes::serialize(BytesSer(&calldata)).unwrap(),
+            ..Message::default()
+        }
+    }
+
+    /// The message [`Harness::send`] would send next, e.g. to [`Harness::apply_reverted`].
+    pub fn send_message(
+        &self,
+        from: Address,
+        to: Address,
+        method_num: MethodNum,
+        params: RawBytes,
+        value: TokenAmount,
+    ) -> Message {
+        Message {
+            from,
+            to,
+            gas_limit: GAS_LIMIT,
+            method_num,
+            sequence: self.sequences.get(&from).copied().unwrap_or_default(),
+            value,
+            params,
+            ..Message::default()
+        }
+    }
+
+    /// Invokes `name` on a deployed contract with ABI encoded `args`.
+    pub fn call(
+        &mut self,
+        from: Address,
+        contract: &Contract,
+        name: &str,
+        args: &[ethabi::Token],
+        value: TokenAmount,
+    ) -> ApplyRet {
+        let calldata = contract.calldata(name, args);
+
+        let start = Instant::now();
+        let res = self.send(
+            from,
+            contract.address,
+            INVOKE_CONTRACT_METHOD_NUM,
+            RawBytes::serialize(BytesSer(&calldata)).unwrap(),
+            value,
+        );
+        let duration = start.elapsed();
+
+        self.gas
+            .record(&contract.name, name, res.msg_receipt.gas_used);
+        self.calls.push(CallRecord {
+            contract: contract.name.clone(),
+            function: name.to_string(),
+            exit_code: res.msg_receipt.exit_code.value(),
+            gas_used: res.msg_receipt.gas_used,
+            revert_reason: revert_reason(&res),
+            duration,
+        });
+
+        res
+    }
+
+    /// Every [`Harness::call`] so far, in order, see [`crate::report`].
+    pub fn calls(&self) -> &[CallRecord] {
+        &self.calls
+    }
+
+    /// Gas used by every [`Harness::call`] so far, see [`crate::gas`].
+    pub fn gas_report(&self) -> &GasReport {
+        &self.gas
+    }
+
+    /// Calls `method` on `to` with raw CBOR `params` through a deployed `ActorCallTest`,
+    /// the way the API wrappers do but with the given precompile `flags`.
+    pub fn call_actor(
+        &mut self,
+        from: Address,
+        contract: &Contract,
+        to: &Address,
+        method: MethodNum,
+        params: &RawBytes,
+        flags: u64,
+    ) -> ApplyRet {
+        let args = call_actor_args(to, method, params, flags);
+        self.call(from, contract, "call_actor", &args, TokenAmount::default())
+    }
+
+    /// [`Harness::call_actor`] with the read-only flag: the actor may neither change state
+    /// nor send funds.
+    pub fn call_read_only(
+        &mut self,
+        from: Address,
+        contract: &Contract,
+        to: &Address,
+        method: MethodNum,
+        params: &RawBytes,
+    ) -> ApplyRet {
+        self.call_actor(from, contract, to, method, params, READ_ONLY_FLAG)
+    }
+}
+
+impl Default for Harness {
+    fn default() -> Self {
+        Self::new()
+    }
+}
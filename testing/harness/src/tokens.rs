@@ -0,0 +1,58 @@
+//! Conversions from native values to the ABI tokens the API contracts take.
+
+use cid::Cid;
+use ethabi::Token;
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::bigint::{BigInt, Sign};
+use fvm_shared::econ::TokenAmount;
+
+/// Addresses are passed to the API contracts as their byte representation.
+pub fn address(addr: &Address) -> Token {
+    Token::Bytes(addr.to_bytes())
+}
+
+/// CIDs are passed as their binary representation, without the dag-cbor multibase prefix.
+pub fn cid(cid: &Cid) -> Token {
+    Token::Bytes(cid.to_bytes())
+}
+
+pub fn uint(value: u64) -> Token {
+    Token::Uint(value.into())
+}
+
+pub fn int(value: i64) -> Token {
+    Token::Int(twos_complement(value < 0, value.unsigned_abs().into()))
+}
+
+/// Token amounts are `int256` on the solidity side.
+pub fn amount(value: &TokenAmount) -> Token {
+    Token::Int(big_int(value.atto()))
+}
+
+/// Some wrappers and returns use `uint256` for amounts that can't be negative.
+pub fn unsigned_amount(value: &TokenAmount) -> Token {
+    Token::Uint(big_int(value.atto()))
+}
+
+/// Some wrappers take token amounts already serialized as a filecoin BigInt.
+pub fn amount_bytes(value: &TokenAmount) -> Token {
+    let BytesDe(bytes) = RawBytes::deserialize(&RawBytes::serialize(value).unwrap()).unwrap();
+    Token::Bytes(bytes)
+}
+
+/// Two's complement of `value`, truncated to 256 bits.
+pub fn big_int(value: &BigInt) -> ethabi::Int {
+    let (sign, magnitude) = value.to_bytes_be();
+    let magnitude = &magnitude[magnitude.len().saturating_sub(32)..];
+
+    twos_complement(sign == Sign::Minus, ethabi::Int::from_big_endian(magnitude))
+}
+
+fn twos_complement(negative: bool, magnitude: ethabi::Int) -> ethabi::Int {
+    if negative {
+        (!magnitude).overflowing_add(1.into()).0
+    } else {
+        magnitude
+    }
+}
@@ -0,0 +1,175 @@
+//! Call trees built from FVM execution traces.
+//!
+//! With tracing on (see [`crate::Harness::trace`]), every [`ApplyRet`] carries the events
+//! the machine recorded while applying the message. They are folded back into the calls
+//! that were made: the contract, the builtin actor it reached through the call actor
+//! precompile and whatever that actor sent in turn, each with its method name, params,
+//! outcome, return and the gas charged while it ran. Params and returns are shown in
+//! diagnostic notation, see [`crate::diagnostic`].
+//!
+//! [`ApplyRet`]: fvm::executor::ApplyRet
+
+use fvm::gas::Gas;
+use fvm::trace::ExecutionEvent;
+use fvm_ipld_encoding::ipld_block::IpldBlock;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::error::ExitCode;
+use fvm_shared::{ActorID, MethodNum};
+use schema::Schema;
+
+use crate::setup::INVOKE_CONTRACT_METHOD_NUM;
+use crate::{diagnostic, methods};
+
+pub enum Outcome {
+    Returned(ExitCode, Option<IpldBlock>),
+    /// The send itself failed (e.g. unknown recipient), the actor never ran.
+    Failed(String),
+    /// The trace ended before the call returned.
+    Unfinished,
+}
+
+pub struct Call {
+    pub from: ActorID,
+    pub to: Address,
+    pub method: MethodNum,
+    pub params: Option<IpldBlock>,
+    pub value: TokenAmount,
+    pub outcome: Outcome,
+    /// Charged while the call ran, nested calls included.
+    pub gas: Gas,
+    pub calls: Vec<Call>,
+}
+
+/// Rebuilds the calls recorded in `events`. Gas charged outside of any call (message
+/// inclusion) is left out.
+pub fn build(events: &[ExecutionEvent]) -> Vec<Call> {
+    let mut calls = vec![];
+    let mut stack: Vec<Call> = vec![];
+
+    for event in events {
+        match event {
+            ExecutionEvent::GasCharge(charge) => {
+                if let Some(call) = stack.last_mut() {
+                    call.gas += charge.total();
+                }
+            }
+            ExecutionEvent::Call {
+                from,
+                to,
+                method,
+                params,
+                value,
+            } => stack.push(Call {
+                from: *from,
+                to: *to,
+                method: *method,
+                params: params.clone(),
+                value: value.clone(),
+                outcome: Outcome::Unfinished,
+                gas: Gas::zero(),
+                calls: vec![],
+            }),
+            ExecutionEvent::CallReturn(exit_code, data) => finish(
+                &mut stack,
+                &mut calls,
+                Outcome::Returned(*exit_code, data.clone()),
+            ),
+            ExecutionEvent::CallError(err) => finish(
+                &mut stack,
+                &mut calls,
+                Outcome::Failed(format!("{:?}", err)),
+            ),
+            _ => {}
+        }
+    }
+
+    while !stack.is_empty() {
+        finish(&mut stack, &mut calls, Outcome::Unfinished);
+    }
+
+    calls
+}
+
+fn finish(stack: &mut Vec<Call>, calls: &mut Vec<Call>, outcome: Outcome) {
+    let mut call = match stack.pop() {
+        Some(call) => call,
+        None => return,
+    };
+    call.outcome = outcome;
+
+    match stack.last_mut() {
+        Some(parent) => {
+            parent.gas += call.gas;
+            parent.calls.push(call);
+        }
+        None => calls.push(call),
+    }
+}
+
+/// Renders `calls` as an indented tree. `contracts` are the IDs of the deployed
+/// contracts, whose methods are not FRC-42 hashes and whose calls to builtin actors go
+/// through the call actor precompile.
+pub fn render(calls: &[Call], contracts: &[ActorID]) -> String {
+    let schema = Schema::new();
+    let mut out = String::new();
+    for call in calls {
+        render_call(&mut out, call, contracts, &schema, 0);
+    }
+    out
+}
+
+fn render_call(
+    out: &mut String,
+    call: &Call,
+    contracts: &[ActorID],
+    schema: &Schema,
+    depth: usize,
+) {
+    let indent = "  ".repeat(depth);
+    let to_contract = call.to.id().map_or(false, |id| contracts.contains(&id));
+
+    let method = match call.method {
+        INVOKE_CONTRACT_METHOD_NUM if to_contract => "InvokeContract".to_string(),
+        method => methods::name(&call.to, method),
+    };
+    let via = if contracts.contains(&call.from) && !to_contract && call.method != 0 {
+        " via call actor precompile"
+    } else {
+        ""
+    };
+    let outcome = match &call.outcome {
+        Outcome::Returned(exit_code, _) => format!("exit code {}", exit_code.value()),
+        Outcome::Failed(err) => format!("send failed: {}", err),
+        Outcome::Unfinished => "did not return".to_string(),
+    };
+
+    out.push_str(&format!(
+        "{}f0{} -> {} {}{}: {}, {} gas",
+        indent,
+        call.from,
+        call.to,
+        method,
+        via,
+        outcome,
+        call.gas.round_up()
+    ));
+    if !call.value.is_zero() {
+        out.push_str(&format!(", value {}", call.value.atto()));
+    }
+    out.push('\n');
+
+    // Contract calldata isn't CBOR the schema knows about, it comes out as plain notation
+    if let Some(params) = &call.params {
+        let params = diagnostic::params(schema, &call.to, call.method, &params.data);
+        out.push_str(&format!("{}  params: {}\n", indent, params));
+    }
+    if let Outcome::Returned(_, Some(data)) = &call.outcome {
+        let ret = diagnostic::ret(schema, &call.to, call.method, &data.data);
+        out.push_str(&format!("{}  return: {}\n", indent, ret));
+    }
+
+    for nested in &call.calls {
+        render_call(out, nested, contracts, schema, depth + 1);
+    }
+}
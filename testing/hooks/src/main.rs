@@ -0,0 +1,321 @@
+use ethabi::Token;
+use fil_actor_datacap::MintParams;
+use fil_actor_verifreg::{AllocationRequest, AllocationRequests, AllocationsResponse};
+use fil_actors_runtime::runtime::Policy;
+use fil_actors_runtime::{DATACAP_TOKEN_ACTOR_ADDR, VERIFIED_REGISTRY_ACTOR_ADDR};
+use frc46_token::token::types::{TransferParams, TransferReturn};
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::commcid::data_commitment_v1_to_cid;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::piece::PaddedPieceSize;
+use fvm_shared::ActorID;
+use harness::method_nums;
+use harness::{Contract, Harness};
+
+const RECEIVER_ARTIFACT: &str = "../../build/v0.8/tests/UniversalReceiverTest";
+
+const FRC46_TOKEN_TYPE: u32 = frc42_dispatch::method_hash!("FRC46") as u32;
+
+/// Method number of the universal receiver hook, the same for every actor receiving tokens.
+const UNIVERSAL_RECEIVER_HOOK: u64 = frc42_dispatch::method_hash!("Receive");
+
+/// Bytes of the allocation requested from verifreg, the smallest it accepts outside of the
+/// 2KiB sector networks. One whole datacap token pays for a byte.
+const ALLOCATION_SIZE: u64 = 1 << 20;
+
+struct Hook {
+    calls: u64,
+    method: u64,
+    type_: u32,
+    from: ActorID,
+    to: ActorID,
+    operator: ActorID,
+    amount: Vec<u8>,
+    operator_data: Vec<u8>,
+    token_data: Vec<u8>,
+}
+
+fn uint(harness: &mut Harness, from: Address, receiver: &Contract, name: &str) -> u64 {
+    let res = harness.call(from, receiver, name, &[], TokenAmount::default());
+    receiver.decode(name, &res)[0].clone().into_uint().unwrap().as_u64()
+}
+
+fn bytes(harness: &mut Harness, from: Address, receiver: &Contract, name: &str) -> Vec<u8> {
+    let res = harness.call(from, receiver, name, &[], TokenAmount::default());
+    receiver.decode(name, &res)[0].clone().into_bytes().unwrap()
+}
+
+fn last_hook(harness: &mut Harness, from: Address, receiver: &Contract) -> Hook {
+    Hook {
+        calls: uint(harness, from, receiver, "hook_calls"),
+        method: uint(harness, from, receiver, "last_method"),
+        type_: uint(harness, from, receiver, "last_type") as u32,
+        from: uint(harness, from, receiver, "last_from"),
+        to: uint(harness, from, receiver, "last_to"),
+        operator: uint(harness, from, receiver, "last_operator"),
+        amount: bytes(harness, from, receiver, "last_amount"),
+        operator_data: bytes(harness, from, receiver, "last_operator_data"),
+        token_data: bytes(harness, from, receiver, "last_token_data"),
+    }
+}
+
+// TokenAmount is sent as a CBOR byte string holding the sign-prefixed big endian value
+fn amount_bytes(amount: &TokenAmount) -> Vec<u8> {
+    let BytesDe(bytes) = RawBytes::deserialize(&RawBytes::serialize(amount).unwrap()).unwrap();
+    bytes
+}
+
+fn datacap_balance(harness: &mut Harness, from: Address, addr: Address) -> TokenAmount {
+    let res = harness.send(
+        from,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::BALANCE,
+        RawBytes::serialize(addr).unwrap(),
+        TokenAmount::default(),
+    );
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    RawBytes::deserialize(&res.msg_receipt.return_data).unwrap()
+}
+
+fn main() {
+    println!("Testing universal receiver hook delivery");
+
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine, tracing calls to show where hook delivery fails
+    harness.trace();
+    harness.instantiate();
+
+    println!("Deploying receiver contract");
+
+    let receiver = harness.deploy(sender[0].1, RECEIVER_ARTIFACT);
+    let receiver_id = receiver.address.id().unwrap();
+
+    println!("Minting datacap to the receiver contract");
+
+    let amount = TokenAmount::from_whole(2);
+    let params = MintParams {
+        to: receiver.address,
+        amount: amount.clone(),
+        operators: vec![],
+    };
+
+    let res = harness.send_implicit(
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::MINT,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    if res.msg_receipt.exit_code.value() != 0 {
+        print!("{}", harness.call_tree(&res));
+    }
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    let hook = last_hook(&mut harness, sender[0].1, &receiver);
+
+    assert_eq!(hook.calls, 1);
+    assert_eq!(hook.method, UNIVERSAL_RECEIVER_HOOK);
+    assert_eq!(hook.type_, FRC46_TOKEN_TYPE);
+    assert_eq!(hook.from, DATACAP_TOKEN_ACTOR_ADDR.id().unwrap());
+    assert_eq!(hook.to, receiver_id);
+    assert_eq!(hook.operator, VERIFIED_REGISTRY_ACTOR_ADDR.id().unwrap());
+    assert_eq!(hook.amount, amount_bytes(&amount));
+    assert!(hook.operator_data.is_empty());
+    assert!(hook.token_data.is_empty());
+
+    println!("Minting datacap to an account");
+
+    let params = MintParams {
+        to: sender[0].1,
+        amount: TokenAmount::from_whole(10),
+        operators: vec![],
+    };
+
+    let res = harness.send_implicit(
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::MINT,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    println!("Transferring datacap from the account to the receiver contract");
+
+    let amount = TokenAmount::from_whole(3);
+    let operator_data = hex::decode("deadbeef").unwrap();
+    let params = TransferParams {
+        to: receiver.address,
+        amount: amount.clone(),
+        operator_data: RawBytes::new(operator_data.clone()),
+    };
+
+    let res = harness.send(
+        sender[0].1,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::TRANSFER,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    if res.msg_receipt.exit_code.value() != 0 {
+        print!("{}", harness.call_tree(&res));
+    }
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    let hook = last_hook(&mut harness, sender[0].1, &receiver);
+
+    assert_eq!(hook.calls, 2);
+    assert_eq!(hook.method, UNIVERSAL_RECEIVER_HOOK);
+    assert_eq!(hook.type_, FRC46_TOKEN_TYPE);
+    assert_eq!(hook.from, sender[0].0);
+    assert_eq!(hook.to, receiver_id);
+    assert_eq!(hook.operator, sender[0].0);
+    assert_eq!(hook.amount, amount_bytes(&amount));
+    assert_eq!(hook.operator_data, operator_data);
+    assert!(hook.token_data.is_empty());
+
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, receiver.address),
+        TokenAmount::from_whole(5)
+    );
+
+    println!("Transferring datacap to a receiver that rejects the hook");
+
+    let res = harness.call(
+        sender[0].1,
+        &receiver,
+        "set_reject",
+        &[Token::Bool(true)],
+        TokenAmount::default(),
+    );
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    let params = TransferParams {
+        to: receiver.address,
+        amount: TokenAmount::from_whole(1),
+        operator_data: RawBytes::default(),
+    };
+
+    let res = harness.send(
+        sender[0].1,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::TRANSFER,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    // The whole transfer is aborted when the receiver hook fails
+    assert_ne!(res.msg_receipt.exit_code.value(), 0);
+
+    let hook = last_hook(&mut harness, sender[0].1, &receiver);
+    assert_eq!(hook.calls, 2);
+
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, receiver.address),
+        TokenAmount::from_whole(5)
+    );
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, sender[0].1),
+        TokenAmount::from_whole(7)
+    );
+
+    println!("Minting datacap to a receiver that rejects the hook");
+
+    let params = MintParams {
+        to: receiver.address,
+        amount: TokenAmount::from_whole(1),
+        operators: vec![],
+    };
+
+    let res = harness.send_implicit(
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::MINT,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    assert_ne!(res.msg_receipt.exit_code.value(), 0);
+
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, receiver.address),
+        TokenAmount::from_whole(5)
+    );
+
+    println!("Transferring datacap to verifreg with an allocation request");
+
+    let params = MintParams {
+        to: sender[0].1,
+        amount: TokenAmount::from_whole(ALLOCATION_SIZE),
+        operators: vec![],
+    };
+
+    let res = harness.send_implicit(
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::MINT,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    // Verifreg only allocates to miners
+    let (miner, _) = harness.create_miner(sender[0].1, sender[0].1);
+
+    let policy = Policy::default();
+    let requests = AllocationRequests {
+        allocations: vec![AllocationRequest {
+            provider: miner,
+            data: data_commitment_v1_to_cid(&[0; 32]).unwrap(),
+            size: PaddedPieceSize(ALLOCATION_SIZE),
+            term_min: policy.minimum_verified_allocation_term,
+            term_max: policy.maximum_verified_allocation_term,
+            expiration: policy.maximum_verified_allocation_expiration,
+        }],
+        extensions: vec![],
+    };
+
+    let params = TransferParams {
+        to: VERIFIED_REGISTRY_ACTOR_ADDR,
+        amount: TokenAmount::from_whole(ALLOCATION_SIZE),
+        operator_data: RawBytes::serialize(requests).unwrap(),
+    };
+
+    let res = harness.send(
+        sender[0].1,
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::TRANSFER,
+        RawBytes::serialize(params).unwrap(),
+        TokenAmount::default(),
+    );
+
+    if res.msg_receipt.exit_code.value() != 0 {
+        print!("{}", harness.call_tree(&res));
+    }
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    // The verifreg receiver hook answers with the allocations it made
+    let ret: TransferReturn = RawBytes::deserialize(&res.msg_receipt.return_data).unwrap();
+    let allocations: AllocationsResponse = RawBytes::deserialize(&ret.recipient_data).unwrap();
+
+    assert_eq!(allocations.allocation_results.success_count, 1);
+    assert_eq!(allocations.new_allocations.len(), 1);
+
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, VERIFIED_REGISTRY_ACTOR_ADDR),
+        TokenAmount::from_whole(ALLOCATION_SIZE)
+    );
+    assert_eq!(
+        datacap_balance(&mut harness, sender[0].1, sender[0].1),
+        TokenAmount::from_whole(7)
+    );
+}
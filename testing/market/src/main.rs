@@ -8,8 +8,12 @@ use fvm_shared::econ::TokenAmount;
 use fvm_ipld_blockstore::MemoryBlockstore;
 use fvm_shared::address::Address;
 use std::env;
+use std::process::exit;
+use std::time::Instant;
+use xfail::report::{report_dir, CallRecord};
+use xfail::{Registry, Status};
 use fvm_shared::message::Message;
-use fvm::executor::{ApplyKind, Executor};
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
 use fil_actor_eam::Return;
 use fvm_ipld_encoding::RawBytes;
 use fil_actors_runtime::{EAM_ACTOR_ADDR};
@@ -26,9 +30,23 @@ pub struct Create2Params {
     pub salt: [u8; 32],
 }
 
+/// What the registry checks and reports of a call to `function`, applied since `start`.
+fn record(function: &str, res: &ApplyRet, start: Instant) -> CallRecord {
+    CallRecord::new(
+        "MarketAPI",
+        function,
+        res.msg_receipt.exit_code.value(),
+        res.msg_receipt.gas_used,
+        res.msg_receipt.return_data.bytes(),
+        start.elapsed(),
+    )
+}
+
 fn main() {
     println!("Testing solidity API");
 
+    let mut known_failures = Registry::load("market");
+
     let bs = MemoryBlockstore::default();
     let actors = std::fs::read("../builtin-actors/output/builtin-actors-devnet-wasm.car").expect("Unable to read actor devnet file file");
     let bundle_root = bundle::import_bundle(&bs, &actors).unwrap();
@@ -145,12 +163,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME : "no such deal"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("get_deal_data_commitment", &res, start));
 
     println!("Calling `get_deal_client`");
 
@@ -164,6 +182,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -172,9 +191,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_client", &res, start));
 
 
     println!("Calling `get_deal_provider`");
@@ -189,6 +206,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -197,9 +215,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_provider", &res, start));
 
 
     println!("Calling `get_deal_label`");
@@ -214,6 +230,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -222,9 +239,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_label", &res, start));
 
 
     println!("Calling `get_deal_term`");
@@ -239,6 +254,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -247,9 +263,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_term", &res, start));
 
 
     println!("Calling `get_deal_total_price`");
@@ -264,6 +278,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -272,9 +287,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_total_price", &res, start));
 
 
     println!("Calling `get_deal_client_collateral`");
@@ -289,6 +302,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -297,9 +311,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_client_collateral", &res, start));
 
 
     println!("Calling `get_deal_provider_collateral`");
@@ -314,6 +326,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -322,9 +335,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_provider_collateral", &res, start));
 
 
     println!("Calling `get_deal_verified`");
@@ -339,6 +350,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -347,9 +359,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_verified", &res, start));
 
 
     println!("Calling `get_deal_activation`");
@@ -364,6 +374,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -372,9 +383,7 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    //assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "586408c379a0000000000000000000000000000000000000000000000000000000000000002000000000000000000000000000000000000000000000000000000000000000136163746f72206572726f7220636f646520313700000000000000000000000000");
+    known_failures.check(record("get_deal_activation", &res, start));
 
 
     println!("Calling `publish_storage_deals`");
@@ -389,6 +398,7 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
@@ -398,7 +408,14 @@ fn main() {
         dbg!(&res);
     }
 
-    // FIXME : "actor error"
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
-    assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "40");
+    // Known to revert without data, anything else is a different failure
+    if known_failures.check(record("publish_storage_deals", &res, start)) == Status::XFail {
+        assert_eq!(hex::encode(res.msg_receipt.return_data.bytes()), "40");
+    }
+
+    known_failures.write_report(&report_dir());
+
+    if known_failures.report() > 0 {
+        exit(1);
+    }
 }
@@ -7,8 +7,12 @@ use fvm_shared::version::NetworkVersion;
 use fvm_ipld_blockstore::MemoryBlockstore;
 use fvm_shared::address::Address;
 use std::env;
+use std::process::exit;
+use std::time::Instant;
+use xfail::report::{report_dir, CallRecord};
+use xfail::Registry;
 use fvm_shared::message::Message;
-use fvm::executor::{ApplyKind, Executor};
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
 use fil_actor_eam::Return;
 use fil_actor_init::ExecReturn;
 use fvm_ipld_encoding::RawBytes;
@@ -38,9 +42,23 @@ pub struct Create2Params {
     pub salt: [u8; 32],
 }
 
+/// What the registry checks and reports of a call to `function`, applied since `start`.
+fn record(function: &str, res: &ApplyRet, start: Instant) -> CallRecord {
+    CallRecord::new(
+        "MinerAPI",
+        function,
+        res.msg_receipt.exit_code.value(),
+        res.msg_receipt.gas_used,
+        res.msg_receipt.return_data.bytes(),
+        start.elapsed(),
+    )
+}
+
 fn main() {
     println!("Testing solidity API");
 
+    let mut known_failures = Registry::load("miner");
+
     let bs = MemoryBlockstore::default();
     let actors = std::fs::read("../builtin-actors/output/builtin-actors-devnet-wasm.car").expect("Unable to read actor devnet file file");
     let bundle_root = bundle::import_bundle(&bs, &actors).unwrap();
@@ -236,12 +254,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("get_owner", &res, start));
 
     println!("Calling `get_available_balance`");
 
@@ -292,12 +310,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("repay_debt", &res, start));
 
 
     println!("Calling `confirm_change_worker_address`");
@@ -312,12 +330,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("confirm_change_worker_address", &res, start));
 
 
     println!("Calling `get_peer_id`");
@@ -371,12 +389,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("change_worker_address", &res, start));
 
     println!("Calling `is_controlling_address`");
 
@@ -426,12 +444,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("change_multiaddresses", &res, start));
 
     println!("Calling `change_peer_id`");
 
@@ -445,10 +463,16 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("change_peer_id", &res, start));
+
+    known_failures.write_report(&report_dir());
+
+    if known_failures.report() > 0 {
+        exit(1);
+    }
 }
@@ -7,8 +7,12 @@ use fvm_shared::version::NetworkVersion;
 use fvm_ipld_blockstore::MemoryBlockstore;
 use fvm_shared::address::Address;
 use std::env;
+use std::process::exit;
+use std::time::Instant;
+use xfail::report::{report_dir, CallRecord};
+use xfail::Registry;
 use fvm_shared::message::Message;
-use fvm::executor::{ApplyKind, Executor};
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
 use fil_actor_eam::Return;
 use fil_actor_init::ExecReturn;
 use fvm_ipld_encoding::RawBytes;
@@ -33,9 +37,23 @@ pub struct Create2Params {
     pub salt: [u8; 32],
 }
 
+/// What the registry checks and reports of a call to `function`, applied since `start`.
+fn record(function: &str, res: &ApplyRet, start: Instant) -> CallRecord {
+    CallRecord::new(
+        "MultisigAPI",
+        function,
+        res.msg_receipt.exit_code.value(),
+        res.msg_receipt.gas_used,
+        res.msg_receipt.return_data.bytes(),
+        start.elapsed(),
+    )
+}
+
 fn main() {
     println!("Testing solidity API");
 
+    let mut known_failures = Registry::load("multisig");
+
     let bs = MemoryBlockstore::default();
     let actors = std::fs::read("../builtin-actors/output/builtin-actors-devnet-wasm.car").expect("Unable to read actor devnet file file");
     let bundle_root = bundle::import_bundle(&bs, &actors).unwrap();
@@ -181,13 +199,13 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("propose", &res, start));
 
     println!("Calling `add_signer`");
 
@@ -201,13 +219,19 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("add_signer", &res, start));
 
     // FIXME: As propose is failing, we cannot execute the rest of the methods...
+
+    known_failures.write_report(&report_dir());
+
+    if known_failures.report() > 0 {
+        exit(1);
+    }
 }
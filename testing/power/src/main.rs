@@ -6,9 +6,13 @@ use fvm_shared::state::StateTreeVersion;
 use fvm_shared::version::NetworkVersion;
 use fvm_ipld_blockstore::MemoryBlockstore;
 use std::env;
+use std::process::exit;
+use std::time::Instant;
+use xfail::report::{report_dir, CallRecord};
+use xfail::Registry;
 use fvm_shared::message::Message;
 use fvm_shared::address::Address;
-use fvm::executor::{ApplyKind, Executor};
+use fvm::executor::{ApplyKind, ApplyRet, Executor};
 use fil_actor_eam::Return;
 use fvm_ipld_encoding::RawBytes;
 use fil_actors_runtime::{EAM_ACTOR_ADDR};
@@ -24,9 +28,23 @@ pub struct Create2Params {
     pub salt: [u8; 32],
 }
 
+/// What the registry checks and reports of a call to `function`, applied since `start`.
+fn record(function: &str, res: &ApplyRet, start: Instant) -> CallRecord {
+    CallRecord::new(
+        "PowerAPI",
+        function,
+        res.msg_receipt.exit_code.value(),
+        res.msg_receipt.gas_used,
+        res.msg_receipt.return_data.bytes(),
+        start.elapsed(),
+    )
+}
+
 fn main() {
     println!("Testing solidity API");
 
+    let mut known_failures = Registry::load("power");
+
     let bs = MemoryBlockstore::default();
     let actors = std::fs::read("../builtin-actors/output/builtin-actors-devnet-wasm.car").expect("Unable to read actor devnet file file");
     let bundle_root = bundle::import_bundle(&bs, &actors).unwrap();
@@ -121,12 +139,12 @@ fn main() {
         ..Message::default()
     };
 
+    let start = Instant::now();
     let res = executor
         .execute_message(message, ApplyKind::Explicit, 100)
         .unwrap();
 
-    // FIXME
-    assert_eq!(res.msg_receipt.exit_code.value(), 33);
+    known_failures.check(record("miner_raw_power", &res, start));
 
     println!("Calling `miner_consensus_count`");
 
@@ -145,4 +163,10 @@ fn main() {
         .unwrap();
 
     assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    known_failures.write_report(&report_dir());
+
+    if known_failures.report() > 0 {
+        exit(1);
+    }
 }
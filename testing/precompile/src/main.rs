@@ -0,0 +1,145 @@
+//! Call actor precompile input layout suite.
+//!
+//! `ActorCallTest` makes calls through `Actor.call` with params around the word boundaries
+//! and ID addresses of different lengths. Each call the execution trace shows reaching the
+//! target actor, which is what the precompile actually read, is checked against the call it
+//! was meant to be.
+
+use std::process::exit;
+
+use ethabi::Uint;
+use fvm::executor::ApplyRet;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::ActorID;
+use harness::precompile::{
+    call_actor_args, differences, CallActorInput, ACTOR_CALL_TEST_ARTIFACT, DEFAULT_FLAG,
+};
+use harness::trace;
+use harness::{Contract, Harness};
+
+/// Around the word boundaries, and past the 0x100 bytes `Actor.call` used to send.
+const PARAMS_LENS: [usize; 11] = [0, 1, 31, 32, 33, 62, 63, 64, 65, 100, 300];
+
+/// Any method number, the layout does not depend on it.
+const METHOD: u64 = 3_916_220_144;
+
+/// Valid CBOR of exactly `len` bytes, a byte string, so that the params are sent as they
+/// are. `None` for 25 and 258 bytes, which no minimally encoded byte string takes.
+fn cbor_params(len: usize) -> Option<Vec<u8>> {
+    let header = match len {
+        0 => return Some(vec![]),
+        1..=24 => vec![0x40 | (len - 1) as u8],
+        25 | 258 => return None,
+        26..=257 => vec![0x58, (len - 2) as u8],
+        _ => {
+            let payload = u16::try_from(len - 3).ok()?;
+            let mut header = vec![0x59];
+            header.extend(payload.to_be_bytes());
+            header
+        }
+    };
+
+    let payload = (0..len - header.len()).map(|i| i as u8);
+    Some(header.into_iter().chain(payload).collect())
+}
+
+/// The call the contract made through the precompile while applying `res`, as an input.
+fn captured(res: &ApplyRet, contract: &Contract) -> Option<CallActorInput> {
+    let contract_id = contract.address.id().unwrap();
+
+    let call = trace::build(&res.exec_trace)
+        .into_iter()
+        .next()?
+        .calls
+        .into_iter()
+        .find(|call| call.from == contract_id)?;
+
+    let (_, value) = call.value.atto().to_bytes_be();
+    Some(CallActorInput::new(
+        call.method,
+        Uint::from_big_endian(&value),
+        call.params.map(|params| params.data).unwrap_or_default(),
+        call.to.to_bytes(),
+    ))
+}
+
+/// Makes the call in `expected` and checks it against the call the trace shows.
+fn check(
+    harness: &mut Harness,
+    sender: Address,
+    contract: &Contract,
+    expected: &CallActorInput,
+) -> Vec<String> {
+    let value = TokenAmount::from_atto(expected.value.as_u64());
+    let to = Address::from_bytes(&expected.address).unwrap();
+    let params = RawBytes::new(expected.params.clone());
+    let args = call_actor_args(&to, expected.method, &params, DEFAULT_FLAG);
+    let res = harness.call(sender, contract, "call_actor", &args, value);
+
+    match captured(&res, contract) {
+        Some(sent) => differences(&sent, expected)
+            .into_iter()
+            .map(|problem| format!("against the traced call: {}", problem))
+            .collect(),
+        None => vec!["no call through the precompile in the trace".to_string()],
+    }
+}
+
+fn main() {
+    println!("Checking the call actor precompile input built by Actor.call");
+
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine, tracing calls to see what the precompile sent
+    harness.trace();
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, ACTOR_CALL_TEST_ARTIFACT);
+
+    let targets = [
+        Address::new_id(101),
+        Address::new_id(1000),
+        Address::new_id(u64::MAX),
+    ];
+
+    let mut failures = 0;
+    let mut checks = 0;
+
+    for target in &targets {
+        for len in PARAMS_LENS {
+            let params = match cbor_params(len) {
+                Some(params) => params,
+                None => continue,
+            };
+
+            for value in [0u64, 100] {
+                checks += 1;
+
+                let expected =
+                    CallActorInput::new(METHOD, value.into(), params.clone(), target.to_bytes());
+
+                let problems = check(&mut harness, sender[0].1, &contract, &expected);
+                if !problems.is_empty() {
+                    failures += 1;
+                    println!(
+                        "  FAILED {} params to {} with value {}:",
+                        len, target, value
+                    );
+                    for problem in problems {
+                        println!("    {}", problem);
+                    }
+                }
+            }
+        }
+    }
+
+    println!("{} checks, {} failures", checks, failures);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
@@ -0,0 +1,366 @@
+//! Read-only call suite.
+//!
+//! `Actor.call` always sends `DEFAULT_FLAG`. The methods behind the getter wrappers are
+//! called through `ActorCallTest` with `READ_ONLY_FLAG` too: those that return the same
+//! data could back a `view` wrapper. The methods behind state-changing wrappers have to
+//! fail under the flag without touching the actor, and succeed without it.
+
+use std::process::exit;
+
+use fil_actor_market::WithdrawBalanceParams;
+use fil_actor_miner::{ChangeMultiaddrsParams, ChangePeerIDParams};
+use fil_actor_power::MinerRawPowerParams;
+use fil_actor_verifreg::GetClaimsParams;
+use fil_actors_runtime::{
+    DATACAP_TOKEN_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR,
+    VERIFIED_REGISTRY_ACTOR_ADDR,
+};
+use frc46_token::token::types::{GetAllowanceParams, IncreaseAllowanceParams};
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::{ActorID, MethodNum};
+use harness::checks::{check, failure, report};
+use harness::method_nums;
+use harness::precompile::{
+    call_actor_args, ACTOR_CALL_TEST_ARTIFACT, DEFAULT_FLAG, READ_ONLY_FLAG,
+};
+use harness::setup::create_miner_params;
+use harness::{Contract, Harness};
+
+/// An actor method behind an API wrapper.
+struct Call {
+    wrapper: &'static str,
+    to: Address,
+    method: MethodNum,
+    params: RawBytes,
+}
+
+fn call(wrapper: &'static str, to: Address, method: MethodNum, params: RawBytes) -> Call {
+    Call {
+        wrapper,
+        to,
+        method,
+        params,
+    }
+}
+
+/// Deploys `ActorCallTest` and gives it a miner and some market escrow, so that the
+/// state-changing methods succeed when called without the flag.
+fn setup() -> (Harness, Contract, Address, Address) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, ACTOR_CALL_TEST_ARTIFACT);
+
+    let (miner, worker) = harness.create_miner(sender[0].1, contract.address);
+
+    let res = harness.send(
+        sender[0].1,
+        STORAGE_MARKET_ACTOR_ADDR,
+        method_nums::market::ADD_BALANCE,
+        RawBytes::serialize(contract.address).unwrap(),
+        TokenAmount::from_atto(1000),
+    );
+    assert_eq!(res.msg_receipt.exit_code.value(), 0);
+
+    (harness, contract, miner, worker)
+}
+
+fn getters(contract: Address, sender: Address, miner: Address, worker: Address) -> Vec<Call> {
+    let mut calls = vec![call(
+        "get_balance",
+        STORAGE_MARKET_ACTOR_ADDR,
+        method_nums::market::GET_BALANCE,
+        RawBytes::serialize(contract).unwrap(),
+    )];
+
+    for (wrapper, method) in [
+        ("get_owner", method_nums::miner::GET_OWNER),
+        ("get_sector_size", method_nums::miner::GET_SECTOR_SIZE),
+        (
+            "get_available_balance",
+            method_nums::miner::GET_AVAILABLE_BALANCE,
+        ),
+        ("get_vesting_funds", method_nums::miner::GET_VESTING_FUNDS),
+        ("get_beneficiary", method_nums::miner::GET_BENEFICIARY),
+        ("get_peer_id", method_nums::miner::GET_PEER_ID),
+        ("get_multiaddresses", method_nums::miner::GET_MULTIADDRS),
+    ] {
+        calls.push(call(wrapper, miner, method, RawBytes::default()));
+    }
+    calls.push(call(
+        "is_controlling_address",
+        miner,
+        method_nums::miner::IS_CONTROLLING_ADDRESS,
+        RawBytes::serialize(worker).unwrap(),
+    ));
+
+    for (wrapper, method) in [
+        ("miner_count", method_nums::power::MINER_COUNT),
+        (
+            "miner_consensus_count",
+            method_nums::power::MINER_CONSENSUS_COUNT,
+        ),
+        ("network_raw_power", method_nums::power::NETWORK_RAW_POWER),
+    ] {
+        calls.push(call(
+            wrapper,
+            STORAGE_POWER_ACTOR_ADDR,
+            method,
+            RawBytes::default(),
+        ));
+    }
+    calls.push(call(
+        "miner_raw_power",
+        STORAGE_POWER_ACTOR_ADDR,
+        method_nums::power::MINER_RAW_POWER,
+        RawBytes::serialize(MinerRawPowerParams {
+            miner: miner.id().unwrap(),
+        })
+        .unwrap(),
+    ));
+
+    for (wrapper, method) in [
+        ("name", method_nums::datacap::NAME),
+        ("symbol", method_nums::datacap::SYMBOL),
+        ("total_supply", method_nums::datacap::TOTAL_SUPPLY),
+    ] {
+        calls.push(call(
+            wrapper,
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method,
+            RawBytes::default(),
+        ));
+    }
+    calls.push(call(
+        "balance",
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::BALANCE,
+        RawBytes::serialize(contract).unwrap(),
+    ));
+    calls.push(call(
+        "allowance",
+        DATACAP_TOKEN_ACTOR_ADDR,
+        method_nums::datacap::ALLOWANCE,
+        RawBytes::serialize(GetAllowanceParams {
+            owner: contract,
+            operator: sender,
+        })
+        .unwrap(),
+    ));
+
+    calls.push(call(
+        "get_claims",
+        VERIFIED_REGISTRY_ACTOR_ADDR,
+        method_nums::verifreg::GET_CLAIMS,
+        RawBytes::serialize(GetClaimsParams {
+            provider: miner.id().unwrap(),
+            claim_ids: vec![],
+        })
+        .unwrap(),
+    ));
+
+    calls
+}
+
+fn mutators(contract: Address, sender: Address, miner: Address, worker: Address) -> Vec<Call> {
+    vec![
+        call(
+            "change_peer_id",
+            miner,
+            method_nums::miner::CHANGE_PEER_ID,
+            RawBytes::serialize(ChangePeerIDParams {
+                new_id: vec![4, 5, 6],
+            })
+            .unwrap(),
+        ),
+        call(
+            "change_multiaddresses",
+            miner,
+            method_nums::miner::CHANGE_MULTIADDRS,
+            RawBytes::serialize(ChangeMultiaddrsParams {
+                new_multi_addrs: vec![BytesDe(vec![4, 5, 6])],
+            })
+            .unwrap(),
+        ),
+        call(
+            "withdraw_balance",
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::WITHDRAW_BALANCE,
+            RawBytes::serialize(WithdrawBalanceParams {
+                provider_or_client: contract,
+                amount: TokenAmount::from_atto(100),
+            })
+            .unwrap(),
+        ),
+        call(
+            "increase_allowance",
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::INCREASE_ALLOWANCE,
+            RawBytes::serialize(IncreaseAllowanceParams {
+                operator: sender,
+                increase: TokenAmount::from_atto(100),
+            })
+            .unwrap(),
+        ),
+        call(
+            "create_miner",
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::CREATE_MINER,
+            RawBytes::serialize(create_miner_params(contract, worker)).unwrap(),
+        ),
+    ]
+}
+
+/// Returns whether the getter behaves the same under the read-only flag.
+fn getter(harness: &mut Harness, contract: &Contract, call: &Call) -> Result<bool, String> {
+    let sender = harness.accounts[0].1;
+
+    let default = harness.call_actor(
+        sender,
+        contract,
+        &call.to,
+        call.method,
+        &call.params,
+        DEFAULT_FLAG,
+    );
+    if default.msg_receipt.exit_code.value() != 0 {
+        return Err(format!("fails without the flag: {}", failure(&default)));
+    }
+
+    let read_only = harness.call_read_only(sender, contract, &call.to, call.method, &call.params);
+    if read_only.msg_receipt.exit_code.value() != 0 {
+        println!(
+            "  {} needs write access: {}",
+            call.wrapper,
+            failure(&read_only)
+        );
+        return Ok(false);
+    }
+
+    let expected = contract.decode("call_actor", &default);
+    let actual = contract.decode("call_actor", &read_only);
+    if expected != actual {
+        return Err(format!(
+            "returned {:?} under the flag instead of {:?}",
+            actual, expected
+        ));
+    }
+
+    Ok(true)
+}
+
+/// The read-only call has to fail and leave the actor as it was, the plain call has to go
+/// through.
+fn mutator(harness: &mut Harness, contract: &Contract, call: &Call) -> Result<(), String> {
+    let sender = harness.accounts[0].1;
+    let target = harness.lookup_id(&call.to).unwrap();
+
+    let before = harness.actor_state(target);
+    let read_only = harness.call_read_only(sender, contract, &call.to, call.method, &call.params);
+    let after = harness.actor_state(target);
+
+    if read_only.msg_receipt.exit_code.value() == 0 {
+        return Err("succeeded under the read-only flag".to_string());
+    }
+    if before != after {
+        return Err(format!(
+            "failed under the read-only flag but changed {}",
+            call.to
+        ));
+    }
+
+    let default = harness.call_actor(
+        sender,
+        contract,
+        &call.to,
+        call.method,
+        &call.params,
+        DEFAULT_FLAG,
+    );
+    if default.msg_receipt.exit_code.value() != 0 {
+        return Err(format!("fails without the flag too: {}", failure(&default)));
+    }
+    if harness.actor_state(target) == after {
+        return Err(format!("did not change {} without the flag", call.to));
+    }
+
+    Ok(())
+}
+
+/// Funds cannot be sent under the read-only flag, not even along with a getter.
+fn value(harness: &mut Harness, contract: &Contract, call: &Call) -> Result<(), String> {
+    let sender = harness.accounts[0].1;
+    let target = harness.lookup_id(&call.to).unwrap();
+
+    let before = harness.actor_state(target);
+    let res = harness.call(
+        sender,
+        contract,
+        "call_actor",
+        &call_actor_args(&call.to, call.method, &call.params, READ_ONLY_FLAG),
+        TokenAmount::from_atto(100),
+    );
+    let after = harness.actor_state(target);
+
+    if res.msg_receipt.exit_code.value() == 0 {
+        return Err("sent funds under the read-only flag".to_string());
+    }
+    if before.map(|actor| actor.balance) != after.map(|actor| actor.balance) {
+        return Err(format!("failed but {} balance changed", call.to));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    println!("Calling the actors behind the API wrappers with the read-only flag");
+
+    let (mut harness, contract, miner, worker) = setup();
+    let sender = harness.accounts[0].1;
+
+    let mut results = vec![];
+    let mut views = vec![];
+
+    println!("Checking getters");
+    let getters = getters(contract.address, sender, miner, worker);
+    for call in &getters {
+        let outcome = getter(&mut harness, &contract, call);
+        if let Ok(true) = outcome {
+            views.push(call.wrapper);
+        }
+        check(&mut results, call.wrapper.to_string(), outcome.map(|_| ()));
+    }
+
+    println!("Checking state-changing methods");
+    for call in &mutators(contract.address, sender, miner, worker) {
+        let outcome = mutator(&mut harness, &contract, call);
+        check(&mut results, call.wrapper.to_string(), outcome);
+    }
+
+    println!("Checking value under the flag");
+    let outcome = value(&mut harness, &contract, &getters[0]);
+    check(
+        &mut results,
+        format!("{} with value", getters[0].wrapper),
+        outcome,
+    );
+
+    println!(
+        "{} of {} getters work read-only and could be view functions: {}",
+        views.len(),
+        getters.len(),
+        views.join(", ")
+    );
+
+    let failures = report("readonly", &results);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
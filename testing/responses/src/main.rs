@@ -0,0 +1,172 @@
+//! Large-response suite for the fixed `Actor.call` response buffer.
+//!
+//! `Actor.call` hands the precompile a fixed output buffer, so variable-size returns (peer
+//! IDs, multiaddrs, deal labels, claim batches) are cut once they grow past it. Each of
+//! them is grown step by step and compared with the native actor until the API stops
+//! matching. Breaks explained by the response buffer are reported; anything else fails the
+//! suite.
+
+mod market;
+mod miner;
+mod verifreg;
+
+use std::process::exit;
+
+use fvm_shared::address::Address;
+use fvm_shared::ActorID;
+use harness::differential::{Case, Differential};
+use harness::precompile::{CallActorInput, MAX_RETURN_SIZE};
+use harness::{Contract, Harness};
+
+/// Deploys the API contract at `artifact` in a fresh world with a single account. `genesis`
+/// runs before the machine is instantiated, while the state tree can still be edited.
+fn deploy<F: Fn(&mut Harness)>(artifact: &str, genesis: F) -> (Harness, Contract) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    genesis(&mut harness);
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, artifact);
+
+    (harness, contract)
+}
+
+/// The step at which a growing return stopped matching the native actor.
+struct Break {
+    step: usize,
+    return_size: usize,
+    input_size: usize,
+    difference: String,
+}
+
+impl Break {
+    /// Why the call broke, if the fixed buffer explains it.
+    fn cause(&self) -> Option<&'static str> {
+        if self.return_size > MAX_RETURN_SIZE {
+            Some("return data cut by the response buffer")
+        } else {
+            None
+        }
+    }
+}
+
+pub struct Growth {
+    function: &'static str,
+    /// What grows at each step, e.g. "label bytes".
+    unit: &'static str,
+    /// Largest step that still matched, with the size of the native return.
+    passed: Option<(usize, usize)>,
+    broke: Option<Break>,
+}
+
+impl Growth {
+    /// Compares the case built for each step until the first difference.
+    pub fn run<F>(
+        differential: &mut Differential,
+        function: &'static str,
+        unit: &'static str,
+        steps: impl IntoIterator<Item = usize>,
+        mut case: F,
+    ) -> Self
+    where
+        F: FnMut(&mut Differential, usize) -> Case,
+    {
+        println!("Growing `{}`", function);
+
+        let mut growth = Growth {
+            function,
+            unit,
+            passed: None,
+            broke: None,
+        };
+
+        for step in steps {
+            let case = case(differential, step);
+            let input_size = CallActorInput::new(
+                case.method,
+                Default::default(),
+                case.params.to_vec(),
+                case.to.to_bytes(),
+            )
+            .size();
+
+            let (native, outcome) = differential.compare(case);
+            let return_size = native.msg_receipt.return_data.len();
+
+            let difference = match (native.msg_receipt.exit_code.value(), outcome) {
+                (0, Ok(())) => {
+                    growth.passed = Some((step, return_size));
+                    continue;
+                }
+                (0, Err(difference)) => difference,
+                (code, _) => format!("native call failed with exit code {}", code),
+            };
+
+            growth.broke = Some(Break {
+                step,
+                return_size,
+                input_size,
+                difference,
+            });
+            break;
+        }
+
+        growth
+    }
+
+    /// Prints one line for the return and whether it broke. Returns true if the break is
+    /// not explained by the fixed buffer.
+    fn report(&self) -> bool {
+        let passed = match self.passed {
+            Some((step, size)) => format!("{} {} ({} bytes returned)", step, self.unit, size),
+            None => "nothing".to_string(),
+        };
+
+        let broke = match &self.broke {
+            Some(broke) => broke,
+            None => {
+                println!("  {}: ok up to {}", self.function, passed);
+                return false;
+            }
+        };
+
+        println!(
+            "  {}: ok up to {}, breaks at {} {} ({} bytes returned, {} bytes input): {}",
+            self.function,
+            passed,
+            broke.step,
+            self.unit,
+            broke.return_size,
+            broke.input_size,
+            broke.cause().unwrap_or("UNEXPLAINED")
+        );
+
+        if broke.cause().is_none() {
+            println!("    {}", broke.difference);
+            return true;
+        }
+
+        false
+    }
+}
+
+fn main() {
+    println!("Growing variable-size returns past the Actor.call response buffer");
+
+    let growths: Vec<Growth> = [miner::run(), market::run(), verifreg::run()]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    println!("Room for {} bytes of return data", MAX_RETURN_SIZE);
+    let unexplained = growths.iter().filter(|growth| growth.report()).count();
+
+    if unexplained > 0 {
+        println!("{} returns broke below the buffer limit", unexplained);
+        exit(1);
+    }
+}
@@ -0,0 +1,59 @@
+use ethabi::Token;
+use fil_actor_market::DealQueryParams;
+use fil_actors_runtime::STORAGE_MARKET_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::deal::DealID;
+use harness::deals::{deal_proposal, insert_deals};
+use harness::differential::{Case, Differential};
+use harness::method_nums;
+use harness::tokens::uint;
+use harness::Harness;
+
+use crate::{deploy, Growth};
+
+const MARKET_API_ARTIFACT: &str = "../../build/v0.8/MarketAPI";
+
+const LABEL_STEP: usize = 16;
+const MAX_LABEL_LEN: usize = 1024;
+
+/// Deal `i` has a label of `i * LABEL_STEP` bytes.
+fn label(id: DealID) -> String {
+    "a".repeat(id as usize * LABEL_STEP)
+}
+
+/// Deals `0..=MAX_LABEL_LEN / LABEL_STEP`, one for every label length.
+fn insert_label_deals(harness: &mut Harness) {
+    let client = harness.accounts[0].1;
+    let deals = (MAX_LABEL_LEN / LABEL_STEP) as DealID;
+
+    let proposals = (0..=deals)
+        .map(|id| deal_proposal(client, label(id)))
+        .collect();
+    insert_deals(harness, proposals);
+}
+
+pub fn run() -> Vec<Growth> {
+    println!("Growing MarketAPI returns");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy(MARKET_API_ARTIFACT, insert_label_deals);
+        (harness, contract, ())
+    });
+
+    let labels = Growth::run(
+        &mut differential,
+        "get_deal_label",
+        "label bytes",
+        (0..=MAX_LABEL_LEN).step_by(LABEL_STEP),
+        |_, len| {
+            let id = (len / LABEL_STEP) as DealID;
+            Case::new("get_deal_label", vec![Token::Tuple(vec![uint(id)])]).native(
+                STORAGE_MARKET_ACTOR_ADDR,
+                method_nums::market::GET_DEAL_LABEL,
+                RawBytes::serialize(DealQueryParams { id }).unwrap(),
+            )
+        },
+    );
+
+    vec![labels]
+}
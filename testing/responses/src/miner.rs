@@ -0,0 +1,91 @@
+use fil_actor_miner::{ChangeMultiaddrsParams, ChangePeerIDParams};
+use fvm_ipld_encoding::{BytesDe, RawBytes};
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::MethodNum;
+use harness::differential::{Case, Differential};
+use harness::method_nums;
+use harness::tokens::address;
+
+use crate::{deploy, Growth};
+
+const MINER_API_ARTIFACT: &str = "../../build/v0.8/MinerAPI";
+
+/// The miner actor refuses longer peer IDs.
+const MAX_PEER_ID_LEN: usize = 128;
+
+/// The miner actor refuses more multiaddr bytes in total.
+const MAX_MULTIADDR_DATA: usize = 1024;
+
+const MULTIADDR_LEN: usize = 16;
+
+/// Sends the same owner message to the miner in both worlds.
+fn change(differential: &mut Differential, miner: Address, method: MethodNum, params: RawBytes) {
+    let sender = differential.sender();
+
+    for harness in [&mut differential.api, &mut differential.native] {
+        let res = harness.send(sender, miner, method, params.clone(), TokenAmount::default());
+        assert_eq!(res.msg_receipt.exit_code.value(), 0);
+    }
+}
+
+pub fn run() -> Vec<Growth> {
+    println!("Growing MinerAPI returns");
+
+    let (mut differential, miner) = Differential::new(|| {
+        let (mut harness, contract) = deploy(MINER_API_ARTIFACT, |_| {});
+        let sender = harness.accounts[0].1;
+        let (miner, _) = harness.create_miner(sender, sender);
+        (harness, contract, miner)
+    });
+
+    let peer_id = Growth::run(
+        &mut differential,
+        "get_peer_id",
+        "peer ID bytes",
+        (8..=MAX_PEER_ID_LEN).step_by(8),
+        |differential, len| {
+            let params = ChangePeerIDParams {
+                new_id: vec![0xaa; len],
+            };
+            change(
+                differential,
+                miner,
+                method_nums::miner::CHANGE_PEER_ID,
+                RawBytes::serialize(params).unwrap(),
+            );
+
+            Case::new("get_peer_id", vec![address(&miner)]).native(
+                miner,
+                method_nums::miner::GET_PEER_ID,
+                RawBytes::default(),
+            )
+        },
+    );
+
+    let multiaddrs = Growth::run(
+        &mut differential,
+        "get_multiaddresses",
+        "multiaddrs",
+        1..=MAX_MULTIADDR_DATA / MULTIADDR_LEN,
+        |differential, count| {
+            let params = ChangeMultiaddrsParams {
+                new_multi_addrs: vec![BytesDe(vec![0xaa; MULTIADDR_LEN]); count],
+            };
+            change(
+                differential,
+                miner,
+                method_nums::miner::CHANGE_MULTIADDRS,
+                RawBytes::serialize(params).unwrap(),
+            );
+
+            Case::new("get_multiaddresses", vec![address(&miner)]).native(
+                miner,
+                method_nums::miner::GET_MULTIADDRS,
+                RawBytes::default(),
+            )
+        },
+    );
+
+    vec![peer_id, multiaddrs]
+}
@@ -0,0 +1,53 @@
+use ethabi::Token;
+use fil_actor_verifreg::GetClaimsParams;
+use fil_actors_runtime::VERIFIED_REGISTRY_ACTOR_ADDR;
+use fvm_ipld_encoding::RawBytes;
+use harness::differential::{Case, Differential};
+use harness::method_nums;
+use harness::tokens::uint;
+
+use crate::{deploy, Growth};
+
+const VERIFREG_API_ARTIFACT: &str = "../../build/v0.8/VerifRegAPI";
+
+const MAX_CLAIMS: usize = 150;
+
+pub fn run() -> Vec<Growth> {
+    println!("Growing VerifRegAPI returns");
+
+    let (mut differential, ()) = Differential::new(|| {
+        let (harness, contract) = deploy(VERIFREG_API_ARTIFACT, |_| {});
+        (harness, contract, ())
+    });
+
+    let provider = differential.api.accounts[0].0;
+
+    // The provider has no claims, so every ID adds a failure to the batch info
+    let claims = Growth::run(
+        &mut differential,
+        "get_claims",
+        "claim IDs",
+        0..=MAX_CLAIMS,
+        |_, count| {
+            let claim_ids: Vec<u64> = (0..count as u64).collect();
+            Case::new(
+                "get_claims",
+                vec![Token::Tuple(vec![
+                    uint(provider),
+                    Token::Array(claim_ids.iter().map(|id| uint(*id)).collect()),
+                ])],
+            )
+            .native(
+                VERIFIED_REGISTRY_ACTOR_ADDR,
+                method_nums::verifreg::GET_CLAIMS,
+                RawBytes::serialize(GetClaimsParams {
+                    provider,
+                    claim_ids,
+                })
+                .unwrap(),
+            )
+        },
+    );
+
+    vec![claims]
+}
@@ -0,0 +1,33 @@
+use crate::{field, method, Method, Struct, Type};
+
+const LIBRARY: &str = "Account";
+
+pub fn structs() -> Vec<Struct> {
+    vec![Struct::tuple(
+        LIBRARY,
+        "AuthenticateMessageParams",
+        "fil_actor_account::types::AuthenticateMessageParams",
+        vec![
+            field("signature", Type::Bytes),
+            field("message", Type::Bytes),
+        ],
+    )]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "AuthenticateMessage",
+            Some(Type::Struct("AuthenticateMessageParams")),
+            None,
+        ),
+        // Accepts anything, token transfers send these
+        method(
+            LIBRARY,
+            "Receive",
+            Some(Type::Struct("UniversalReceiverParams")),
+            None,
+        ),
+    ]
+}
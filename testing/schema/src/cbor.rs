@@ -0,0 +1,285 @@
+//! A reader for the DAG-CBOR subset the actors encode their params and returns in.
+//!
+//! Lengths are always definite and tags other than 42 (CIDs) don't occur, but both are
+//! read anyway so that a payload off the wire can still be shown. Indefinite lengths are
+//! refused.
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    Uint(u64),
+    /// `-1 - n`, which may not fit an `i64`.
+    Negative(u64),
+    Bytes(Vec<u8>),
+    Text(String),
+    Array(Vec<Item>),
+    Map(Vec<(Item, Item)>),
+    Tag(u64, Box<Item>),
+    Bool(bool),
+    Null,
+    Undefined,
+    Float(f64),
+    Simple(u8),
+}
+
+impl Item {
+    /// Name of the kind of item, for error messages.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Item::Uint(_) => "unsigned integer",
+            Item::Negative(_) => "negative integer",
+            Item::Bytes(_) => "byte string",
+            Item::Text(_) => "text string",
+            Item::Array(_) => "array",
+            Item::Map(_) => "map",
+            Item::Tag(..) => "tag",
+            Item::Bool(_) => "bool",
+            Item::Null => "null",
+            Item::Undefined => "undefined",
+            Item::Float(_) => "float",
+            Item::Simple(_) => "simple value",
+        }
+    }
+}
+
+/// Nesting deeper than this is refused rather than overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+struct Reader<'a> {
+    input: &'a [u8],
+    idx: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], String> {
+        let end = self
+            .idx
+            .checked_add(len)
+            .filter(|end| *end <= self.input.len())
+            .ok_or_else(|| format!("unexpected end of input at byte {}", self.idx))?;
+        let bytes = &self.input[self.idx..end];
+        self.idx = end;
+        Ok(bytes)
+    }
+
+    fn uint(&mut self, len: usize) -> Result<u64, String> {
+        Ok(self
+            .take(len)?
+            .iter()
+            .fold(0, |value, byte| value << 8 | u64::from(*byte)))
+    }
+
+    /// The argument of the header: the value, length or tag number.
+    fn argument(&mut self, info: u8) -> Result<Option<u64>, String> {
+        match info {
+            0..=23 => Ok(Some(info.into())),
+            24 => self.uint(1).map(Some),
+            25 => self.uint(2).map(Some),
+            26 => self.uint(4).map(Some),
+            27 => self.uint(8).map(Some),
+            31 => Ok(None),
+            _ => Err(format!(
+                "reserved additional info {} at byte {}",
+                info,
+                self.idx - 1
+            )),
+        }
+    }
+
+    fn length(&mut self, info: u8) -> Result<usize, String> {
+        let at = self.idx - 1;
+        match self.argument(info)? {
+            // Every item takes at least a byte, longer can't be valid
+            Some(len) if len <= (self.input.len() - self.idx) as u64 => Ok(len as usize),
+            Some(len) => Err(format!(
+                "length {} past the end of input at byte {}",
+                len, at
+            )),
+            None => Err(format!("indefinite length at byte {}", at)),
+        }
+    }
+
+    fn item(&mut self, depth: usize) -> Result<Item, String> {
+        if depth > MAX_DEPTH {
+            return Err(format!(
+                "nested deeper than {} at byte {}",
+                MAX_DEPTH, self.idx
+            ));
+        }
+
+        let at = self.idx;
+        let header = self.take(1)?[0];
+        let (major, info) = (header >> 5, header & 0x1f);
+
+        let item = match major {
+            0 => Item::Uint(self.argument(info)?.ok_or("indefinite integer")?),
+            1 => Item::Negative(self.argument(info)?.ok_or("indefinite integer")?),
+            2 => {
+                let len = self.length(info)?;
+                Item::Bytes(self.take(len)?.to_vec())
+            }
+            3 => {
+                let len = self.length(info)?;
+                let text = String::from_utf8(self.take(len)?.to_vec())
+                    .map_err(|_| format!("invalid UTF-8 in text string at byte {}", at))?;
+                Item::Text(text)
+            }
+            4 => {
+                let len = self.length(info)?;
+                let items = (0..len)
+                    .map(|_| self.item(depth + 1))
+                    .collect::<Result<_, _>>()?;
+                Item::Array(items)
+            }
+            5 => {
+                let len = self.length(info)?;
+                let entries = (0..len)
+                    .map(|_| Ok((self.item(depth + 1)?, self.item(depth + 1)?)))
+                    .collect::<Result<_, String>>()?;
+                Item::Map(entries)
+            }
+            6 => {
+                let tag = self.argument(info)?.ok_or("indefinite tag")?;
+                Item::Tag(tag, Box::new(self.item(depth + 1)?))
+            }
+            _ => match info {
+                20 => Item::Bool(false),
+                21 => Item::Bool(true),
+                22 => Item::Null,
+                23 => Item::Undefined,
+                24 => Item::Simple(self.take(1)?[0]),
+                25 => Item::Float(half(self.uint(2)? as u16)),
+                26 => Item::Float(f32::from_bits(self.uint(4)? as u32).into()),
+                27 => Item::Float(f64::from_bits(self.uint(8)?)),
+                0..=19 => Item::Simple(info),
+                _ => return Err(format!("unexpected break or reserved value at byte {}", at)),
+            },
+        };
+
+        Ok(item)
+    }
+}
+
+fn half(bits: u16) -> f64 {
+    let sign = if bits & 0x8000 != 0 { -1.0 } else { 1.0 };
+    let exponent = i32::from((bits >> 10) & 0x1f);
+    let mantissa = f64::from(bits & 0x3ff);
+
+    sign * match exponent {
+        0 => mantissa * 2f64.powi(-24),
+        31 if mantissa == 0.0 => f64::INFINITY,
+        31 => f64::NAN,
+        _ => (1.0 + mantissa / 1024.0) * 2f64.powi(exponent - 15),
+    }
+}
+
+/// Reads a single item spanning the whole input.
+pub fn decode(input: &[u8]) -> Result<Item, String> {
+    let mut reader = Reader { input, idx: 0 };
+    let item = reader.item(0)?;
+
+    if reader.idx != input.len() {
+        return Err(format!(
+            "{} trailing bytes after the item",
+            input.len() - reader.idx
+        ));
+    }
+
+    Ok(item)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_nested_items() {
+        // [1, -2, h'ff', "a", {0: null}, 42(h'00'), true]
+        let input = [
+            0x87, 0x01, 0x21, 0x41, 0xff, 0x61, 0x61, 0xa1, 0x00, 0xf6, 0xd8, 0x2a, 0x41, 0x00,
+            0xf5,
+        ];
+
+        assert_eq!(
+            decode(&input),
+            Ok(Item::Array(vec![
+                Item::Uint(1),
+                Item::Negative(1),
+                Item::Bytes(vec![0xff]),
+                Item::Text("a".to_string()),
+                Item::Map(vec![(Item::Uint(0), Item::Null)]),
+                Item::Tag(42, Box::new(Item::Bytes(vec![0x00]))),
+                Item::Bool(true),
+            ]))
+        );
+    }
+
+    #[test]
+    fn reads_every_argument_width() {
+        assert_eq!(decode(&[0x17]), Ok(Item::Uint(23)));
+        assert_eq!(decode(&[0x18, 0x18]), Ok(Item::Uint(24)));
+        assert_eq!(decode(&[0x19, 0x01, 0x00]), Ok(Item::Uint(256)));
+        assert_eq!(
+            decode(&[0x1a, 0x00, 0x01, 0x00, 0x00]),
+            Ok(Item::Uint(65536))
+        );
+        assert_eq!(
+            decode(&[0x3b, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]),
+            Ok(Item::Negative(u64::MAX))
+        );
+        assert_eq!(decode(&[0xf9, 0x3c, 0x00]), Ok(Item::Float(1.0)));
+    }
+
+    #[test]
+    fn refuses_malformed_input() {
+        assert_eq!(
+            decode(&[0x01, 0x02]),
+            Err("1 trailing bytes after the item".to_string())
+        );
+        assert_eq!(
+            decode(&[0x19, 0x01]),
+            Err("unexpected end of input at byte 1".to_string())
+        );
+        assert_eq!(
+            decode(&[0x9f, 0x01, 0xff]),
+            Err("indefinite length at byte 0".to_string())
+        );
+        assert_eq!(
+            decode(&[0x43, 0x01]),
+            Err("length 3 past the end of input at byte 0".to_string())
+        );
+        assert_eq!(
+            decode(&[0x1c]),
+            Err("reserved additional info 28 at byte 0".to_string())
+        );
+        assert_eq!(
+            decode(&[0x62, 0xc3, 0x28]),
+            Err("invalid UTF-8 in text string at byte 0".to_string())
+        );
+        assert_eq!(
+            decode(&[0xff]),
+            Err("unexpected break or reserved value at byte 0".to_string())
+        );
+    }
+
+    #[test]
+    fn refuses_deep_nesting() {
+        let input = vec![0x81; MAX_DEPTH + 1]
+            .into_iter()
+            .chain([0x00])
+            .collect::<Vec<_>>();
+        assert_eq!(
+            decode(&input),
+            Err(format!(
+                "nested deeper than {} at byte {}",
+                MAX_DEPTH,
+                MAX_DEPTH + 1
+            ))
+        );
+
+        let input = vec![0x81; MAX_DEPTH]
+            .into_iter()
+            .chain([0x00])
+            .collect::<Vec<_>>();
+        assert!(decode(&input).is_ok());
+    }
+}
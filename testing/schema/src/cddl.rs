@@ -0,0 +1,180 @@
+//! The schema as CDDL (RFC 8610), for tooling that reads or writes the payloads off-chain.
+//!
+//! Every method is listed in a comment with its params and return, followed by a rule per
+//! struct and enum and the prelude of the primitive types they use.
+
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+use crate::{Encoding, Schema, Type};
+
+/// Rules for the primitive types, other than the integer widths.
+const PRELUDE: &str = "\
+; A sign byte, 0 or 1, followed by the big endian magnitude. Empty for zero.
+bigint = bstr .size (0..128)
+
+; Protocol byte followed by the payload.
+address = bstr
+
+; The binary CID, after a 0x00 multibase prefix.
+cid = #6.42(bstr)
+
+; Deal labels are text, or bytes when not valid UTF-8.
+label = tstr / bstr
+";
+
+fn cddl_type(ty: &Type, widths: &mut BTreeSet<(bool, u16)>) -> String {
+    match ty {
+        Type::Bool => "bool".to_string(),
+        Type::Uint(bits) => {
+            widths.insert((false, *bits));
+            format!("uint{}", bits)
+        }
+        Type::Int(bits) => {
+            widths.insert((true, *bits));
+            format!("int{}", bits)
+        }
+        Type::BigInt => "bigint".to_string(),
+        Type::Bytes => "bstr".to_string(),
+        Type::Address => "address".to_string(),
+        Type::Cid => "cid".to_string(),
+        Type::String => "tstr".to_string(),
+        Type::Label => "label".to_string(),
+        Type::Array(item) => format!("[* {}]", cddl_type(item, widths)),
+        Type::Optional(value) => format!("{} / null", cddl_type(value, widths)),
+        Type::Struct(name) | Type::Enum(name) => name.to_string(),
+    }
+}
+
+fn width_rule(signed: bool, bits: u16) -> String {
+    if signed {
+        let max = (1u128 << (bits - 1)) - 1;
+        format!("int{} = -{}..{}", bits, max + 1, max)
+    } else {
+        format!("uint{} = uint .size {}", bits, bits / 8)
+    }
+}
+
+pub fn render(schema: &Schema) -> String {
+    let mut widths = BTreeSet::new();
+    let mut out = String::new();
+
+    writeln!(
+        out,
+        "; Params and returns of the builtin actor methods the APIs call."
+    )
+    .unwrap();
+
+    writeln!(out, ";").unwrap();
+    for method in &schema.methods {
+        let mut describe = |ty: &Option<Type>| match ty {
+            Some(ty) => cddl_type(ty, &mut widths),
+            None => "nothing".to_string(),
+        };
+        let params = describe(&method.params);
+        let ret = describe(&method.ret);
+        writeln!(
+            out,
+            "; {}.{}: {} -> {}",
+            method.actor, method.name, params, ret
+        )
+        .unwrap();
+    }
+
+    for library in schema.libraries() {
+        writeln!(out, "\n; {}\n", library).unwrap();
+
+        for e in schema.enums.iter().filter(|e| e.library == library) {
+            writeln!(out, "; {}", e.rust).unwrap();
+            let variants: Vec<String> = e
+                .variants
+                .iter()
+                .map(|variant| format!("{}: {}", variant.name, variant.code))
+                .collect();
+            writeln!(out, "{} = &(\n  {},\n)\n", e.name, variants.join(",\n  ")).unwrap();
+        }
+
+        for s in schema.structs.iter().filter(|s| s.library == library) {
+            writeln!(out, "; {}", s.rust).unwrap();
+            match s.encoding {
+                Encoding::Transparent => {
+                    let field = &s.fields[0];
+                    let ty = cddl_type(&field.ty, &mut widths);
+                    writeln!(out, "{} = {} ; {}\n", s.name, ty, field.name).unwrap();
+                }
+                Encoding::Tuple => {
+                    let fields: Vec<String> = s
+                        .fields
+                        .iter()
+                        .map(|field| {
+                            format!("{}: {}", field.name, cddl_type(&field.ty, &mut widths))
+                        })
+                        .collect();
+                    writeln!(out, "{} = [\n  {},\n]\n", s.name, fields.join(",\n  ")).unwrap();
+                }
+            }
+        }
+    }
+
+    writeln!(out, "; Primitives\n").unwrap();
+    for (signed, bits) in widths {
+        writeln!(out, "{}", width_rule(signed, bits)).unwrap();
+    }
+    write!(out, "\n{}", PRELUDE).unwrap();
+
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_structs_as_arrays() {
+        let cddl = render(&Schema::new());
+
+        assert!(cddl.contains(
+            "; fil_actor_market::WithdrawBalanceParams\n\
+             WithdrawBalanceParams = [\n  \
+             provider_or_client: address,\n  \
+             amount: bigint,\n\
+             ]\n"
+        ));
+        assert!(
+            cddl.contains("; fil_actor_market::DealQueryParams\nDealQueryParams = uint64 ; id\n")
+        );
+    }
+
+    #[test]
+    fn renders_enums_as_choices() {
+        let cddl = render(&Schema::new());
+
+        assert!(cddl.contains("SectorSize = &(\n  _2KiB: 2048,\n"));
+    }
+
+    #[test]
+    fn lists_every_method() {
+        let schema = Schema::new();
+        let cddl = render(&schema);
+
+        assert!(cddl.contains(
+            "; Market.WithdrawBalance: WithdrawBalanceParams -> WithdrawBalanceReturn\n"
+        ));
+        assert!(cddl.contains("; Miner.GetSectorSize: nothing -> GetSectorSizeReturn\n"));
+        for method in &schema.methods {
+            assert!(cddl.contains(&format!("; {}.{}: ", method.actor, method.name)));
+        }
+    }
+
+    #[test]
+    fn width_rules() {
+        assert_eq!(width_rule(false, 32), "uint32 = uint .size 4");
+        assert_eq!(
+            width_rule(true, 64),
+            "int64 = -9223372036854775808..9223372036854775807"
+        );
+
+        let cddl = render(&Schema::new());
+        assert!(cddl.contains("\nuint64 = uint .size 8\n"));
+    }
+}
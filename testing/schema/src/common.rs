@@ -0,0 +1,186 @@
+//! Types shared by several actors, kept together as in `CommonTypes.sol`.
+
+use crate::{field, Enum, Struct, Type, Variant, ACTOR_ID, CHAIN_EPOCH, COMMON, EXIT_CODE};
+
+fn variants(variants: &[(&'static str, u64)]) -> Vec<Variant> {
+    variants
+        .iter()
+        .map(|(name, code)| Variant { name, code: *code })
+        .collect()
+}
+
+/// Variants are listed by code. `fvm_shared` declares the 8MiB and 512MiB seal proofs the
+/// other way round, their codes follow the sizes.
+pub fn enums() -> Vec<Enum> {
+    vec![
+        Enum {
+            name: "RegisteredSealProof",
+            library: COMMON,
+            rust: "fvm_shared::sector::RegisteredSealProof",
+            variants: variants(&[
+                ("StackedDRG2KiBV1", 0),
+                ("StackedDRG8MiBV1", 1),
+                ("StackedDRG512MiBV1", 2),
+                ("StackedDRG32GiBV1", 3),
+                ("StackedDRG64GiBV1", 4),
+                ("StackedDRG2KiBV1P1", 5),
+                ("StackedDRG8MiBV1P1", 6),
+                ("StackedDRG512MiBV1P1", 7),
+                ("StackedDRG32GiBV1P1", 8),
+                ("StackedDRG64GiBV1P1", 9),
+            ]),
+        },
+        Enum {
+            name: "RegisteredPoStProof",
+            library: COMMON,
+            rust: "fvm_shared::sector::RegisteredPoStProof",
+            variants: variants(&[
+                ("StackedDRGWinning2KiBV1", 0),
+                ("StackedDRGWinning8MiBV1", 1),
+                ("StackedDRGWinning512MiBV1", 2),
+                ("StackedDRGWinning32GiBV1", 3),
+                ("StackedDRGWinning64GiBV1", 4),
+                ("StackedDRGWindow2KiBV1", 5),
+                ("StackedDRGWindow8MiBV1", 6),
+                ("StackedDRGWindow512MiBV1", 7),
+                ("StackedDRGWindow32GiBV1", 8),
+                ("StackedDRGWindow64GiBV1", 9),
+            ]),
+        },
+        Enum {
+            name: "RegisteredUpdateProof",
+            library: COMMON,
+            rust: "fvm_shared::sector::RegisteredUpdateProof",
+            variants: variants(&[
+                ("StackedDRG2KiBV1", 0),
+                ("StackedDRG8MiBV1", 1),
+                ("StackedDRG512MiBV1", 2),
+                ("StackedDRG32GiBV1", 3),
+                ("StackedDRG64GiBV1", 4),
+            ]),
+        },
+        // Encoded as the size in bytes
+        Enum {
+            name: "SectorSize",
+            library: COMMON,
+            rust: "fvm_shared::sector::SectorSize",
+            variants: variants(&[
+                ("_2KiB", 2 << 10),
+                ("_8MiB", 8 << 20),
+                ("_512MiB", 512 << 20),
+                ("_32GiB", 32 << 30),
+                ("_64GiB", 64 << 30),
+            ]),
+        },
+    ]
+}
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            COMMON,
+            "DealProposal",
+            "fil_actor_market::DealProposal",
+            vec![
+                field("piece_cid", Type::Cid),
+                field("piece_size", Type::Uint(64)),
+                field("verified_deal", Type::Bool),
+                field("client", Type::Address),
+                field("provider", Type::Address),
+                field("label", Type::Label),
+                field("start_epoch", CHAIN_EPOCH),
+                field("end_epoch", CHAIN_EPOCH),
+                field("storage_price_per_epoch", Type::BigInt),
+                field("provider_collateral", Type::BigInt),
+                field("client_collateral", Type::BigInt),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "ClientDealProposal",
+            "fil_actor_market::ClientDealProposal",
+            vec![
+                field("proposal", Type::Struct("DealProposal")),
+                field("client_signature", Type::Bytes),
+            ],
+        ),
+        // A `(ChainEpoch, TokenAmount)` pair natively
+        Struct::tuple(
+            COMMON,
+            "VestingFunds",
+            "(fvm_shared::clock::ChainEpoch, fvm_shared::econ::TokenAmount)",
+            vec![field("epoch", CHAIN_EPOCH), field("amount", Type::BigInt)],
+        ),
+        Struct::tuple(
+            COMMON,
+            "PendingBeneficiaryChange",
+            "fil_actor_miner::PendingBeneficiaryChange",
+            vec![
+                field("new_beneficiary", Type::Address),
+                field("new_quota", Type::BigInt),
+                field("new_expiration", CHAIN_EPOCH),
+                field("approved_by_beneficiary", Type::Bool),
+                field("approved_by_nominee", Type::Bool),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "BeneficiaryTerm",
+            "fil_actor_miner::BeneficiaryTerm",
+            vec![
+                field("quota", Type::BigInt),
+                field("used_quota", Type::BigInt),
+                field("expiration", CHAIN_EPOCH),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "ActiveBeneficiary",
+            "fil_actor_miner::ActiveBeneficiary",
+            vec![
+                field("beneficiary", Type::Address),
+                field("term", Type::Struct("BeneficiaryTerm")),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "FailCode",
+            "fil_actors_runtime::FailCode",
+            vec![field("idx", Type::Uint(32)), field("code", EXIT_CODE)],
+        ),
+        Struct::tuple(
+            COMMON,
+            "BatchReturn",
+            "fil_actors_runtime::BatchReturn",
+            vec![
+                field("success_count", Type::Uint(32)),
+                field("fail_codes", Type::array(Type::Struct("FailCode"))),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "Claim",
+            "fil_actor_verifreg::Claim",
+            vec![
+                field("provider", ACTOR_ID),
+                field("client", ACTOR_ID),
+                field("data", Type::Cid),
+                field("size", Type::Uint(64)),
+                field("term_min", CHAIN_EPOCH),
+                field("term_max", CHAIN_EPOCH),
+                field("term_start", CHAIN_EPOCH),
+                field("sector", Type::Uint(64)),
+            ],
+        ),
+        Struct::tuple(
+            COMMON,
+            "ClaimTerm",
+            "fil_actor_verifreg::ClaimTerm",
+            vec![
+                field("provider", ACTOR_ID),
+                field("claim_id", Type::Uint(64)),
+                field("term_max", CHAIN_EPOCH),
+            ],
+        ),
+    ]
+}
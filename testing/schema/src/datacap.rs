@@ -0,0 +1,206 @@
+use crate::{field, method, Method, Struct, Type};
+
+const LIBRARY: &str = "DataCap";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "MintParams",
+            "fil_actor_datacap::MintParams",
+            vec![
+                field("to", Type::Address),
+                field("amount", Type::BigInt),
+                field("operators", Type::array(Type::Address)),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "MintReturn",
+            "frc46_token::token::types::MintReturn",
+            vec![
+                field("balance", Type::BigInt),
+                field("supply", Type::BigInt),
+                field("recipient_data", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "DestroyParams",
+            "fil_actor_datacap::DestroyParams",
+            vec![field("owner", Type::Address), field("amount", Type::BigInt)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetAllowanceParams",
+            "frc46_token::token::types::GetAllowanceParams",
+            vec![
+                field("owner", Type::Address),
+                field("operator", Type::Address),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "TransferParams",
+            "frc46_token::token::types::TransferParams",
+            vec![
+                field("to", Type::Address),
+                field("amount", Type::BigInt),
+                field("operator_data", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "TransferReturn",
+            "frc46_token::token::types::TransferReturn",
+            vec![
+                field("from_balance", Type::BigInt),
+                field("to_balance", Type::BigInt),
+                field("recipient_data", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "TransferFromParams",
+            "frc46_token::token::types::TransferFromParams",
+            vec![
+                field("from", Type::Address),
+                field("to", Type::Address),
+                field("amount", Type::BigInt),
+                field("operator_data", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "TransferFromReturn",
+            "frc46_token::token::types::TransferFromReturn",
+            vec![
+                field("from_balance", Type::BigInt),
+                field("to_balance", Type::BigInt),
+                field("allowance", Type::BigInt),
+                field("recipient_data", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "IncreaseAllowanceParams",
+            "frc46_token::token::types::IncreaseAllowanceParams",
+            vec![
+                field("operator", Type::Address),
+                field("increase", Type::BigInt),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "DecreaseAllowanceParams",
+            "frc46_token::token::types::DecreaseAllowanceParams",
+            vec![
+                field("operator", Type::Address),
+                field("decrease", Type::BigInt),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RevokeAllowanceParams",
+            "frc46_token::token::types::RevokeAllowanceParams",
+            vec![field("operator", Type::Address)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "BurnParams",
+            "frc46_token::token::types::BurnParams",
+            vec![field("amount", Type::BigInt)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "BurnReturn",
+            "frc46_token::token::types::BurnReturn",
+            vec![field("balance", Type::BigInt)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "BurnFromParams",
+            "frc46_token::token::types::BurnFromParams",
+            vec![field("owner", Type::Address), field("amount", Type::BigInt)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "BurnFromReturn",
+            "frc46_token::token::types::BurnFromReturn",
+            vec![
+                field("balance", Type::BigInt),
+                field("allowance", Type::BigInt),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "Mint",
+            Some(Type::Struct("MintParams")),
+            Some(Type::Struct("MintReturn")),
+        ),
+        method(
+            LIBRARY,
+            "Destroy",
+            Some(Type::Struct("DestroyParams")),
+            Some(Type::Struct("BurnReturn")),
+        ),
+        method(LIBRARY, "Name", None, Some(Type::String)),
+        method(LIBRARY, "Symbol", None, Some(Type::String)),
+        method(LIBRARY, "Granularity", None, Some(Type::Uint(64))),
+        method(LIBRARY, "TotalSupply", None, Some(Type::BigInt)),
+        method(LIBRARY, "Balance", Some(Type::Address), Some(Type::BigInt)),
+        method(
+            LIBRARY,
+            "Transfer",
+            Some(Type::Struct("TransferParams")),
+            Some(Type::Struct("TransferReturn")),
+        ),
+        method(
+            LIBRARY,
+            "TransferFrom",
+            Some(Type::Struct("TransferFromParams")),
+            Some(Type::Struct("TransferFromReturn")),
+        ),
+        method(
+            LIBRARY,
+            "IncreaseAllowance",
+            Some(Type::Struct("IncreaseAllowanceParams")),
+            Some(Type::BigInt),
+        ),
+        method(
+            LIBRARY,
+            "DecreaseAllowance",
+            Some(Type::Struct("DecreaseAllowanceParams")),
+            Some(Type::BigInt),
+        ),
+        method(
+            LIBRARY,
+            "RevokeAllowance",
+            Some(Type::Struct("RevokeAllowanceParams")),
+            Some(Type::BigInt),
+        ),
+        method(
+            LIBRARY,
+            "Burn",
+            Some(Type::Struct("BurnParams")),
+            Some(Type::Struct("BurnReturn")),
+        ),
+        method(
+            LIBRARY,
+            "BurnFrom",
+            Some(Type::Struct("BurnFromParams")),
+            Some(Type::Struct("BurnFromReturn")),
+        ),
+        method(
+            LIBRARY,
+            "Allowance",
+            Some(Type::Struct("GetAllowanceParams")),
+            Some(Type::BigInt),
+        ),
+    ]
+}
@@ -0,0 +1,62 @@
+use crate::{field, method, Method, Struct, Type};
+
+const LIBRARY: &str = "Init";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "ExecParams",
+            "fil_actor_init::ExecParams",
+            vec![
+                field("code_cid", Type::Cid),
+                field("constructor_params", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ExecReturn",
+            "fil_actor_init::ExecReturn",
+            vec![
+                field("id_address", Type::Address),
+                field("robust_address", Type::Address),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "Exec4Params",
+            "fil_actor_init::Exec4Params",
+            vec![
+                field("code_cid", Type::Cid),
+                field("constructor_params", Type::Bytes),
+                field("subaddress", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "Exec4Return",
+            "fil_actor_init::Exec4Return",
+            vec![
+                field("id_address", Type::Address),
+                field("robust_address", Type::Address),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "Exec",
+            Some(Type::Struct("ExecParams")),
+            Some(Type::Struct("ExecReturn")),
+        ),
+        method(
+            LIBRARY,
+            "Exec4",
+            Some(Type::Struct("Exec4Params")),
+            Some(Type::Struct("Exec4Return")),
+        ),
+    ]
+}
@@ -0,0 +1,358 @@
+//! Wire format of the params and returns of the builtin actor methods the APIs call.
+//!
+//! Every struct is described as the actor crates encode it: its fields in order, the
+//! width and signedness of its integers, and whether it is a CBOR array (`Serialize_tuple`)
+//! or its single field encoded on its own (`#[serde(transparent)]`). Names are those of
+//! the Rust types, e.g. `DealQueryParams` where the Solidity side has a
+//! `GetDealClientParams` per getter.
+//!
+//! [`cddl`] exports the schema for off-chain tooling and [`Schema::validate`] checks
+//! payloads against it, read with [`cbor`].
+//!
+//! This crate has no dependencies, so that the script and the suites built against any FVM
+//! can use it.
+
+pub mod cbor;
+pub mod cddl;
+
+mod account;
+mod common;
+mod datacap;
+mod init;
+mod market;
+mod miner;
+mod multisig;
+mod power;
+mod validate;
+mod verifreg;
+
+pub use validate::Invalid;
+
+/// `ActorID`, `DealID`, `AllocationID`, `ClaimID`, `SectorNumber`, `PaddedPieceSize`.
+pub const ACTOR_ID: Type = Type::Uint(64);
+/// `ChainEpoch` and `TxnID`.
+pub const CHAIN_EPOCH: Type = Type::Int(64);
+/// `ExitCode`.
+pub const EXIT_CODE: Type = Type::Uint(32);
+
+/// Library the types shared by several actors live in, as in `CommonTypes.sol`.
+pub const COMMON: &str = "Common";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Bool,
+    /// Unsigned integer of the given width in bits.
+    Uint(u16),
+    /// Signed integer of the given width in bits.
+    Int(u16),
+    /// `TokenAmount`, `StoragePower` and `DataCap`: a sign byte followed by the big endian
+    /// magnitude, in a byte string.
+    BigInt,
+    /// `RawBytes`, signatures, bitfields, peer IDs and multiaddrs.
+    Bytes,
+    /// An address in its byte form.
+    Address,
+    /// A CID under tag 42.
+    Cid,
+    String,
+    /// A deal label, either a text or a byte string.
+    Label,
+    Array(Box<Type>),
+    /// `null` when absent.
+    Optional(Box<Type>),
+    Struct(&'static str),
+    Enum(&'static str),
+}
+
+impl Type {
+    pub fn array(item: Type) -> Self {
+        Type::Array(Box::new(item))
+    }
+
+    pub fn optional(value: Type) -> Self {
+        Type::Optional(Box::new(value))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// A CBOR array of the fields.
+    Tuple,
+    /// The single field, without an enclosing array.
+    Transparent,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Field {
+    pub name: &'static str,
+    pub ty: Type,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Struct {
+    pub name: &'static str,
+    /// Actor the type belongs to, e.g. `Market`, or [`COMMON`].
+    pub library: &'static str,
+    /// Path of the Rust type, e.g. `fil_actor_market::WithdrawBalanceParams`.
+    pub rust: &'static str,
+    pub encoding: Encoding,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Variant {
+    pub name: &'static str,
+    /// The number the variant is encoded as.
+    pub code: u64,
+}
+
+/// Enums are encoded as the unsigned integer code of their variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Enum {
+    pub name: &'static str,
+    pub library: &'static str,
+    pub rust: &'static str,
+    pub variants: Vec<Variant>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Method {
+    /// As in `script/src/actors.rs`, e.g. `VerifReg`.
+    pub actor: &'static str,
+    pub name: &'static str,
+    /// `None` when the method takes no params.
+    pub params: Option<Type>,
+    /// `None` when the method returns nothing.
+    pub ret: Option<Type>,
+}
+
+pub struct Schema {
+    pub structs: Vec<Struct>,
+    pub enums: Vec<Enum>,
+    pub methods: Vec<Method>,
+}
+
+pub(crate) fn field(name: &'static str, ty: Type) -> Field {
+    Field { name, ty }
+}
+
+pub(crate) fn method(
+    actor: &'static str,
+    name: &'static str,
+    params: Option<Type>,
+    ret: Option<Type>,
+) -> Method {
+    Method {
+        actor,
+        name,
+        params,
+        ret,
+    }
+}
+
+impl Struct {
+    pub(crate) fn tuple(
+        library: &'static str,
+        name: &'static str,
+        rust: &'static str,
+        fields: Vec<Field>,
+    ) -> Self {
+        Struct {
+            name,
+            library,
+            rust,
+            encoding: Encoding::Tuple,
+            fields,
+        }
+    }
+
+    pub(crate) fn transparent(
+        library: &'static str,
+        name: &'static str,
+        rust: &'static str,
+        field: Field,
+    ) -> Self {
+        Struct {
+            name,
+            library,
+            rust,
+            encoding: Encoding::Transparent,
+            fields: vec![field],
+        }
+    }
+}
+
+impl Enum {
+    /// The variant encoded as `code`.
+    pub fn variant(&self, code: u64) -> Option<&Variant> {
+        self.variants.iter().find(|variant| variant.code == code)
+    }
+}
+
+impl Schema {
+    pub fn new() -> Self {
+        let mut schema = Schema {
+            structs: vec![],
+            enums: common::enums(),
+            methods: vec![],
+        };
+
+        for (structs, methods) in [
+            (common::structs(), vec![]),
+            (market::structs(), market::methods()),
+            (miner::structs(), miner::methods()),
+            (power::structs(), power::methods()),
+            (verifreg::structs(), verifreg::methods()),
+            (datacap::structs(), datacap::methods()),
+            (init::structs(), init::methods()),
+            (account::structs(), account::methods()),
+            (multisig::structs(), multisig::methods()),
+        ] {
+            schema.structs.extend(structs);
+            schema.methods.extend(methods);
+        }
+
+        schema
+    }
+
+    pub fn find_struct(&self, name: &str) -> Option<&Struct> {
+        self.structs.iter().find(|s| s.name == name)
+    }
+
+    pub fn find_enum(&self, name: &str) -> Option<&Enum> {
+        self.enums.iter().find(|e| e.name == name)
+    }
+
+    pub fn method(&self, actor: &str, name: &str) -> Option<&Method> {
+        self.methods
+            .iter()
+            .find(|m| m.actor == actor && m.name == name)
+    }
+
+    /// Libraries in the order their types are listed, [`COMMON`] first.
+    pub fn libraries(&self) -> Vec<&'static str> {
+        let mut libraries = vec![];
+        for library in self.structs.iter().map(|s| s.library) {
+            if !libraries.contains(&library) {
+                libraries.push(library);
+            }
+        }
+        libraries
+    }
+
+    /// Names of the structs and enums `ty` refers to that aren't in the schema.
+    pub fn unknown_references(&self, ty: &Type) -> Vec<&'static str> {
+        match ty {
+            Type::Array(item) | Type::Optional(item) => self.unknown_references(item),
+            Type::Struct(name) if self.find_struct(name).is_none() => vec![name],
+            Type::Enum(name) if self.find_enum(name).is_none() => vec![name],
+            _ => vec![],
+        }
+    }
+}
+
+impl Default for Schema {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn references_are_known() {
+        let schema = Schema::new();
+
+        for s in &schema.structs {
+            for field in &s.fields {
+                assert_eq!(
+                    schema.unknown_references(&field.ty),
+                    Vec::<&str>::new(),
+                    "{}.{}",
+                    s.name,
+                    field.name
+                );
+            }
+        }
+        for method in &schema.methods {
+            for ty in method.params.iter().chain(&method.ret) {
+                assert_eq!(
+                    schema.unknown_references(ty),
+                    Vec::<&str>::new(),
+                    "{}.{}",
+                    method.actor,
+                    method.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn names_are_unique() {
+        let schema = Schema::new();
+
+        let mut names: Vec<&str> = schema
+            .structs
+            .iter()
+            .map(|s| s.name)
+            .chain(schema.enums.iter().map(|e| e.name))
+            .collect();
+        let count = names.len();
+        names.sort_unstable();
+        names.dedup();
+        assert_eq!(names.len(), count);
+
+        let mut methods: Vec<(&str, &str)> =
+            schema.methods.iter().map(|m| (m.actor, m.name)).collect();
+        let count = methods.len();
+        methods.sort_unstable();
+        methods.dedup();
+        assert_eq!(methods.len(), count);
+    }
+
+    #[test]
+    fn rust_types_have_the_schema_name() {
+        for s in &Schema::new().structs {
+            // Tuples have no name natively
+            if s.rust.starts_with('(') {
+                continue;
+            }
+            assert_eq!(s.rust.rsplit("::").next(), Some(s.name), "{}", s.rust);
+        }
+    }
+
+    #[test]
+    fn enum_codes_are_distinct() {
+        for e in &Schema::new().enums {
+            for (i, variant) in e.variants.iter().enumerate() {
+                assert_eq!(e.variant(variant.code), Some(variant), "{}", e.name);
+                assert!(
+                    e.variants[..i].iter().all(|v| v.name != variant.name),
+                    "{}::{}",
+                    e.name,
+                    variant.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn libraries_start_with_common() {
+        let libraries = Schema::new().libraries();
+        assert_eq!(libraries[0], COMMON);
+        assert!(libraries.contains(&"Market"));
+    }
+
+    #[test]
+    fn unknown_references_look_through_arrays() {
+        let schema = Schema::new();
+        let ty = Type::optional(Type::array(Type::Struct("Missing")));
+
+        assert_eq!(schema.unknown_references(&ty), vec!["Missing"]);
+        assert!(schema
+            .unknown_references(&Type::array(Type::Struct("Claim")))
+            .is_empty());
+    }
+}
@@ -0,0 +1,206 @@
+use crate::{field, method, Method, Struct, Type, ACTOR_ID, CHAIN_EPOCH};
+
+const LIBRARY: &str = "Market";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "WithdrawBalanceParams",
+            "fil_actor_market::WithdrawBalanceParams",
+            vec![
+                field("provider_or_client", Type::Address),
+                field("amount", Type::BigInt),
+            ],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "WithdrawBalanceReturn",
+            "fil_actor_market::WithdrawBalanceReturn",
+            field("amount_withdrawn", Type::BigInt),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetBalanceReturn",
+            "fil_actor_market::GetBalanceReturn",
+            vec![
+                field("balance", Type::BigInt),
+                field("locked", Type::BigInt),
+            ],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "DealQueryParams",
+            "fil_actor_market::DealQueryParams",
+            field("id", Type::Uint(64)),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetDealDataCommitmentReturn",
+            "fil_actor_market::GetDealDataCommitmentReturn",
+            vec![field("data", Type::Cid), field("size", Type::Uint(64))],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealClientReturn",
+            "fil_actor_market::GetDealClientReturn",
+            field("client", ACTOR_ID),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealProviderReturn",
+            "fil_actor_market::GetDealProviderReturn",
+            field("provider", ACTOR_ID),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealLabelReturn",
+            "fil_actor_market::GetDealLabelReturn",
+            field("label", Type::Label),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetDealTermReturn",
+            "fil_actor_market::GetDealTermReturn",
+            vec![field("start", CHAIN_EPOCH), field("duration", CHAIN_EPOCH)],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealTotalPriceReturn",
+            "fil_actor_market::GetDealTotalPriceReturn",
+            field("total_price", Type::BigInt),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealClientCollateralReturn",
+            "fil_actor_market::GetDealClientCollateralReturn",
+            field("collateral", Type::BigInt),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealProviderCollateralReturn",
+            "fil_actor_market::GetDealProviderCollateralReturn",
+            field("collateral", Type::BigInt),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetDealVerifiedReturn",
+            "fil_actor_market::GetDealVerifiedReturn",
+            field("verified", Type::Bool),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetDealActivationReturn",
+            "fil_actor_market::GetDealActivationReturn",
+            vec![
+                field("activated", CHAIN_EPOCH),
+                field("terminated", CHAIN_EPOCH),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "PublishStorageDealsParams",
+            "fil_actor_market::PublishStorageDealsParams",
+            vec![field(
+                "deals",
+                Type::array(Type::Struct("ClientDealProposal")),
+            )],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "PublishStorageDealsReturn",
+            "fil_actor_market::PublishStorageDealsReturn",
+            vec![
+                field("ids", Type::array(Type::Uint(64))),
+                // RLE+ bitfield
+                field("valid_deals", Type::Bytes),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    let deal_query = || Some(Type::Struct("DealQueryParams"));
+
+    vec![
+        method(LIBRARY, "AddBalance", Some(Type::Address), None),
+        method(
+            LIBRARY,
+            "WithdrawBalance",
+            Some(Type::Struct("WithdrawBalanceParams")),
+            Some(Type::Struct("WithdrawBalanceReturn")),
+        ),
+        method(
+            LIBRARY,
+            "PublishStorageDeals",
+            Some(Type::Struct("PublishStorageDealsParams")),
+            Some(Type::Struct("PublishStorageDealsReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetBalance",
+            Some(Type::Address),
+            Some(Type::Struct("GetBalanceReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealDataCommitment",
+            deal_query(),
+            Some(Type::Struct("GetDealDataCommitmentReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealClient",
+            deal_query(),
+            Some(Type::Struct("GetDealClientReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealProvider",
+            deal_query(),
+            Some(Type::Struct("GetDealProviderReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealLabel",
+            deal_query(),
+            Some(Type::Struct("GetDealLabelReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealTerm",
+            deal_query(),
+            Some(Type::Struct("GetDealTermReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealTotalPrice",
+            deal_query(),
+            Some(Type::Struct("GetDealTotalPriceReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealClientCollateral",
+            deal_query(),
+            Some(Type::Struct("GetDealClientCollateralReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealProviderCollateral",
+            deal_query(),
+            Some(Type::Struct("GetDealProviderCollateralReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealVerified",
+            deal_query(),
+            Some(Type::Struct("GetDealVerifiedReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetDealActivation",
+            deal_query(),
+            Some(Type::Struct("GetDealActivationReturn")),
+        ),
+    ]
+}
@@ -0,0 +1,190 @@
+use crate::{field, method, Method, Struct, Type, CHAIN_EPOCH};
+
+const LIBRARY: &str = "Miner";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "GetOwnerReturn",
+            "fil_actor_miner::GetOwnerReturn",
+            vec![
+                field("owner", Type::Address),
+                field("proposed", Type::optional(Type::Address)),
+            ],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "IsControllingAddressParam",
+            "fil_actor_miner::IsControllingAddressParam",
+            field("address", Type::Address),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "IsControllingAddressReturn",
+            "fil_actor_miner::IsControllingAddressReturn",
+            field("is_controlling", Type::Bool),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetSectorSizeReturn",
+            "fil_actor_miner::GetSectorSizeReturn",
+            field("sector_size", Type::Enum("SectorSize")),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetAvailableBalanceReturn",
+            "fil_actor_miner::GetAvailableBalanceReturn",
+            field("available_balance", Type::BigInt),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetVestingFundsReturn",
+            "fil_actor_miner::GetVestingFundsReturn",
+            field("vesting_funds", Type::array(Type::Struct("VestingFunds"))),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ChangeBeneficiaryParams",
+            "fil_actor_miner::ChangeBeneficiaryParams",
+            vec![
+                field("new_beneficiary", Type::Address),
+                field("new_quota", Type::BigInt),
+                field("new_expiration", CHAIN_EPOCH),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetBeneficiaryReturn",
+            "fil_actor_miner::GetBeneficiaryReturn",
+            vec![
+                field("active", Type::Struct("ActiveBeneficiary")),
+                field(
+                    "proposed",
+                    Type::optional(Type::Struct("PendingBeneficiaryChange")),
+                ),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ChangeWorkerAddressParams",
+            "fil_actor_miner::ChangeWorkerAddressParams",
+            vec![
+                field("new_worker", Type::Address),
+                field("new_control_addresses", Type::array(Type::Address)),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ChangePeerIDParams",
+            "fil_actor_miner::ChangePeerIDParams",
+            vec![field("new_id", Type::Bytes)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ChangeMultiaddrsParams",
+            "fil_actor_miner::ChangeMultiaddrsParams",
+            vec![field("new_multi_addrs", Type::array(Type::Bytes))],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetPeerIDReturn",
+            "fil_actor_miner::GetPeerIDReturn",
+            field("peer_id", Type::Bytes),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "GetMultiaddrsReturn",
+            "fil_actor_miner::GetMultiaddrsReturn",
+            field("multi_addrs", Type::array(Type::Bytes)),
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "ChangeWorkerAddress",
+            Some(Type::Struct("ChangeWorkerAddressParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "ChangePeerID",
+            Some(Type::Struct("ChangePeerIDParams")),
+            None,
+        ),
+        // The miner's WithdrawBalanceParams and WithdrawBalanceReturn are transparent, and
+        // named like the market's, so only their amount is described
+        method(
+            LIBRARY,
+            "WithdrawBalance",
+            Some(Type::BigInt),
+            Some(Type::BigInt),
+        ),
+        method(
+            LIBRARY,
+            "ChangeMultiaddrs",
+            Some(Type::Struct("ChangeMultiaddrsParams")),
+            None,
+        ),
+        method(LIBRARY, "ConfirmChangeWorkerAddress", None, None),
+        method(LIBRARY, "RepayDebt", None, None),
+        method(LIBRARY, "ChangeOwnerAddress", Some(Type::Address), None),
+        method(
+            LIBRARY,
+            "ChangeBeneficiary",
+            Some(Type::Struct("ChangeBeneficiaryParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "GetBeneficiary",
+            None,
+            Some(Type::Struct("GetBeneficiaryReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetOwner",
+            None,
+            Some(Type::Struct("GetOwnerReturn")),
+        ),
+        method(
+            LIBRARY,
+            "IsControllingAddress",
+            Some(Type::Struct("IsControllingAddressParam")),
+            Some(Type::Struct("IsControllingAddressReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetSectorSize",
+            None,
+            Some(Type::Struct("GetSectorSizeReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetAvailableBalance",
+            None,
+            Some(Type::Struct("GetAvailableBalanceReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetVestingFunds",
+            None,
+            Some(Type::Struct("GetVestingFundsReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetPeerID",
+            None,
+            Some(Type::Struct("GetPeerIDReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetMultiaddrs",
+            None,
+            Some(Type::Struct("GetMultiaddrsReturn")),
+        ),
+    ]
+}
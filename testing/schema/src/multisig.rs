@@ -0,0 +1,144 @@
+use crate::{field, method, Method, Struct, Type, CHAIN_EPOCH, EXIT_CODE};
+
+const LIBRARY: &str = "Multisig";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "ProposeParams",
+            "fil_actor_multisig::ProposeParams",
+            vec![
+                field("to", Type::Address),
+                field("value", Type::BigInt),
+                field("method", Type::Uint(64)),
+                field("params", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ProposeReturn",
+            "fil_actor_multisig::ProposeReturn",
+            vec![
+                field("txn_id", CHAIN_EPOCH),
+                field("applied", Type::Bool),
+                field("code", EXIT_CODE),
+                field("ret", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "TxnIDParams",
+            "fil_actor_multisig::TxnIDParams",
+            vec![
+                field("id", CHAIN_EPOCH),
+                field("proposal_hash", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ApproveReturn",
+            "fil_actor_multisig::ApproveReturn",
+            vec![
+                field("applied", Type::Bool),
+                field("code", EXIT_CODE),
+                field("ret", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "AddSignerParams",
+            "fil_actor_multisig::AddSignerParams",
+            vec![
+                field("signer", Type::Address),
+                field("increase", Type::Bool),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RemoveSignerParams",
+            "fil_actor_multisig::RemoveSignerParams",
+            vec![
+                field("signer", Type::Address),
+                field("decrease", Type::Bool),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "SwapSignerParams",
+            "fil_actor_multisig::SwapSignerParams",
+            vec![field("from", Type::Address), field("to", Type::Address)],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ChangeNumApprovalsThresholdParams",
+            "fil_actor_multisig::ChangeNumApprovalsThresholdParams",
+            vec![field("new_threshold", Type::Uint(64))],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "LockBalanceParams",
+            "fil_actor_multisig::LockBalanceParams",
+            vec![
+                field("start_epoch", CHAIN_EPOCH),
+                field("unlock_duration", CHAIN_EPOCH),
+                field("amount", Type::BigInt),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "Propose",
+            Some(Type::Struct("ProposeParams")),
+            Some(Type::Struct("ProposeReturn")),
+        ),
+        method(
+            LIBRARY,
+            "Approve",
+            Some(Type::Struct("TxnIDParams")),
+            Some(Type::Struct("ApproveReturn")),
+        ),
+        method(LIBRARY, "Cancel", Some(Type::Struct("TxnIDParams")), None),
+        method(
+            LIBRARY,
+            "AddSigner",
+            Some(Type::Struct("AddSignerParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "RemoveSigner",
+            Some(Type::Struct("RemoveSignerParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "SwapSigner",
+            Some(Type::Struct("SwapSignerParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "ChangeNumApprovalsThreshold",
+            Some(Type::Struct("ChangeNumApprovalsThresholdParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "LockBalance",
+            Some(Type::Struct("LockBalanceParams")),
+            None,
+        ),
+        // Accepts anything, token transfers send these
+        method(
+            LIBRARY,
+            "Receive",
+            Some(Type::Struct("UniversalReceiverParams")),
+            None,
+        ),
+    ]
+}
@@ -0,0 +1,97 @@
+use crate::{field, method, Method, Struct, Type, ACTOR_ID};
+
+const LIBRARY: &str = "Power";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "CreateMinerParams",
+            "fil_actor_power::CreateMinerParams",
+            vec![
+                field("owner", Type::Address),
+                field("worker", Type::Address),
+                field("window_post_proof_type", Type::Enum("RegisteredPoStProof")),
+                field("peer", Type::Bytes),
+                field("multiaddrs", Type::array(Type::Bytes)),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "CreateMinerReturn",
+            "fil_actor_power::CreateMinerReturn",
+            vec![
+                field("id_address", Type::Address),
+                field("robust_address", Type::Address),
+            ],
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "MinerCountReturn",
+            "fil_actor_power::MinerCountReturn",
+            field("miner_count", Type::Int(64)),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "MinerConsensusCountReturn",
+            "fil_actor_power::MinerConsensusCountReturn",
+            field("miner_consensus_count", Type::Int(64)),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "NetworkRawPowerReturn",
+            "fil_actor_power::NetworkRawPowerReturn",
+            field("raw_byte_power", Type::BigInt),
+        ),
+        Struct::transparent(
+            LIBRARY,
+            "MinerRawPowerParams",
+            "fil_actor_power::MinerRawPowerParams",
+            field("miner", ACTOR_ID),
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "MinerRawPowerReturn",
+            "fil_actor_power::MinerRawPowerReturn",
+            vec![
+                field("raw_byte_power", Type::BigInt),
+                field("meets_consensus_minimum", Type::Bool),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "CreateMiner",
+            Some(Type::Struct("CreateMinerParams")),
+            Some(Type::Struct("CreateMinerReturn")),
+        ),
+        method(
+            LIBRARY,
+            "NetworkRawPower",
+            None,
+            Some(Type::Struct("NetworkRawPowerReturn")),
+        ),
+        method(
+            LIBRARY,
+            "MinerRawPower",
+            Some(Type::Struct("MinerRawPowerParams")),
+            Some(Type::Struct("MinerRawPowerReturn")),
+        ),
+        method(
+            LIBRARY,
+            "MinerCount",
+            None,
+            Some(Type::Struct("MinerCountReturn")),
+        ),
+        method(
+            LIBRARY,
+            "MinerConsensusCount",
+            None,
+            Some(Type::Struct("MinerConsensusCountReturn")),
+        ),
+    ]
+}
@@ -0,0 +1,377 @@
+//! Checks payloads against the schema, e.g. params captured from a test run or produced by
+//! off-chain tooling.
+//!
+//! Besides the shape of every struct, integers have to fit their width, enums have to be
+//! one of their codes, and BigInts, addresses and CIDs have to be well formed as the actors
+//! decode them.
+
+use crate::cbor::{self, Item};
+use crate::{Encoding, Method, Schema, Type};
+
+/// Longest BigInt the actors decode, sign byte included.
+const MAX_BIGINT_LEN: usize = 128;
+
+/// Subaddress of a delegated (`f4`) address.
+const MAX_SUBADDRESS_LEN: usize = 54;
+
+/// Tag of a CID in DAG-CBOR.
+const CID_TAG: u64 = 42;
+
+/// The problem and where in the payload it is, e.g. `WithdrawBalanceParams.amount`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Invalid {
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Invalid {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+fn invalid(path: &str, message: String) -> Invalid {
+    Invalid {
+        path: path.to_string(),
+        message,
+    }
+}
+
+fn expected(path: &str, what: &str, item: &Item) -> Invalid {
+    invalid(path, format!("expected {}, found {}", what, item.kind()))
+}
+
+fn leb128(bytes: &[u8]) -> Option<usize> {
+    let len = bytes.iter().position(|byte| byte & 0x80 == 0)? + 1;
+    (len <= 10).then_some(len)
+}
+
+/// Why `bytes` isn't the byte form of an address, if it isn't.
+fn address(bytes: &[u8]) -> Option<String> {
+    let (protocol, payload) = match bytes.split_first() {
+        Some(split) => split,
+        None => return Some("empty address".to_string()),
+    };
+
+    let valid = match protocol {
+        // ID
+        0 => leb128(payload) == Some(payload.len()),
+        // secp256k1 and actor, a 20 byte hash
+        1 | 2 => payload.len() == 20,
+        // BLS public key
+        3 => payload.len() == 48,
+        // delegated, a namespace then the subaddress
+        4 => leb128(payload).is_some_and(|len| payload.len() - len <= MAX_SUBADDRESS_LEN),
+        _ => return Some(format!("unknown address protocol {}", protocol)),
+    };
+
+    (!valid).then(|| format!("malformed protocol {} address", protocol))
+}
+
+impl Schema {
+    /// Checks `item` is a `ty`, `path` names it in the errors.
+    pub fn validate_item(&self, ty: &Type, item: &Item, path: &str) -> Result<(), Invalid> {
+        match (ty, item) {
+            (Type::Optional(_), Item::Null) => Ok(()),
+            (Type::Optional(value), _) => self.validate_item(value, item, path),
+
+            (Type::Bool, Item::Bool(_)) => Ok(()),
+
+            (Type::Uint(bits), Item::Uint(value)) => {
+                if *bits < 64 && *value >> bits != 0 {
+                    Err(invalid(path, format!("{} doesn't fit uint{}", value, bits)))
+                } else {
+                    Ok(())
+                }
+            }
+            (Type::Int(bits), Item::Uint(value) | Item::Negative(value)) => {
+                // Both -1 - n and n fit when n < 2^(bits - 1)
+                if *value >> (bits - 1) != 0 {
+                    let shown = match item {
+                        Item::Negative(_) => -1 - i128::from(*value),
+                        _ => i128::from(*value),
+                    };
+                    Err(invalid(path, format!("{} doesn't fit int{}", shown, bits)))
+                } else {
+                    Ok(())
+                }
+            }
+
+            (Type::BigInt, Item::Bytes(bytes)) => match bytes.first() {
+                _ if bytes.len() > MAX_BIGINT_LEN => Err(invalid(
+                    path,
+                    format!(
+                        "BigInt of {} bytes, at most {}",
+                        bytes.len(),
+                        MAX_BIGINT_LEN
+                    ),
+                )),
+                None | Some(0 | 1) => Ok(()),
+                Some(sign) => Err(invalid(path, format!("BigInt sign byte {}", sign))),
+            },
+
+            (Type::Bytes, Item::Bytes(_)) => Ok(()),
+
+            (Type::Address, Item::Bytes(bytes)) => match address(bytes) {
+                Some(problem) => Err(invalid(path, problem)),
+                None => Ok(()),
+            },
+
+            (Type::Cid, Item::Tag(CID_TAG, cid)) => match cid.as_ref() {
+                // Multibase identity prefix
+                Item::Bytes(bytes) if bytes.len() > 1 && bytes[0] == 0 => Ok(()),
+                Item::Bytes(_) => Err(invalid(path, "malformed CID".to_string())),
+                other => Err(expected(path, "byte string under tag 42", other)),
+            },
+
+            (Type::String, Item::Text(_)) => Ok(()),
+            (Type::Label, Item::Text(_) | Item::Bytes(_)) => Ok(()),
+
+            (Type::Array(item_type), Item::Array(items)) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.validate_item(item_type, item, &format!("{}[{}]", path, i))?;
+                }
+                Ok(())
+            }
+
+            (Type::Struct(name), _) => {
+                let s = self
+                    .find_struct(name)
+                    .ok_or_else(|| invalid(path, format!("unknown type {}", name)))?;
+                let path = if path.is_empty() { name } else { path };
+
+                match (s.encoding, item) {
+                    (Encoding::Transparent, _) => {
+                        let field = &s.fields[0];
+                        self.validate_item(&field.ty, item, &format!("{}.{}", path, field.name))
+                    }
+                    (Encoding::Tuple, Item::Array(items)) if items.len() == s.fields.len() => {
+                        for (field, item) in s.fields.iter().zip(items) {
+                            self.validate_item(
+                                &field.ty,
+                                item,
+                                &format!("{}.{}", path, field.name),
+                            )?;
+                        }
+                        Ok(())
+                    }
+                    (Encoding::Tuple, Item::Array(items)) => Err(invalid(
+                        path,
+                        format!("{} fields, expected {}", items.len(), s.fields.len()),
+                    )),
+                    (Encoding::Tuple, _) => Err(expected(
+                        path,
+                        &format!("array of {} fields", s.fields.len()),
+                        item,
+                    )),
+                }
+            }
+
+            (Type::Enum(name), Item::Uint(code)) => {
+                let e = self
+                    .find_enum(name)
+                    .ok_or_else(|| invalid(path, format!("unknown type {}", name)))?;
+                match e.variant(*code) {
+                    Some(_) => Ok(()),
+                    None => Err(invalid(path, format!("{} isn't a {}", code, name))),
+                }
+            }
+
+            (Type::Bool, _) => Err(expected(path, "bool", item)),
+            (Type::Uint(bits), _) => Err(expected(path, &format!("uint{}", bits), item)),
+            (Type::Int(bits), _) => Err(expected(path, &format!("int{}", bits), item)),
+            (Type::Cid, _) => Err(expected(path, "CID", item)),
+            (Type::String, _) => Err(expected(path, "text string", item)),
+            (Type::Label, _) => Err(expected(path, "text or byte string", item)),
+            (Type::Array(_), _) => Err(expected(path, "array", item)),
+            (Type::Enum(name), _) => Err(expected(path, name, item)),
+            (Type::BigInt | Type::Bytes | Type::Address, _) => {
+                Err(expected(path, "byte string", item))
+            }
+        }
+    }
+
+    /// Checks the encoded `payload` is a `ty`.
+    pub fn validate(&self, ty: &Type, payload: &[u8]) -> Result<(), Invalid> {
+        let item = cbor::decode(payload).map_err(|message| invalid("", message))?;
+        self.validate_item(ty, &item, "")
+    }
+
+    /// Checks the params of `method`, which are empty when it takes none.
+    pub fn validate_params(&self, method: &Method, payload: &[u8]) -> Result<(), Invalid> {
+        validate_optional(self, method.params.as_ref(), payload, "params")
+    }
+
+    /// Checks the return of `method`, which is empty when it returns nothing.
+    pub fn validate_return(&self, method: &Method, payload: &[u8]) -> Result<(), Invalid> {
+        validate_optional(self, method.ret.as_ref(), payload, "return")
+    }
+}
+
+fn validate_optional(
+    schema: &Schema,
+    ty: Option<&Type>,
+    payload: &[u8],
+    what: &str,
+) -> Result<(), Invalid> {
+    match ty {
+        Some(ty) => schema.validate(ty, payload),
+        None if payload.is_empty() => Ok(()),
+        None => Err(invalid(
+            "",
+            format!("{} bytes where the method has no {}", payload.len(), what),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `[h'0064', h'0001']`: ID address 100 and 1 attoFIL.
+    const WITHDRAW_BALANCE_PARAMS: &str = "82420064420001";
+
+    fn validate(ty: &Type, payload: &str) -> Result<(), Invalid> {
+        Schema::new().validate(ty, &hex(payload))
+    }
+
+    fn hex(payload: &str) -> Vec<u8> {
+        (0..payload.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&payload[i..i + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn message(result: Result<(), Invalid>) -> String {
+        result.unwrap_err().to_string()
+    }
+
+    #[test]
+    fn accepts_a_struct() {
+        let params = Type::Struct("WithdrawBalanceParams");
+        assert_eq!(validate(&params, WITHDRAW_BALANCE_PARAMS), Ok(()));
+    }
+
+    #[test]
+    fn reports_the_field() {
+        let params = Type::Struct("WithdrawBalanceParams");
+        assert_eq!(
+            message(validate(&params, "82420564420001")),
+            "WithdrawBalanceParams.provider_or_client: unknown address protocol 5"
+        );
+        assert_eq!(
+            message(validate(&params, "8242006442000100")),
+            "1 trailing bytes after the item"
+        );
+        assert_eq!(
+            message(validate(&params, "81420064")),
+            "WithdrawBalanceParams: 1 fields, expected 2"
+        );
+        assert_eq!(
+            message(validate(&params, "420064")),
+            "WithdrawBalanceParams: expected array of 2 fields, found byte string"
+        );
+    }
+
+    #[test]
+    fn transparent_structs_are_their_field() {
+        let params = Type::Struct("DealQueryParams");
+        assert_eq!(validate(&params, "1864"), Ok(()));
+        assert_eq!(
+            message(validate(&params, "811864")),
+            "DealQueryParams.id: expected uint64, found array"
+        );
+    }
+
+    #[test]
+    fn integers_fit_their_width() {
+        assert_eq!(validate(&Type::Uint(32), "1affffffff"), Ok(()));
+        assert_eq!(
+            message(validate(&Type::Uint(32), "1b0000000100000000")),
+            "4294967296 doesn't fit uint32"
+        );
+
+        assert_eq!(validate(&Type::Int(64), "1b7fffffffffffffff"), Ok(()));
+        assert_eq!(validate(&Type::Int(64), "3b7fffffffffffffff"), Ok(()));
+        assert_eq!(
+            message(validate(&Type::Int(64), "1b8000000000000000")),
+            "9223372036854775808 doesn't fit int64"
+        );
+        assert_eq!(
+            message(validate(&Type::Int(64), "3b8000000000000000")),
+            "-9223372036854775809 doesn't fit int64"
+        );
+    }
+
+    #[test]
+    fn bigints_have_a_sign_byte() {
+        assert_eq!(validate(&Type::BigInt, "40"), Ok(()));
+        assert_eq!(validate(&Type::BigInt, "42010a"), Ok(()));
+        assert_eq!(
+            message(validate(&Type::BigInt, "42020a")),
+            "BigInt sign byte 2"
+        );
+    }
+
+    #[test]
+    fn addresses_are_well_formed() {
+        assert_eq!(validate(&Type::Address, "420064"), Ok(()));
+        assert_eq!(
+            message(validate(&Type::Address, "4400808080")),
+            "malformed protocol 0 address"
+        );
+        assert_eq!(
+            message(validate(&Type::Address, "4201ff")),
+            "malformed protocol 1 address"
+        );
+        assert_eq!(message(validate(&Type::Address, "40")), "empty address");
+    }
+
+    #[test]
+    fn cids_are_tagged() {
+        assert_eq!(validate(&Type::Cid, "d82a43000171"), Ok(()));
+        assert_eq!(
+            message(validate(&Type::Cid, "d82a43010171")),
+            "malformed CID"
+        );
+        assert_eq!(
+            message(validate(&Type::Cid, "43000171")),
+            "expected CID, found byte string"
+        );
+    }
+
+    #[test]
+    fn enums_are_one_of_their_codes() {
+        let size = Type::Struct("GetSectorSizeReturn");
+        assert_eq!(validate(&size, "190800"), Ok(()));
+        assert_eq!(
+            message(validate(&size, "03")),
+            "GetSectorSizeReturn.sector_size: 3 isn't a SectorSize"
+        );
+    }
+
+    #[test]
+    fn optionals_may_be_null() {
+        let optional = Type::optional(Type::Address);
+        assert_eq!(validate(&optional, "f6"), Ok(()));
+        assert_eq!(validate(&optional, "420064"), Ok(()));
+        assert!(validate(&Type::Address, "f6").is_err());
+    }
+
+    #[test]
+    fn methods_without_params_take_nothing() {
+        let schema = Schema::new();
+        let method = schema.method("Miner", "GetSectorSize").unwrap();
+
+        assert_eq!(schema.validate_params(method, &[]), Ok(()));
+        assert_eq!(
+            message(schema.validate_params(method, &hex("f6"))),
+            "1 bytes where the method has no params"
+        );
+        assert_eq!(schema.validate_return(method, &hex("190800")), Ok(()));
+    }
+}
@@ -0,0 +1,138 @@
+use crate::{field, method, Method, Struct, Type, ACTOR_ID};
+
+const LIBRARY: &str = "VerifReg";
+
+pub fn structs() -> Vec<Struct> {
+    vec![
+        Struct::tuple(
+            LIBRARY,
+            "GetClaimsParams",
+            "fil_actor_verifreg::GetClaimsParams",
+            vec![
+                field("provider", ACTOR_ID),
+                field("claim_ids", Type::array(Type::Uint(64))),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "GetClaimsReturn",
+            "fil_actor_verifreg::GetClaimsReturn",
+            vec![
+                field("batch_info", Type::Struct("BatchReturn")),
+                field("claims", Type::array(Type::Struct("Claim"))),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "VerifierParams",
+            "fil_actor_verifreg::VerifierParams",
+            vec![
+                field("address", Type::Address),
+                field("allowance", Type::BigInt),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RemoveExpiredAllocationsParams",
+            "fil_actor_verifreg::RemoveExpiredAllocationsParams",
+            vec![
+                field("client", ACTOR_ID),
+                field("allocation_ids", Type::array(Type::Uint(64))),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RemoveExpiredAllocationsReturn",
+            "fil_actor_verifreg::RemoveExpiredAllocationsReturn",
+            vec![
+                field("considered", Type::array(Type::Uint(64))),
+                field("results", Type::Struct("BatchReturn")),
+                field("datacap_recovered", Type::BigInt),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RemoveExpiredClaimsParams",
+            "fil_actor_verifreg::RemoveExpiredClaimsParams",
+            vec![
+                field("provider", ACTOR_ID),
+                field("claim_ids", Type::array(Type::Uint(64))),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "RemoveExpiredClaimsReturn",
+            "fil_actor_verifreg::RemoveExpiredClaimsReturn",
+            vec![
+                field("considered", Type::array(Type::Uint(64))),
+                field("results", Type::Struct("BatchReturn")),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "ExtendClaimTermsParams",
+            "fil_actor_verifreg::ExtendClaimTermsParams",
+            vec![field("terms", Type::array(Type::Struct("ClaimTerm")))],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "UniversalReceiverParams",
+            "fvm_actor_utils::receiver::UniversalReceiverParams",
+            vec![
+                field("type_", Type::Uint(32)),
+                field("payload", Type::Bytes),
+            ],
+        ),
+        Struct::tuple(
+            LIBRARY,
+            "AllocationsResponse",
+            "fil_actor_verifreg::AllocationsResponse",
+            vec![
+                field("allocation_results", Type::Struct("BatchReturn")),
+                field("extension_results", Type::Struct("BatchReturn")),
+                field("new_allocations", Type::array(Type::Uint(64))),
+            ],
+        ),
+    ]
+}
+
+pub fn methods() -> Vec<Method> {
+    vec![
+        method(
+            LIBRARY,
+            "AddVerifiedClient",
+            Some(Type::Struct("VerifierParams")),
+            None,
+        ),
+        method(
+            LIBRARY,
+            "RemoveExpiredAllocations",
+            Some(Type::Struct("RemoveExpiredAllocationsParams")),
+            Some(Type::Struct("RemoveExpiredAllocationsReturn")),
+        ),
+        method(
+            LIBRARY,
+            "GetClaims",
+            Some(Type::Struct("GetClaimsParams")),
+            Some(Type::Struct("GetClaimsReturn")),
+        ),
+        method(
+            LIBRARY,
+            "ExtendClaimTerms",
+            Some(Type::Struct("ExtendClaimTermsParams")),
+            Some(Type::Struct("BatchReturn")),
+        ),
+        method(
+            LIBRARY,
+            "RemoveExpiredClaims",
+            Some(Type::Struct("RemoveExpiredClaimsParams")),
+            Some(Type::Struct("RemoveExpiredClaimsReturn")),
+        ),
+        method(
+            LIBRARY,
+            "Receive",
+            Some(Type::Struct("UniversalReceiverParams")),
+            Some(Type::Struct("AllocationsResponse")),
+        ),
+    ]
+}
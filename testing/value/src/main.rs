@@ -0,0 +1,281 @@
+//! Value forwarding suite.
+//!
+//! `Actor.call` forwards `msg.value` on every call. Every message here is applied with the
+//! harness balance checks on, so value has to be conserved, and the balance changes tell
+//! which actor ended up with the value sent along. API wrappers may only move value to the
+//! actor they are meant to pay; the others have to leave it with the sender. Through
+//! `ActorCallTest`, which calls from a payable function like any contract using `Actor.call`
+//! would, the recipient of the forwarded value is reported for each actor.
+
+use std::process::exit;
+
+use ethabi::Token;
+use fil_actor_verifreg::GetClaimsParams;
+use fil_actors_runtime::{
+    DATACAP_TOKEN_ACTOR_ADDR, STORAGE_MARKET_ACTOR_ADDR, STORAGE_POWER_ACTOR_ADDR,
+    VERIFIED_REGISTRY_ACTOR_ADDR,
+};
+use fvm_ipld_encoding::RawBytes;
+use fvm_shared::address::Address;
+use fvm_shared::econ::TokenAmount;
+use fvm_shared::{ActorID, MethodNum};
+use harness::balances::{transfers, Changes};
+use harness::checks::{check, report, Results};
+use harness::method_nums;
+use harness::precompile::{call_actor_args, ACTOR_CALL_TEST_ARTIFACT, DEFAULT_FLAG};
+use harness::tokens::{address, uint};
+use harness::{Contract, Harness};
+
+const MARKET_API_ARTIFACT: &str = "../../build/v0.8/MarketAPI";
+const MINER_API_ARTIFACT: &str = "../../build/v0.8/MinerAPI";
+const POWER_API_ARTIFACT: &str = "../../build/v0.8/PowerAPI";
+const DATACAP_API_ARTIFACT: &str = "../../build/v0.8/DataCapAPI";
+const VERIFREG_API_ARTIFACT: &str = "../../build/v0.8/VerifRegAPI";
+
+/// Sent along with every call.
+const VALUE: u64 = 100;
+
+/// Deploys `artifact` next to a miner owned by the sender, then turns the balance checks on.
+fn setup(artifact: &str) -> (Harness, Contract, Address) {
+    let mut harness = Harness::new();
+
+    let sender: [(ActorID, Address); 1] = harness.create_accounts();
+
+    // Instantiate machine
+    harness.instantiate();
+
+    let contract = harness.deploy(sender[0].1, artifact);
+
+    let (miner, _) = harness.create_miner(sender[0].1, sender[0].1);
+
+    harness.check_balances();
+
+    (harness, contract, miner)
+}
+
+/// Singletons by their fixed IDs, for test output.
+fn actor_name(id: ActorID, contract: &Contract) -> String {
+    match id {
+        2 => "reward".to_string(),
+        4 => "power".to_string(),
+        5 => "market".to_string(),
+        6 => "verifreg".to_string(),
+        7 => "datacap".to_string(),
+        99 => "burnt funds".to_string(),
+        id if Address::new_id(id) == contract.address => "contract".to_string(),
+        id => format!("f0{}", id),
+    }
+}
+
+fn describe(moved: &Changes, contract: &Contract) -> String {
+    if moved.is_empty() {
+        return "nothing moved".to_string();
+    }
+
+    moved
+        .iter()
+        .map(|(id, change)| format!("{} {}", actor_name(*id, contract), change.atto()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Value moved by the last message, besides what the sender paid for gas.
+fn value_moved(harness: &Harness) -> Changes {
+    transfers(harness.balance_changes(), harness.accounts[0].0)
+}
+
+/// Calls `wrapper` with value. Only `recipient` may get it; without one, the value has to
+/// stay with the sender.
+fn check_wrapper(
+    results: &mut Results,
+    artifact: &str,
+    wrapper: &str,
+    args: impl Fn(&Contract, Address) -> Vec<Token>,
+    recipient: Option<Address>,
+) {
+    let (mut harness, contract, miner) = setup(artifact);
+    let sender = harness.accounts[0].1;
+
+    harness.call(
+        sender,
+        &contract,
+        wrapper,
+        &args(&contract, miner),
+        TokenAmount::from_atto(VALUE),
+    );
+    let moved = value_moved(&harness);
+    println!("  {}: {}", wrapper, describe(&moved, &contract));
+
+    let expected: Changes = recipient
+        .map(|to| (to.id().unwrap(), TokenAmount::from_atto(VALUE)))
+        .into_iter()
+        .collect();
+
+    let outcome = if moved == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "expected {}, got {}",
+            describe(&expected, &contract),
+            describe(&moved, &contract)
+        ))
+    };
+
+    check(results, wrapper.to_string(), outcome);
+}
+
+fn wrappers(results: &mut Results) {
+    println!("Sending value to the API wrappers");
+
+    check_wrapper(
+        results,
+        MARKET_API_ARTIFACT,
+        "add_balance",
+        |contract, _| vec![address(&contract.address)],
+        Some(STORAGE_MARKET_ACTOR_ADDR),
+    );
+    check_wrapper(
+        results,
+        MARKET_API_ARTIFACT,
+        "get_balance",
+        |contract, _| vec![address(&contract.address)],
+        None,
+    );
+
+    for getter in [
+        "get_owner",
+        "get_sector_size",
+        "get_available_balance",
+        "get_vesting_funds",
+        "get_beneficiary",
+        "get_peer_id",
+        "get_multiaddresses",
+    ] {
+        check_wrapper(
+            results,
+            MINER_API_ARTIFACT,
+            getter,
+            |_, miner| vec![address(&miner)],
+            None,
+        );
+    }
+
+    for getter in ["miner_count", "miner_consensus_count", "network_raw_power"] {
+        check_wrapper(results, POWER_API_ARTIFACT, getter, |_, _| vec![], None);
+    }
+
+    for getter in ["name", "symbol", "total_supply"] {
+        check_wrapper(results, DATACAP_API_ARTIFACT, getter, |_, _| vec![], None);
+    }
+    check_wrapper(
+        results,
+        DATACAP_API_ARTIFACT,
+        "balance",
+        |contract, _| vec![address(&contract.address)],
+        None,
+    );
+
+    check_wrapper(
+        results,
+        VERIFREG_API_ARTIFACT,
+        "get_claims",
+        |_, miner| {
+            vec![Token::Tuple(vec![
+                uint(miner.id().unwrap()),
+                Token::Array(vec![]),
+            ])]
+        },
+        None,
+    );
+}
+
+/// Getters called with value from a payable function. Reports where the value went; it
+/// may only reach the actor that was called.
+fn forwarding(results: &mut Results) {
+    println!("Sending value along with getters through ActorCallTest");
+
+    let (mut harness, contract, miner) = setup(ACTOR_CALL_TEST_ARTIFACT);
+    let sender = harness.accounts[0].1;
+
+    let getters: [(&str, Address, MethodNum, RawBytes); 5] = [
+        (
+            "GetBalance",
+            STORAGE_MARKET_ACTOR_ADDR,
+            method_nums::market::GET_BALANCE,
+            RawBytes::serialize(contract.address).unwrap(),
+        ),
+        (
+            "GetOwner",
+            miner,
+            method_nums::miner::GET_OWNER,
+            RawBytes::default(),
+        ),
+        (
+            "MinerCount",
+            STORAGE_POWER_ACTOR_ADDR,
+            method_nums::power::MINER_COUNT,
+            RawBytes::default(),
+        ),
+        (
+            "Name",
+            DATACAP_TOKEN_ACTOR_ADDR,
+            method_nums::datacap::NAME,
+            RawBytes::default(),
+        ),
+        (
+            "GetClaims",
+            VERIFIED_REGISTRY_ACTOR_ADDR,
+            method_nums::verifreg::GET_CLAIMS,
+            RawBytes::serialize(GetClaimsParams {
+                provider: miner.id().unwrap(),
+                claim_ids: vec![],
+            })
+            .unwrap(),
+        ),
+    ];
+
+    for (name, to, method, params) in getters {
+        let res = harness.call(
+            sender,
+            &contract,
+            "call_actor",
+            &call_actor_args(&to, method, &params, DEFAULT_FLAG),
+            TokenAmount::from_atto(VALUE),
+        );
+        let moved = value_moved(&harness);
+        println!(
+            "  {} (exit code {}): {}",
+            name,
+            res.msg_receipt.exit_code.value(),
+            describe(&moved, &contract)
+        );
+
+        let target = to.id().unwrap();
+        let stray: Vec<_> = moved
+            .keys()
+            .filter(|id| **id != target && Address::new_id(**id) != contract.address)
+            .collect();
+
+        let outcome = if stray.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("value reached {}", describe(&moved, &contract)))
+        };
+        check(results, format!("{} forwarding", name), outcome);
+    }
+}
+
+fn main() {
+    println!("Checking where the value sent through Actor.call ends up");
+
+    let mut results = vec![];
+
+    wrappers(&mut results);
+    forwarding(&mut results);
+
+    let failures = report("value", &results);
+
+    if failures > 0 {
+        exit(1);
+    }
+}
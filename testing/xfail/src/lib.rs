@@ -0,0 +1,305 @@
+//! Registry of known failures for the integration binaries under `testing/`.
+//!
+//! Calls that are known to fail are listed in `testing/known-failures.txt`, one per line,
+//! with the suite, the function called, the exit code it fails with and why:
+//!
+//! ```text
+//! market  get_deal_client  33  deal 1 does not exist
+//! ```
+//!
+//! A listed call that fails with that exit code is an expected failure (XFAIL). One that
+//! starts succeeding is flagged (XPASS) but doesn't fail the suite, so the entry can be
+//! removed. Anything else, a listed call failing differently or an unlisted call failing,
+//! is a failure.
+//!
+//! Checks take the [`report::CallRecord`] of the call, and [`Registry::write_report`]
+//! writes them, with their outcome, as JUnit XML and JSON, see [`report`].
+//!
+//! This crate has no dependencies, so that suites built against any FVM can use it.
+
+pub mod report;
+
+use std::fs;
+use std::path::Path;
+
+use report::{CallRecord, Report, TestCase};
+
+/// Relative to the suite directory, where the binaries are run from.
+pub const KNOWN_FAILURES_PATH: &str = "../known-failures.txt";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KnownFailure {
+    pub suite: String,
+    pub function: String,
+    pub exit_code: u32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    XFail,
+    XPass,
+    Fail(String),
+}
+
+pub struct Registry {
+    suite: String,
+    known: Vec<KnownFailure>,
+    results: Vec<TestCase>,
+}
+
+/// Suites that check their calls against the registry.
+pub const SUITES: [&str; 5] = ["market", "miner", "multisig", "power", "verifreg"];
+
+/// Parses the manifest format described above. Blank lines and `#` comments are skipped.
+/// A suite not in [`SUITES`], or a call listed twice, is rejected.
+pub fn parse(content: &str) -> Result<Vec<KnownFailure>, String> {
+    let mut known: Vec<KnownFailure> = vec![];
+
+    for line in content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+    {
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        let failure = match &fields[..] {
+            [suite, function, exit_code, reason @ ..] if !reason.is_empty() => KnownFailure {
+                suite: suite.to_string(),
+                function: function.to_string(),
+                exit_code: exit_code
+                    .parse()
+                    .map_err(|_| format!("invalid exit code in `{}`", line))?,
+                reason: reason.join(" "),
+            },
+            _ => {
+                return Err(format!(
+                    "expected `suite function exit_code reason`, got `{}`",
+                    line
+                ))
+            }
+        };
+
+        if !SUITES.contains(&failure.suite.as_str()) {
+            return Err(format!("unknown suite in `{}`", line));
+        }
+        if known
+            .iter()
+            .any(|k| k.suite == failure.suite && k.function == failure.function)
+        {
+            return Err(format!(
+                "{} {} is listed twice",
+                failure.suite, failure.function
+            ));
+        }
+
+        known.push(failure);
+    }
+
+    Ok(known)
+}
+
+impl Registry {
+    /// Loads the known failures of `suite` from [`KNOWN_FAILURES_PATH`].
+    pub fn load(suite: &str) -> Self {
+        let content =
+            fs::read_to_string(KNOWN_FAILURES_PATH).expect("Unable to read known failures");
+        let known = parse(&content).unwrap_or_else(|err| panic!("{}", err));
+
+        Registry::new(suite, known)
+    }
+
+    pub fn new(suite: &str, known: Vec<KnownFailure>) -> Self {
+        Registry {
+            suite: suite.to_string(),
+            known: known.into_iter().filter(|k| k.suite == suite).collect(),
+            results: vec![],
+        }
+    }
+
+    pub fn known_failure(&self, function: &str) -> Option<&KnownFailure> {
+        self.known.iter().find(|k| k.function == function)
+    }
+
+    /// Checks the exit code of `call`: 0 unless it is a known failure.
+    pub fn check(&mut self, call: CallRecord) -> Status {
+        let function = call.function.as_str();
+        let status = match (self.known_failure(function), call.exit_code) {
+            (None, 0) => Status::Pass,
+            (None, code) => Status::Fail(format!("failed with exit code {}", code)),
+            (Some(_), 0) => Status::XPass,
+            (Some(known), code) if code == known.exit_code => Status::XFail,
+            (Some(known), code) => Status::Fail(format!(
+                "known to fail with exit code {} ({}), got {}",
+                known.exit_code, known.reason, code
+            )),
+        };
+
+        match &status {
+            Status::Pass => {}
+            Status::XFail => println!(
+                "  XFAIL {}: {}",
+                function,
+                self.known_failure(function).unwrap().reason
+            ),
+            Status::XPass => println!(
+                "  XPASS {}: succeeds now, remove it from {}",
+                function, KNOWN_FAILURES_PATH
+            ),
+            Status::Fail(problem) => println!("  FAILED {}: {}", function, problem),
+        }
+
+        self.results.push(TestCase::call(call, status.clone()));
+        status
+    }
+
+    pub fn results(&self) -> &[TestCase] {
+        &self.results
+    }
+
+    /// Writes the checks so far to `<suite>.xml` and `<suite>.json` in `dir`.
+    pub fn write_report(&self, dir: &Path) {
+        let mut report = Report::new(&self.suite);
+        report.extend(&self.results);
+        report.write(dir);
+    }
+
+    /// Prints a summary and returns the number of failures. Known failures of the suite
+    /// that were never checked are listed too, they are probably stale.
+    pub fn report(&self) -> usize {
+        let count = |wanted: fn(&Status) -> bool| {
+            self.results
+                .iter()
+                .filter(|case| wanted(&case.status))
+                .count()
+        };
+        let failures = count(|status| matches!(status, Status::Fail(_)));
+
+        println!(
+            "{}: {} passed, {} expected failures, {} unexpected passes, {} failures",
+            self.suite,
+            count(|status| *status == Status::Pass),
+            count(|status| *status == Status::XFail),
+            count(|status| *status == Status::XPass),
+            failures
+        );
+
+        for known in &self.known {
+            if !self.results.iter().any(|case| case.name == known.function) {
+                println!("  {} is a known failure but was not called", known.function);
+            }
+        }
+
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn parses_entries() {
+        let content = "\
+# suite  function  exit code  reason
+
+market   get_deal_client  33  deal 1 does not exist
+  power  miner_count      18  not investigated yet
+";
+
+        assert_eq!(
+            parse(content),
+            Ok(vec![
+                KnownFailure {
+                    suite: "market".to_string(),
+                    function: "get_deal_client".to_string(),
+                    exit_code: 33,
+                    reason: "deal 1 does not exist".to_string(),
+                },
+                KnownFailure {
+                    suite: "power".to_string(),
+                    function: "miner_count".to_string(),
+                    exit_code: 18,
+                    reason: "not investigated yet".to_string(),
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_lines() {
+        for line in [
+            "market get_deal_client 33",
+            "market get_deal_client",
+            "market",
+            "market get_deal_client thirty-three no such deal",
+            "market get_deal_client -1 no such deal",
+        ] {
+            assert!(parse(line).is_err(), "`{}` parsed", line);
+        }
+    }
+
+    #[test]
+    fn rejects_duplicate_entries() {
+        let content = "\
+market get_deal_client 33 deal 1 does not exist
+market get_deal_client 16 listed again
+";
+
+        assert_eq!(
+            parse(content),
+            Err("market get_deal_client is listed twice".to_string())
+        );
+    }
+
+    #[test]
+    fn allows_a_function_in_several_suites() {
+        let content = "\
+market get_balance 33 not investigated yet
+miner  get_balance 33 not investigated yet
+";
+
+        assert_eq!(parse(content).map(|known| known.len()), Ok(2));
+    }
+
+    #[test]
+    fn rejects_unknown_suites() {
+        assert_eq!(
+            parse("makret get_deal_client 33 deal 1 does not exist"),
+            Err("unknown suite in `makret get_deal_client 33 deal 1 does not exist`".to_string())
+        );
+    }
+
+    #[test]
+    fn known_failures_file_parses() {
+        let content = fs::read_to_string(KNOWN_FAILURES_PATH).unwrap();
+        parse(&content).unwrap();
+    }
+
+    fn call(function: &str, exit_code: u32) -> CallRecord {
+        CallRecord::new("MarketAPI", function, exit_code, 1000, &[], Duration::ZERO)
+    }
+
+    #[test]
+    fn checks_against_known_failures() {
+        let known = parse("market get_deal_client 33 deal 1 does not exist").unwrap();
+        let mut registry = Registry::new("market", known);
+
+        assert_eq!(registry.check(call("get_deal_client", 33)), Status::XFail);
+        assert_eq!(registry.check(call("get_deal_client", 0)), Status::XPass);
+        assert!(matches!(
+            registry.check(call("get_deal_client", 16)),
+            Status::Fail(_)
+        ));
+        assert_eq!(registry.check(call("get_balance", 0)), Status::Pass);
+        assert!(matches!(
+            registry.check(call("get_balance", 33)),
+            Status::Fail(_)
+        ));
+        assert_eq!(registry.report(), 2);
+        assert_eq!(registry.results()[0].group, "MarketAPI");
+        assert_eq!(registry.results()[0].call.as_ref().unwrap().exit_code, 33);
+    }
+}
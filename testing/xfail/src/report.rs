@@ -0,0 +1,525 @@
+//! Machine-readable test reports, written by every suite under `testing/`.
+//!
+//! A [`Report`] holds one [`TestCase`] per check with its [`Status`]. Checks that are a
+//! single contract call carry its [`CallRecord`]: exit code, gas, decoded revert reason and
+//! how long it took to apply. Reports are written as JUnit XML for CI, where expected
+//! failures show up as skipped tests, and as JSON for tracking coverage and regressions
+//! over time.
+//!
+//! This lives here rather than in the harness so that the suites built against another
+//! FVM than the harness write the same reports.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::Status;
+
+/// Where reports are written, relative to the suite directory, unless `REPORT_DIR` says
+/// otherwise.
+pub const DEFAULT_REPORT_DIR: &str = "reports";
+
+/// Selector of solidity's `Error(string)`, the payload of `require` and `revert`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// `REPORT_DIR`, or [`DEFAULT_REPORT_DIR`].
+pub fn report_dir() -> PathBuf {
+    env::var("REPORT_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from(DEFAULT_REPORT_DIR))
+}
+
+/// The 32 byte big-endian word at `offset` of `data`, if it is there and fits a `usize`.
+fn word(data: &[u8], offset: usize) -> Option<usize> {
+    let word = data.get(offset..offset.checked_add(32)?)?;
+    let (high, low) = word.split_at(24);
+    if high.iter().any(|byte| *byte != 0) {
+        return None;
+    }
+    Some(low.iter().fold(0, |acc, byte| (acc << 8) | *byte as usize))
+}
+
+/// Decodes the `Error(string)` payload of what a reverted contract call returned, if there
+/// is one.
+pub fn revert_reason(output: &[u8]) -> Option<String> {
+    let args = output.strip_prefix(&ERROR_SELECTOR)?;
+    let offset = word(args, 0)?;
+    let len = word(args, offset)?;
+    let start = offset.checked_add(32)?;
+    let bytes = args.get(start..start.checked_add(len)?)?;
+
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+/// The byte string a contract call returns, CBOR encoded in the message receipt.
+fn cbor_bytes(return_data: &[u8]) -> Option<&[u8]> {
+    let (first, rest) = return_data.split_first()?;
+    if first >> 5 != 2 {
+        return None;
+    }
+
+    let (len, rest) = match first & 0x1f {
+        len @ 0..=23 => (len as usize, rest),
+        extra @ 24..=27 => {
+            let size = 1 << (extra - 24);
+            let len = rest.get(..size)?;
+            (
+                len.iter().fold(0, |acc, byte| (acc << 8) | *byte as usize),
+                &rest[size..],
+            )
+        }
+        _ => return None,
+    };
+
+    rest.get(..len)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CallRecord {
+    pub contract: String,
+    pub function: String,
+    pub exit_code: u32,
+    pub gas_used: i64,
+    pub revert_reason: Option<String>,
+    pub duration: Duration,
+}
+
+impl CallRecord {
+    /// The record of a call to `function` of `contract`, from the receipt of its message.
+    /// `return_data` is the raw return data of the receipt, the CBOR byte string the
+    /// contract returned.
+    pub fn new(
+        contract: &str,
+        function: &str,
+        exit_code: u32,
+        gas_used: i64,
+        return_data: &[u8],
+        duration: Duration,
+    ) -> Self {
+        CallRecord {
+            contract: contract.to_string(),
+            function: function.to_string(),
+            exit_code,
+            gas_used,
+            revert_reason: cbor_bytes(return_data).and_then(revert_reason),
+            duration,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TestCase {
+    /// The `testsuite` the case belongs to: the contract called, or the suite for checks
+    /// that are not a single call.
+    pub group: String,
+    pub name: String,
+    pub call: Option<CallRecord>,
+    pub status: Status,
+}
+
+impl TestCase {
+    /// A check of a single contract call, grouped under the contract.
+    pub fn call(call: CallRecord, status: Status) -> Self {
+        TestCase {
+            group: call.contract.clone(),
+            name: call.function.clone(),
+            call: Some(call),
+            status,
+        }
+    }
+
+    /// Any other check, grouped under `suite`.
+    pub fn check(suite: &str, name: &str, status: Status) -> Self {
+        TestCase {
+            group: suite.to_string(),
+            name: name.to_string(),
+            call: None,
+            status,
+        }
+    }
+
+    fn duration(&self) -> Duration {
+        self.call
+            .as_ref()
+            .map_or(Duration::ZERO, |call| call.duration)
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+fn escape_json(text: &str) -> String {
+    let mut out = String::new();
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn json_string(text: Option<&str>) -> String {
+    text.map_or_else(
+        || "null".to_string(),
+        |text| format!("\"{}\"", escape_json(text)),
+    )
+}
+
+/// Name of the status in the JSON report, and the message that goes with it.
+fn status_and_message(status: &Status) -> (&'static str, Option<&str>) {
+    match status {
+        Status::Pass => ("passed", None),
+        Status::XFail => ("xfail", None),
+        Status::XPass => (
+            "xpass",
+            Some("succeeds now, remove it from the known failures"),
+        ),
+        Status::Fail(problem) => ("failed", Some(problem)),
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Report {
+    pub name: String,
+    pub cases: Vec<TestCase>,
+}
+
+impl Report {
+    pub fn new(name: &str) -> Self {
+        Report {
+            name: name.to_string(),
+            cases: vec![],
+        }
+    }
+
+    pub fn push(&mut self, case: TestCase) {
+        self.cases.push(case);
+    }
+
+    pub fn extend(&mut self, cases: &[TestCase]) {
+        self.cases.extend_from_slice(cases);
+    }
+
+    pub fn failures(&self) -> usize {
+        self.cases
+            .iter()
+            .filter(|case| matches!(case.status, Status::Fail(_)))
+            .count()
+    }
+
+    fn skipped(cases: &[&TestCase]) -> usize {
+        cases
+            .iter()
+            .filter(|case| case.status == Status::XFail)
+            .count()
+    }
+
+    /// One `testsuite` per group, one `testcase` per check. Exit code, gas and revert
+    /// reason of a call are kept as `testcase` properties, and known failures are skipped.
+    pub fn junit(&self) -> String {
+        let mut groups: Vec<&str> = vec![];
+        for case in &self.cases {
+            if !groups.contains(&case.group.as_str()) {
+                groups.push(&case.group);
+            }
+        }
+
+        let total: Duration = self.cases.iter().map(TestCase::duration).sum();
+        let mut out = format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+             <testsuites name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+            escape_xml(&self.name),
+            self.cases.len(),
+            self.failures(),
+            Self::skipped(&self.cases.iter().collect::<Vec<_>>()),
+            total.as_secs_f64()
+        );
+
+        for group in groups {
+            let cases: Vec<&TestCase> = self
+                .cases
+                .iter()
+                .filter(|case| case.group == group)
+                .collect();
+            let failures = cases
+                .iter()
+                .filter(|case| matches!(case.status, Status::Fail(_)))
+                .count();
+            let time: Duration = cases.iter().map(|case| case.duration()).sum();
+
+            out.push_str(&format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.6}\">\n",
+                escape_xml(group),
+                cases.len(),
+                failures,
+                Self::skipped(&cases),
+                time.as_secs_f64()
+            ));
+
+            for case in cases {
+                out.push_str(&format!(
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.6}\">\n",
+                    escape_xml(&case.group),
+                    escape_xml(&case.name),
+                    case.duration().as_secs_f64()
+                ));
+
+                if let Some(call) = &case.call {
+                    out.push_str("      <properties>\n");
+                    let mut properties = vec![
+                        ("exit_code", call.exit_code.to_string()),
+                        ("gas_used", call.gas_used.to_string()),
+                    ];
+                    if let Some(reason) = &call.revert_reason {
+                        properties.push(("revert_reason", reason.clone()));
+                    }
+                    for (name, value) in properties {
+                        out.push_str(&format!(
+                            "        <property name=\"{}\" value=\"{}\"/>\n",
+                            name,
+                            escape_xml(&value)
+                        ));
+                    }
+                    out.push_str("      </properties>\n");
+                }
+
+                match &case.status {
+                    Status::Pass => {}
+                    Status::XFail => {
+                        out.push_str("      <skipped message=\"known failure\"/>\n");
+                    }
+                    Status::XPass => out.push_str(&format!(
+                        "      <system-out>{}</system-out>\n",
+                        escape_xml(status_and_message(&case.status).1.unwrap())
+                    )),
+                    Status::Fail(problem) => out.push_str(&format!(
+                        "      <failure message=\"{}\"/>\n",
+                        escape_xml(problem)
+                    )),
+                }
+                out.push_str("    </testcase>\n");
+            }
+
+            out.push_str("  </testsuite>\n");
+        }
+
+        out.push_str("</testsuites>\n");
+        out
+    }
+
+    /// `{name, cases}`, with the group, name, status and message of every case, and the
+    /// call record of the cases that are a single call.
+    pub fn json(&self) -> String {
+        let cases: Vec<String> = self
+            .cases
+            .iter()
+            .map(|case| {
+                let mut fields = vec![
+                    ("group", json_string(Some(&case.group))),
+                    ("name", json_string(Some(&case.name))),
+                ];
+                if let Some(call) = &case.call {
+                    fields.extend([
+                        ("exit_code", call.exit_code.to_string()),
+                        ("gas_used", call.gas_used.to_string()),
+                        ("revert_reason", json_string(call.revert_reason.as_deref())),
+                        ("duration", call.duration.as_secs_f64().to_string()),
+                    ]);
+                }
+                let (status, message) = status_and_message(&case.status);
+                fields.extend([
+                    ("status", json_string(Some(status))),
+                    ("message", json_string(message)),
+                ]);
+
+                let fields: Vec<String> = fields
+                    .into_iter()
+                    .map(|(key, value)| format!("      \"{}\": {}", key, value))
+                    .collect();
+                format!("    {{\n{}\n    }}", fields.join(",\n"))
+            })
+            .collect();
+
+        let cases = if cases.is_empty() {
+            "[]".to_string()
+        } else {
+            format!("[\n{}\n  ]", cases.join(",\n"))
+        };
+
+        format!(
+            "{{\n  \"name\": \"{}\",\n  \"cases\": {}\n}}\n",
+            escape_json(&self.name),
+            cases
+        )
+    }
+
+    /// Writes `<name>.xml` and `<name>.json` to `dir`, creating it if needed.
+    pub fn write(&self, dir: &Path) {
+        fs::create_dir_all(dir).expect("Unable to create report directory");
+        fs::write(dir.join(format!("{}.xml", self.name)), self.junit())
+            .expect("Unable to write JUnit report");
+        fs::write(dir.join(format!("{}.json", self.name)), self.json())
+            .expect("Unable to write JSON report");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// `Error(string)` with `reason`, as solidity encodes it.
+    fn error(reason: &str) -> Vec<u8> {
+        let mut output = ERROR_SELECTOR.to_vec();
+        let mut offset = [0; 32];
+        offset[31] = 0x20;
+        output.extend(offset);
+        let mut len = [0; 32];
+        len[31] = reason.len() as u8;
+        output.extend(len);
+        output.extend(reason.as_bytes());
+        output.resize(output.len() + (32 - reason.len() % 32) % 32, 0);
+        output
+    }
+
+    fn call(function: &str, exit_code: u32, revert_reason: Option<&str>) -> CallRecord {
+        CallRecord {
+            contract: "MarketAPI".to_string(),
+            function: function.to_string(),
+            exit_code,
+            gas_used: 1000,
+            revert_reason: revert_reason.map(str::to_string),
+            duration: Duration::from_millis(250),
+        }
+    }
+
+    fn report() -> Report {
+        let mut report = Report::new("market");
+        report.push(TestCase::call(call("get_balance", 0, None), Status::Pass));
+        report.push(TestCase::call(
+            call("get_deal_client", 33, None),
+            Status::XFail,
+        ));
+        report.push(TestCase::call(call("get_owner", 0, None), Status::XPass));
+        report.push(TestCase::call(
+            call("add_balance", 33, Some("no <escrow>")),
+            Status::Fail("failed with exit code 33".to_string()),
+        ));
+        report.push(TestCase::check("market", "0065 is f0101", Status::Pass));
+        report
+    }
+
+    #[test]
+    fn decodes_revert_reasons() {
+        let output = error("actor error code 17");
+        assert_eq!(
+            revert_reason(&output),
+            Some("actor error code 17".to_string())
+        );
+
+        // Wrapped in the CBOR byte string of the receipt
+        let mut return_data = vec![0x58, output.len() as u8];
+        return_data.extend(&output);
+        let record = CallRecord::new(
+            "MarketAPI",
+            "get_balance",
+            33,
+            10,
+            &return_data,
+            Duration::ZERO,
+        );
+        assert_eq!(
+            record.revert_reason,
+            Some("actor error code 17".to_string())
+        );
+
+        assert_eq!(revert_reason(&[]), None);
+        assert_eq!(revert_reason(&output[..40]), None);
+        assert_eq!(
+            CallRecord::new("MarketAPI", "get_balance", 0, 10, &[0x40], Duration::ZERO)
+                .revert_reason,
+            None
+        );
+    }
+
+    #[test]
+    fn junit_groups_cases_and_skips_known_failures() {
+        let junit = report().junit();
+
+        assert!(junit.contains(
+            "<testsuites name=\"market\" tests=\"5\" failures=\"1\" skipped=\"1\" time=\"1.000000\">"
+        ));
+        assert!(junit.contains(
+            "  <testsuite name=\"MarketAPI\" tests=\"4\" failures=\"1\" skipped=\"1\" time=\"1.000000\">"
+        ));
+        assert!(junit.contains(
+            "  <testsuite name=\"market\" tests=\"1\" failures=\"0\" skipped=\"0\" time=\"0.000000\">\n    \
+             <testcase classname=\"market\" name=\"0065 is f0101\" time=\"0.000000\">\n    \
+             </testcase>"
+        ));
+        assert!(junit.contains(
+            "<testcase classname=\"MarketAPI\" name=\"get_deal_client\" time=\"0.250000\">\n      \
+             <properties>\n        \
+             <property name=\"exit_code\" value=\"33\"/>\n        \
+             <property name=\"gas_used\" value=\"1000\"/>\n      \
+             </properties>\n      \
+             <skipped message=\"known failure\"/>"
+        ));
+        assert!(junit.contains(
+            "        <property name=\"revert_reason\" value=\"no &lt;escrow&gt;\"/>\n      \
+             </properties>\n      \
+             <failure message=\"failed with exit code 33\"/>"
+        ));
+    }
+
+    #[test]
+    fn json_has_every_case() {
+        let mut report = report();
+        report.cases.drain(1..4);
+
+        assert_eq!(
+            report.json(),
+            r#"{
+  "name": "market",
+  "cases": [
+    {
+      "group": "MarketAPI",
+      "name": "get_balance",
+      "exit_code": 0,
+      "gas_used": 1000,
+      "revert_reason": null,
+      "duration": 0.25,
+      "status": "passed",
+      "message": null
+    },
+    {
+      "group": "market",
+      "name": "0065 is f0101",
+      "status": "passed",
+      "message": null
+    }
+  ]
+}
+"#
+        );
+    }
+
+    #[test]
+    fn json_without_cases() {
+        assert_eq!(
+            Report::new("power").json(),
+            "{\n  \"name\": \"power\",\n  \"cases\": []\n}\n"
+        );
+    }
+}